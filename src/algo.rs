@@ -0,0 +1,19 @@
+//! Graph algorithms, which work on every graph type implementing the traits they need.
+//!
+//! - `community`: label propagation, Louvain and modularity on undirected wt-graphs
+//! - `scc`: strongly connected components and the condensation of wt-digraphs
+//! - `traversal`: breadth-first and depth-first search
+
+pub(crate) mod biconnected;
+pub(crate) mod centrality;
+pub(crate) mod cores;
+pub mod community;
+pub(crate) mod flow;
+pub(crate) mod neighborhood;
+pub(crate) mod pagerank;
+pub mod scc;
+pub(crate) mod search;
+pub(crate) mod spanning;
+pub(crate) mod topological;
+pub mod traversal;
+pub(crate) mod triangles;
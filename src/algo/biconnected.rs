@@ -0,0 +1,159 @@
+// The algorithm behind traits::Biconnectivity.
+//
+// Hopcroft and Tarjan's depth-first search: `low` of a vertex is the smallest discovery time reachable from its
+// subtree with at most one back edge. A vertex is a cut vertex if a child can't reach above it, an edge to a child is a
+// bridge if the child can't even reach the vertex itself, and the edges pushed onto a stack since that child was
+// discovered form a biconnected component.
+// The search keeps its own stack of frames instead of recursing, so long paths don't overflow the call stack.
+// Only the first edge back to the parent is the tree edge, a parallel edge is a back edge; self-loops are ignored.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::BiconnectedComponents;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+// a vertex on the stack of the depth-first search
+struct Frame {
+    vertex: usize,
+    parent: Option<usize>,
+    next: usize,          // the position of the next neighbor to look at
+    skipped_parent: bool, // whether the tree edge to the parent has been passed
+}
+
+pub(crate) fn biconnected_components<T, S>(graph: &S) -> BiconnectedComponents<T>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+    // the neighbors of every vertex by position, without the vertex itself. Every edge is read at the end with the
+    // smaller index, where undirected graphs store it, so parallel edges are counted the same at both ends.
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+    for (i, v) in vertices.iter().enumerate() {
+        for next in search::successors(graph, v) {
+            let j = positions[&next];
+            if j > i {
+                neighbors[i].push(j);
+                neighbors[j].push(i);
+            }
+        }
+    }
+
+    let len = vertices.len();
+    let mut discovered: Vec<Option<usize>> = vec![None; len];
+    let mut low = vec![0; len];
+    let mut time = 0;
+    let mut cut = vec![false; len];
+    let mut bridges = Vec::new();
+    let mut components = Vec::new();
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    let mut stack: Vec<Frame> = Vec::new();
+
+    for root in 0..len {
+        if discovered[root].is_some() {
+            continue;
+        }
+        discovered[root] = Some(time);
+        low[root] = time;
+        time += 1;
+        stack.push(Frame {
+            vertex: root,
+            parent: None,
+            next: 0,
+            skipped_parent: false,
+        });
+        let mut root_children = 0;
+
+        while let Some(frame) = stack.last_mut() {
+            let v = frame.vertex;
+            let discovered_v = discovered[v].unwrap(); // safe, a vertex is discovered before it's pushed
+            if let Some(&w) = neighbors[v].get(frame.next) {
+                frame.next += 1;
+                if Some(w) == frame.parent && !frame.skipped_parent {
+                    frame.skipped_parent = true;
+                    continue;
+                }
+                match discovered[w] {
+                    None => {
+                        discovered[w] = Some(time);
+                        low[w] = time;
+                        time += 1;
+                        edges.push((v, w));
+                        stack.push(Frame {
+                            vertex: w,
+                            parent: Some(v),
+                            next: 0,
+                            skipped_parent: false,
+                        });
+                    }
+                    // an edge to an ancestor; from the other end it's skipped, the ancestor was discovered earlier
+                    Some(discovered_w) if discovered_w < discovered_v => {
+                        low[v] = low[v].min(discovered_w);
+                        edges.push((v, w));
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // all neighbors of v are done
+            let parent = frame.parent;
+            stack.pop();
+            let Some(p) = parent else {
+                continue;
+            };
+            low[p] = low[p].min(low[v]);
+            let discovered_p = discovered[p].unwrap(); // safe, see above
+            if low[v] >= discovered_p {
+                if p == root {
+                    root_children += 1;
+                } else {
+                    cut[p] = true;
+                }
+                // the edges since (p, v) are the component
+                let mut component = Vec::new();
+                while let Some((a, b)) = edges.pop() {
+                    component.push(a);
+                    component.push(b);
+                    if (a, b) == (p, v) {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                component.dedup();
+                components.push(component);
+            }
+            if low[v] > discovered_p {
+                bridges.push((p.min(v), p.max(v)));
+            }
+        }
+        if root_children > 1 {
+            cut[root] = true;
+        }
+    }
+
+    bridges.sort_unstable();
+    components.sort_unstable();
+    BiconnectedComponents {
+        articulation_points: (0..len)
+            .filter(|&v| cut[v])
+            .map(|v| vertices[v].clone())
+            .collect(),
+        bridges: bridges
+            .into_iter()
+            .map(|(a, b)| (vertices[a].clone(), vertices[b].clone()))
+            .collect(),
+        components: components
+            .into_iter()
+            .map(|component| component.into_iter().map(|v| vertices[v].clone()).collect())
+            .collect(),
+    }
+}
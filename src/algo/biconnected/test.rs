@@ -0,0 +1,115 @@
+use crate::graph::undirected::UGraph;
+use crate::io::*;
+use crate::traits::{BiconnectedComponents, Biconnectivity, Graph, WT};
+use crate::wt::undirected::WTUGraph;
+
+// the triangles 0 1 2 and 3 4 5, the bridges 2 - 3 and 5 - 6, the parallel edges 6 - 7,
+// 8 without edges and a self-loop at 9
+const TEXT: &str = "10\n11\n0 1\n1 2\n2 0\n2 3\n3 4\n4 5\n5 3\n5 6\n6 7\n6 7\n9 9\n";
+const WEIGHTED: &str =
+    "10\n11\n0 1 1\n1 2 1\n2 0 1\n2 3 1\n3 4 1\n4 5 1\n5 3 1\n5 6 1\n6 7 1\n6 7 1\n9 9 1\n";
+
+fn expected() -> BiconnectedComponents<usize> {
+    BiconnectedComponents {
+        articulation_points: vec![2, 3, 5, 6],
+        bridges: vec![(2, 3), (5, 6)],
+        components: vec![
+            vec![0, 1, 2],
+            vec![2, 3],
+            vec![3, 4, 5],
+            vec![5, 6],
+            vec![6, 7],
+        ],
+    }
+}
+
+#[test]
+fn biconnected_components() {
+    let ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    assert_eq!(ug.biconnected_components(), expected());
+    let wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    assert_eq!(wtug.biconnected_components(), expected());
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    wtug.delete_vertex(4);
+    wtug.commit_edits();
+    let result = wtug.biconnected_components();
+    assert_eq!(result.articulation_points, vec![2, 3, 5, 6]);
+    assert_eq!(result.bridges, vec![(2, 3), (3, 5), (5, 6)]);
+    assert_eq!(
+        result.components,
+        vec![
+            vec![0, 1, 2],
+            vec![2, 3],
+            vec![3, 5],
+            vec![5, 6],
+            vec![6, 7]
+        ]
+    );
+
+    let mut ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    ug.delete_vertex(4);
+    assert_eq!(ug.biconnected_components(), result);
+}
+
+#[test]
+fn root() {
+    // the search starts at 0, which is a cut vertex only because it has two children
+    let ug: UGraph = read_ugraph("4\n3\n0 1\n0 2\n0 3\n".as_bytes()).unwrap();
+    let result = ug.biconnected_components();
+    assert_eq!(result.articulation_points, vec![0]);
+    assert_eq!(result.bridges, vec![(0, 1), (0, 2), (0, 3)]);
+
+    let ug: UGraph = read_ugraph("3\n3\n0 1\n1 2\n2 0\n".as_bytes()).unwrap();
+    let result = ug.biconnected_components();
+    assert!(result.articulation_points.is_empty());
+    assert!(result.bridges.is_empty());
+    assert_eq!(result.components, vec![vec![0, 1, 2]]);
+}
+
+#[test]
+fn long_path() {
+    // deep enough to overflow the stack of a recursive search
+    let len = 100_000;
+    let adj = (0..len)
+        .map(|v| if v + 1 < len { vec![v + 1] } else { vec![] })
+        .collect();
+    let wtug = WTUGraph::from_ugraph(UGraph::from_adjacency_list(len, len - 1, adj));
+    let result = wtug.biconnected_components();
+    assert_eq!(result.articulation_points.len(), len - 2);
+    assert_eq!(result.bridges.len(), len - 1);
+    assert_eq!(result.components.len(), len - 1);
+}
+
+#[test]
+fn all_types() {
+    fn check<G: Biconnectivity<usize>>(graph: G) {
+        assert_eq!(graph.biconnected_components(), expected());
+    }
+    fn check_labeled<G: Biconnectivity<String>>(graph: G) {
+        let result = graph.biconnected_components();
+        assert_eq!(result.articulation_points, vec!["2", "3", "5", "6"]);
+        assert_eq!(
+            result.bridges,
+            vec![
+                ("2".to_string(), "3".to_string()),
+                ("5".to_string(), "6".to_string())
+            ]
+        );
+        assert_eq!(result.components.len(), 5);
+    }
+    check(read_ugraph(TEXT.as_bytes()).unwrap());
+    check(read_wt_ugraph(TEXT.as_bytes()).unwrap());
+    check(read_weighted_ugraph::<_, i32>(WEIGHTED.as_bytes()).unwrap());
+    check(read_weighted_wt_ugraph::<_, i32>(WEIGHTED.as_bytes()).unwrap());
+    // labeled graphs only have the vertices with edges, so 8 is missing
+    let text = TEXT.replacen("10", "9", 1);
+    let weighted = WEIGHTED.replacen("10", "9", 1);
+    check_labeled(read_labeled_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+}
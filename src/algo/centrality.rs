@@ -0,0 +1,234 @@
+// The algorithms behind traits::Centrality, on the positions of the vertices of a SearchView.
+// All measures are built on the shortest paths from every source, which are found with a breadth first search in
+// unweighted graphs and with Dijkstra's algorithm in weighted graphs.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::CentralityMeasure;
+use crate::GraphError;
+use num::{Num, ToPrimitive};
+use rand::rngs::StdRng;
+use rand::seq::index;
+use rand::SeedableRng;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+// the shortest paths from one source
+struct Paths<D> {
+    distances: Vec<Option<D>>,
+    counts: Vec<f64>,              // the number of shortest paths to every vertex
+    predecessors: Vec<Vec<usize>>, // the previous vertices on these paths
+    order: Vec<usize>,             // the reached vertices by increasing distance
+}
+
+pub(crate) fn centrality<T, D, S>(
+    graph: &S,
+    measure: CentralityMeasure,
+) -> Result<HashMap<T, f64>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy + ToPrimitive,
+    S: SearchView<T, Distance = D>,
+{
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+    let mut adj = Vec::with_capacity(vertices.len());
+    for vertex in vertices.iter() {
+        let mut edges = Vec::new();
+        for next in search::successors(graph, vertex) {
+            let weight = graph.distance(vertex, &next);
+            if weight < D::zero() {
+                return Err(GraphError::NegativeWeight);
+            }
+            edges.push((positions[&next], weight));
+        }
+        adj.push(edges);
+    }
+    let paths = |source: usize| match S::WEIGHTED {
+        true => dijkstra(&adj, source),
+        false => bfs(&adj, source),
+    };
+
+    let n = vertices.len();
+    let scores = match measure {
+        CentralityMeasure::Betweenness => betweenness(n, 0..n, paths, S::DIRECTED),
+        CentralityMeasure::BetweennessSampled { sources, seed } if sources < n => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let sample = index::sample(&mut rng, n, sources);
+            let mut scores = betweenness(n, sample.into_iter(), paths, S::DIRECTED);
+            let scale = n as f64 / sources as f64;
+            scores.iter_mut().for_each(|score| *score *= scale);
+            scores
+        }
+        CentralityMeasure::BetweennessSampled { .. } => betweenness(n, 0..n, paths, S::DIRECTED),
+        CentralityMeasure::Closeness => (0..n)
+            .map(|source| {
+                let paths = paths(source);
+                let reached = paths.order.len() - 1;
+                let total: f64 = distances(&paths).sum();
+                if total > 0.0 {
+                    (reached as f64 / total) * (reached as f64 / (n - 1) as f64)
+                } else {
+                    0.0
+                }
+            })
+            .collect(),
+        CentralityMeasure::Harmonic => (0..n)
+            .map(|source| {
+                distances(&paths(source))
+                    .filter(|&d| d > 0.0)
+                    .map(|d| 1.0 / d)
+                    .sum()
+            })
+            .collect(),
+    };
+    Ok(vertices.into_iter().zip(scores).collect())
+}
+
+// the distances to all vertices which are reached, except for the source
+fn distances<D: ToPrimitive + Copy>(paths: &Paths<D>) -> impl Iterator<Item = f64> + '_ {
+    paths.order[1..]
+        .iter()
+        .map(|&v| paths.distances[v].unwrap().to_f64().unwrap_or(f64::NAN)) // safe, v was reached
+}
+
+// Brandes' algorithm: the dependency of the source on every vertex is summed up from the farthest vertices back
+fn betweenness<D, P, I>(n: usize, sources: I, paths: P, directed: bool) -> Vec<f64>
+where
+    P: Fn(usize) -> Paths<D>,
+    I: Iterator<Item = usize>,
+{
+    let mut scores = vec![0.0; n];
+    let mut dependency = vec![0.0; n];
+    for source in sources {
+        let paths = paths(source);
+        for &v in paths.order.iter() {
+            dependency[v] = 0.0;
+        }
+        for &w in paths.order.iter().rev() {
+            for &v in paths.predecessors[w].iter() {
+                dependency[v] += paths.counts[v] / paths.counts[w] * (1.0 + dependency[w]);
+            }
+            if w != source {
+                scores[w] += dependency[w];
+            }
+        }
+    }
+    if !directed {
+        // every path has been found from both ends
+        scores.iter_mut().for_each(|score| *score /= 2.0);
+    }
+    scores
+}
+
+fn bfs<D: Num + Copy>(adj: &[Vec<(usize, D)>], source: usize) -> Paths<D> {
+    let mut paths = Paths::new(adj.len(), source);
+    let mut queue = VecDeque::from([source]);
+    while let Some(vertex) = queue.pop_front() {
+        paths.order.push(vertex);
+        let distance = paths.distances[vertex].unwrap() + D::one(); // safe, vertex came from the queue
+        for &(next, _) in adj[vertex].iter() {
+            if paths.distances[next].is_none() {
+                paths.distances[next] = Some(distance);
+                queue.push_back(next);
+            }
+            if paths.distances[next] == Some(distance) {
+                paths.add(vertex, next);
+            }
+        }
+    }
+    paths
+}
+
+// an entry of the queue of dijkstra; the entry with the smallest distance is the greatest
+struct Entry<D> {
+    distance: D,
+    vertex: usize,
+}
+
+impl<D: PartialOrd> PartialEq for Entry<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<D: PartialOrd> Eq for Entry<D> {}
+
+impl<D: PartialOrd> PartialOrd for Entry<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D: PartialOrd> Ord for Entry<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+fn dijkstra<D: Num + PartialOrd + Copy>(adj: &[Vec<(usize, D)>], source: usize) -> Paths<D> {
+    let mut paths = Paths::new(adj.len(), source);
+    let mut done = vec![false; adj.len()];
+    let mut queue = BinaryHeap::from([Entry {
+        distance: D::zero(),
+        vertex: source,
+    }]);
+    while let Some(Entry { distance, vertex }) = queue.pop() {
+        if done[vertex] {
+            continue; // outdated, the vertex has been taken with a shorter distance
+        }
+        done[vertex] = true;
+        paths.order.push(vertex);
+        for &(next, weight) in adj[vertex].iter() {
+            let next_distance = distance + weight;
+            match paths.distances[next] {
+                Some(d) if next_distance > d => continue,
+                Some(d) if next_distance == d => {}
+                _ => {
+                    // a shorter path, which replaces the ones found before
+                    paths.distances[next] = Some(next_distance);
+                    paths.counts[next] = 0.0;
+                    paths.predecessors[next].clear();
+                    queue.push(Entry {
+                        distance: next_distance,
+                        vertex: next,
+                    });
+                }
+            }
+            if !done[next] {
+                paths.add(vertex, next);
+            }
+        }
+    }
+    paths
+}
+
+impl<D: Num + Copy> Paths<D> {
+    fn new(len: usize, source: usize) -> Self {
+        let mut paths = Paths {
+            distances: vec![None; len],
+            counts: vec![0.0; len],
+            predecessors: vec![Vec::new(); len],
+            order: Vec::new(),
+        };
+        paths.distances[source] = Some(D::zero());
+        paths.counts[source] = 1.0;
+        paths
+    }
+
+    // `vertex` is on a shortest path to `next`
+    fn add(&mut self, vertex: usize, next: usize) {
+        self.counts[next] += self.counts[vertex];
+        self.predecessors[next].push(vertex);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::graph::{directed::Digraph, undirected::UGraph};
+use crate::io::*;
+use crate::traits::{Centrality, CentralityMeasure, Graph, WT};
+use crate::GraphError;
+use std::collections::HashMap;
+
+use CentralityMeasure::*;
+
+fn assert_close<T>(scores: &HashMap<T, f64>, expected: &[(T, f64)])
+where
+    T: std::hash::Hash + Eq + std::fmt::Debug,
+{
+    assert_eq!(scores.len(), expected.len());
+    for (v, score) in expected {
+        assert!(
+            (scores[v] - score).abs() < 1e-9,
+            "{:?}: {} != {}",
+            v,
+            scores[v],
+            score
+        );
+    }
+}
+
+// 0 - 1 - 2 - 3 - 4
+fn path() -> UGraph {
+    let adj = vec![vec![1], vec![2], vec![3], vec![4], vec![]];
+    UGraph::from_adjacency_list(5, 4, adj)
+}
+
+#[test]
+fn betweenness() {
+    let expected = [(0, 0.0), (1, 3.0), (2, 4.0), (3, 3.0), (4, 0.0)];
+    assert_close(&path().centrality(Betweenness).unwrap(), &expected);
+
+    // 0 -> 1 -> 3, 0 -> 2 -> 3
+    let adj = vec![vec![1, 2], vec![3], vec![3], vec![]];
+    let diamond = Digraph::from_adjacency_list(4, 4, adj);
+    let expected = [(0, 0.0), (1, 0.5), (2, 0.5), (3, 0.0)];
+    assert_close(&diamond.centrality(Betweenness).unwrap(), &expected);
+}
+
+#[test]
+fn weighted_betweenness() {
+    // 0 -1- 1 -1- 2 and 0 -w- 2
+    let scores = |w: i32| {
+        let text = format!("3\n3\n0 1 1\n1 2 1\n0 2 {}\n", w);
+        let wug = read_weighted_ugraph::<_, i32>(text.as_bytes()).unwrap();
+        wug.centrality(Betweenness).unwrap()[&1]
+    };
+    assert_eq!(scores(3), 1.0);
+    assert_eq!(scores(2), 0.5); // two shortest paths from 0 to 2
+    assert_eq!(scores(1), 0.0);
+
+    let text = "3\n3\n0 1 1\n1 2 1\n0 2 3\n";
+    let wtd = read_weighted_wt_digraph::<_, i32>(text.as_bytes()).unwrap();
+    assert_close(
+        &wtd.centrality(Betweenness).unwrap(),
+        &[(0, 0.0), (1, 1.0), (2, 0.0)],
+    );
+
+    let text = "2\n1\n0 1 -1\n";
+    let wdg = read_weighted_digraph::<_, i32>(text.as_bytes()).unwrap();
+    assert_eq!(wdg.centrality(Harmonic), Err(GraphError::NegativeWeight));
+}
+
+#[test]
+fn sampled_betweenness() {
+    let ug = path();
+    let exact = ug.centrality(Betweenness).unwrap();
+    let all = BetweennessSampled {
+        sources: 5,
+        seed: 1,
+    };
+    assert_eq!(ug.centrality(all).unwrap(), exact);
+
+    let two = BetweennessSampled {
+        sources: 2,
+        seed: 7,
+    };
+    let sampled = ug.centrality(two).unwrap();
+    assert_eq!(sampled, ug.centrality(two).unwrap());
+    assert_eq!(sampled.len(), 5);
+    assert_eq!(sampled[&0], 0.0);
+    assert_eq!(sampled[&4], 0.0);
+}
+
+#[test]
+fn closeness_and_harmonic() {
+    let ug = path();
+    let closeness = ug.centrality(Closeness).unwrap();
+    assert_close(
+        &closeness,
+        &[
+            (0, 0.4),
+            (1, 4.0 / 7.0),
+            (2, 4.0 / 6.0),
+            (3, 4.0 / 7.0),
+            (4, 0.4),
+        ],
+    );
+    let harmonic = ug.centrality(Harmonic).unwrap();
+    assert!((harmonic[&0] - (1.0 + 1.0 / 2.0 + 1.0 / 3.0 + 1.0 / 4.0)).abs() < 1e-9);
+    assert!((harmonic[&2] - 3.0).abs() < 1e-9);
+
+    // 0 -> 1 -> 2, 3: only half of the others can be reached from 1
+    let adj = vec![vec![1], vec![2], vec![], vec![]];
+    let dg = Digraph::from_adjacency_list(4, 2, adj);
+    assert_close(
+        &dg.centrality(Closeness).unwrap(),
+        &[
+            (0, 2.0 / 3.0 * 2.0 / 3.0),
+            (1, 1.0 / 3.0),
+            (2, 0.0),
+            (3, 0.0),
+        ],
+    );
+}
+
+#[test]
+fn deleted_vertices() {
+    let text = "3\n2\n0 1\n1 2\n";
+    let mut wtd = read_wt_digraph(text.as_bytes()).unwrap();
+    wtd.delete_vertex(2);
+    wtd.commit_edits();
+    assert_close(&wtd.centrality(Harmonic).unwrap(), &[(0, 1.0), (1, 0.0)]);
+}
+
+#[test]
+fn all_types() {
+    let text = "3\n2\n0 1\n1 2\n";
+    let weighted = "3\n2\n0 1 2\n1 2 3\n";
+    fn check<G: Centrality<usize>>(graph: G) {
+        assert_eq!(graph.centrality(Betweenness).unwrap()[&1], 1.0);
+    }
+    fn check_labeled<G: Centrality<String>>(graph: G) {
+        assert_eq!(graph.centrality(Betweenness).unwrap()["1"], 1.0);
+    }
+    check(read_digraph(text.as_bytes()).unwrap());
+    check(read_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_ugraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_wt_digraph(text.as_bytes()).unwrap());
+    check(read_wt_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_wt_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_wt_ugraph::<_, f64>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, f64>(weighted.as_bytes()).unwrap());
+}
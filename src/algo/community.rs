@@ -0,0 +1,381 @@
+//! Community detection on undirected wt-graphs: label propagation, Louvain and modularity.
+//!
+//! All functions read the edges straight from the wavelet tree at the last commit, the graph doesn't have to be
+//! exported first. A partition assigns a community to every index, `None` to the indices of deleted vertices.
+//! In weighted graphs the weights are the strengths of the edges and shouldn't be negative; parallel edges add up.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::prelude::*;
+//!
+//! // two triangles, 0 1 2 and 3 4 5, joined by the edge 2 - 3
+//! let adj = vec![vec![1, 2], vec![2], vec![3], vec![4, 5], vec![5], vec![]];
+//! let wtug = WTUGraph::from_ugraph(UGraph::from_adjacency_list(6, 7, adj));
+//!
+//! let louvain = wtug.louvain();
+//! assert_eq!(louvain.communities, vec![Some(0), Some(0), Some(0), Some(1), Some(1), Some(1)]);
+//! assert_eq!(wtug.modularity(&louvain.communities), louvain.modularity);
+//! ```
+
+use crate::traits::{Graph, Weighted};
+use crate::wt::undirected::WTUGraph;
+use crate::wt::weighted_undirected::WeightedWTUGraph;
+use num::{Num, ToPrimitive};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod test;
+
+// label propagation stops after this many rounds, even if labels still change
+const MAX_ROUNDS: usize = 100;
+
+/// The communities found by `label_propagation` or `louvain`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Communities {
+    /// The community of every index, `None` for deleted vertices. The communities are numbered from 0 in the order
+    /// of their smallest index.
+    pub communities: Vec<Option<usize>>,
+    /// The modularity of the partition, see `modularity`.
+    pub modularity: f64,
+}
+
+impl WTUGraph {
+    /// Finds communities by asynchronous label propagation: every vertex starts in its own community, then the
+    /// vertices, in an order shuffled by the seeded random number generator, join the community most of their
+    /// neighbors are in, until no vertex changes (or after 100 rounds). Ties are broken at random, unless the
+    /// vertex' own community is among them.
+    pub fn label_propagation(&self, seed: u64) -> Communities {
+        label_propagation(self, seed)
+    }
+
+    /// Finds communities with the Louvain method: vertices are moved to the community of a neighbor as long as this
+    /// increases the modularity, then each community becomes a single vertex and the vertices are moved again,
+    /// until nothing moves anymore.
+    pub fn louvain(&self) -> Communities {
+        louvain(self)
+    }
+
+    /// Returns the modularity of the partition: the share of the edges inside the communities, minus the share
+    /// expected if the edges were placed at random with the same degrees. Between -0.5 and 1; 0 for a graph without
+    /// edges. Vertices without a community in the partition (`None`, or an index beyond its end) are in a community
+    /// of their own.
+    pub fn modularity(&self, partition: &[Option<usize>]) -> f64 {
+        modularity(self, partition)
+    }
+}
+
+impl<W> WeightedWTUGraph<W>
+where
+    W: Num + Copy + ToPrimitive,
+{
+    /// Finds communities by asynchronous label propagation, see `WTUGraph::label_propagation`.
+    /// A vertex joins the community with the biggest sum of weights to it.
+    pub fn label_propagation(&self, seed: u64) -> Communities {
+        label_propagation(self, seed)
+    }
+
+    /// Finds communities with the Louvain method, see `WTUGraph::louvain`.
+    pub fn louvain(&self) -> Communities {
+        louvain(self)
+    }
+
+    /// Returns the modularity of the partition, see `WTUGraph::modularity`.
+    pub fn modularity(&self, partition: &[Option<usize>]) -> f64 {
+        modularity(self, partition)
+    }
+}
+
+// an undirected wt-graph as seen by the algorithms
+trait Network {
+    fn index_len(&self) -> usize;
+
+    fn exists(&self, vertex: usize) -> bool;
+
+    // the neighbors which exist with the weights of the edges; a loop is returned once
+    fn neighbors(&self, vertex: usize) -> Vec<(usize, f64)>;
+}
+
+impl Network for WTUGraph {
+    fn index_len(&self) -> usize {
+        self.wtd.wt_adj_len
+    }
+
+    fn exists(&self, vertex: usize) -> bool {
+        self.vertex_exists(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> Vec<(usize, f64)> {
+        self.neighbors_iter(vertex)
+            .filter(|&v| self.vertex_exists(v))
+            .map(|v| (v, 1.0))
+            .collect()
+    }
+}
+
+impl<W> Network for WeightedWTUGraph<W>
+where
+    W: Num + Copy + ToPrimitive,
+{
+    fn index_len(&self) -> usize {
+        self.wdg.dg.wt_adj_len
+    }
+
+    fn exists(&self, vertex: usize) -> bool {
+        self.vertex_exists(vertex)
+    }
+
+    fn neighbors(&self, vertex: usize) -> Vec<(usize, f64)> {
+        self.neighbors_iter(vertex)
+            .filter(|&v| self.vertex_exists(v))
+            .map(|v| (v, self.weight(vertex, v).to_f64().unwrap_or(0.0)))
+            .collect()
+    }
+}
+
+// a weighted graph on positions 0..len, which the Louvain method shrinks step by step
+struct Level {
+    adj: Vec<Vec<(usize, f64)>>, // the edges to other vertices, in the lists of both ends
+    loops: Vec<f64>,             // the weight of the loops of every vertex
+}
+
+impl Level {
+    fn from_network<N: Network>(network: &N, indices: &[usize], positions: &[Option<usize>]) -> Self {
+        let mut level = Level {
+            adj: vec![Vec::new(); indices.len()],
+            loops: vec![0.0; indices.len()],
+        };
+        for (i, &v) in indices.iter().enumerate() {
+            for (w, weight) in network.neighbors(v) {
+                let j = positions[w].unwrap(); // safe, neighbors only returns vertices which exist
+                if i == j {
+                    level.loops[i] += weight;
+                } else {
+                    level.adj[i].push((j, weight));
+                }
+            }
+        }
+        level
+    }
+
+    // a loop counts twice, once for each of its ends
+    fn degree(&self, vertex: usize) -> f64 {
+        self.adj[vertex].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * self.loops[vertex]
+    }
+
+    // twice the sum of all weights
+    fn total(&self) -> f64 {
+        (0..self.adj.len()).map(|v| self.degree(v)).sum()
+    }
+
+    fn modularity(&self, communities: &[usize]) -> f64 {
+        let total = self.total();
+        if total == 0.0 {
+            return 0.0;
+        }
+        let count = communities.iter().max().map_or(0, |max| max + 1);
+        let mut inside = vec![0.0; count];
+        let mut degrees = vec![0.0; count];
+        for (v, &c) in communities.iter().enumerate() {
+            inside[c] += 2.0 * self.loops[v];
+            degrees[c] += self.degree(v);
+            for &(w, weight) in self.adj[v].iter() {
+                if communities[w] == c {
+                    inside[c] += weight;
+                }
+            }
+        }
+        inside
+            .iter()
+            .zip(degrees)
+            .map(|(inside, degree)| inside / total - (degree / total).powi(2))
+            .sum()
+    }
+
+    // moves single vertices to the neighboring community with the biggest gain in modularity,
+    // returns true if any vertex has moved
+    fn move_vertices(&self, communities: &mut [usize]) -> bool {
+        let total = self.total();
+        if total == 0.0 {
+            return false;
+        }
+        let degrees: Vec<f64> = (0..self.adj.len()).map(|v| self.degree(v)).collect();
+        let mut community_degrees = vec![0.0; self.adj.len()];
+        for (v, &c) in communities.iter().enumerate() {
+            community_degrees[c] += degrees[v];
+        }
+
+        let mut moved = false;
+        loop {
+            let mut changed = false;
+            for v in 0..self.adj.len() {
+                let own = communities[v];
+                community_degrees[own] -= degrees[v];
+                let mut links: HashMap<usize, f64> = HashMap::from([(own, 0.0)]);
+                for &(w, weight) in self.adj[v].iter() {
+                    *links.entry(communities[w]).or_insert(0.0) += weight;
+                }
+                // the gain of joining c, up to a factor which is the same for all communities
+                let gain = |c: usize| links[&c] - community_degrees[c] * degrees[v] / total;
+                let mut best = own;
+                for &c in links.keys() {
+                    if gain(c) > gain(best) || (gain(c) == gain(best) && c < best && best != own) {
+                        best = c;
+                    }
+                }
+                community_degrees[best] += degrees[v];
+                if best != own {
+                    communities[v] = best;
+                    changed = true;
+                    moved = true;
+                }
+            }
+            if !changed {
+                return moved;
+            }
+        }
+    }
+
+    // every community becomes a vertex, the edges inside it become its loop
+    fn aggregate(&self, communities: &[usize], count: usize) -> Level {
+        let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut loops = vec![0.0; count];
+        for (v, &c) in communities.iter().enumerate() {
+            loops[c] += self.loops[v];
+            for &(w, weight) in self.adj[v].iter() {
+                if communities[w] == c {
+                    loops[c] += weight / 2.0; // the edge is in the lists of both ends
+                } else {
+                    *weights[c].entry(communities[w]).or_insert(0.0) += weight;
+                }
+            }
+        }
+        Level {
+            adj: weights
+                .into_iter()
+                .map(|edges| {
+                    let mut edges: Vec<(usize, f64)> = edges.into_iter().collect();
+                    edges.sort_by_key(|&(w, _)| w);
+                    edges
+                })
+                .collect(),
+            loops,
+        }
+    }
+}
+
+// the existing indices, and the position of every index among them
+fn positions<N: Network>(network: &N) -> (Vec<usize>, Vec<Option<usize>>) {
+    let indices: Vec<usize> = (0..network.index_len())
+        .filter(|&v| network.exists(v))
+        .collect();
+    let mut positions = vec![None; network.index_len()];
+    for (i, &v) in indices.iter().enumerate() {
+        positions[v] = Some(i);
+    }
+    (indices, positions)
+}
+
+// numbers the communities from 0 in the order of their first vertex
+fn renumber(communities: &mut [usize]) -> usize {
+    let mut numbers = HashMap::new();
+    for c in communities.iter_mut() {
+        let next = numbers.len();
+        *c = *numbers.entry(*c).or_insert(next);
+    }
+    numbers.len()
+}
+
+fn result(level: &Level, indices: &[usize], len: usize, mut communities: Vec<usize>) -> Communities {
+    renumber(&mut communities);
+    let mut result = vec![None; len];
+    for (i, &v) in indices.iter().enumerate() {
+        result[v] = Some(communities[i]);
+    }
+    Communities {
+        modularity: level.modularity(&communities),
+        communities: result,
+    }
+}
+
+fn label_propagation<N: Network>(network: &N, seed: u64) -> Communities {
+    let (indices, positions) = positions(network);
+    let level = Level::from_network(network, &indices, &positions);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut labels: Vec<usize> = (0..indices.len()).collect();
+    let mut order = labels.clone();
+    for _ in 0..MAX_ROUNDS {
+        order.shuffle(&mut rng);
+        let mut changed = false;
+        for &v in order.iter() {
+            let mut weights: HashMap<usize, f64> = HashMap::new();
+            for &(w, weight) in level.adj[v].iter() {
+                *weights.entry(labels[w]).or_insert(0.0) += weight;
+            }
+            let Some(max) = weights.values().copied().reduce(f64::max) else {
+                continue; // no neighbors
+            };
+            if weights.get(&labels[v]) == Some(&max) {
+                continue;
+            }
+            let mut best: Vec<usize> = weights
+                .into_iter()
+                .filter(|&(_, weight)| weight == max)
+                .map(|(label, _)| label)
+                .collect();
+            best.sort_unstable(); // the order of a HashMap isn't seeded
+            labels[v] = *best.choose(&mut rng).unwrap(); // safe, max is one of the weights
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+    result(&level, &indices, network.index_len(), labels)
+}
+
+fn louvain<N: Network>(network: &N) -> Communities {
+    let (indices, positions) = positions(network);
+    let first = Level::from_network(network, &indices, &positions);
+    // the community of every vertex of the first level
+    let mut communities: Vec<usize> = (0..indices.len()).collect();
+    let mut level = Level {
+        adj: first.adj.clone(),
+        loops: first.loops.clone(),
+    };
+    loop {
+        let mut moved: Vec<usize> = (0..level.adj.len()).collect();
+        if !level.move_vertices(&mut moved) {
+            break;
+        }
+        let count = renumber(&mut moved);
+        for c in communities.iter_mut() {
+            *c = moved[*c];
+        }
+        level = level.aggregate(&moved, count);
+    }
+    result(&first, &indices, network.index_len(), communities)
+}
+
+fn modularity<N: Network>(network: &N, partition: &[Option<usize>]) -> f64 {
+    let (indices, positions) = positions(network);
+    let level = Level::from_network(network, &indices, &positions);
+    // the vertices without a community get new ones after the biggest in the partition
+    let mut next = partition.iter().flatten().max().map_or(0, |max| max + 1);
+    let mut communities: Vec<usize> = indices
+        .iter()
+        .map(|&v| match partition.get(v).copied().flatten() {
+            Some(c) => c,
+            None => {
+                next += 1;
+                next - 1
+            }
+        })
+        .collect();
+    // renumber, so the communities can be used as positions
+    renumber(&mut communities);
+    level.modularity(&communities)
+}
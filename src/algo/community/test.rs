@@ -0,0 +1,117 @@
+use crate::io::*;
+use crate::traits::{Graph, WT};
+use crate::wt::{undirected::WTUGraph, weighted_undirected::WeightedWTUGraph};
+
+// two triangles, 0 1 2 and 3 4 5, joined by the edge 2 - 3
+const TRIANGLES: &str = "6\n7\n0 1\n0 2\n1 2\n2 3\n3 4\n3 5\n4 5\n";
+
+// 0 --10-- 1 --1-- 2 --10-- 3
+const PAIRS: &str = "4\n3\n0 1 10\n1 2 1\n2 3 10\n";
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn louvain() {
+    let wtug: WTUGraph = read_wt_ugraph(TRIANGLES.as_bytes()).unwrap();
+    let louvain = wtug.louvain();
+    assert_eq!(
+        louvain.communities,
+        vec![Some(0), Some(0), Some(0), Some(1), Some(1), Some(1)]
+    );
+    // 2 * (6/14 - (7/14)^2)
+    assert!(close(louvain.modularity, 5.0 / 14.0));
+
+    let wtug: WeightedWTUGraph<i32> = read_weighted_wt_ugraph(PAIRS.as_bytes()).unwrap();
+    let louvain = wtug.louvain();
+    assert_eq!(
+        louvain.communities,
+        vec![Some(0), Some(0), Some(1), Some(1)]
+    );
+    assert_eq!(wtug.modularity(&louvain.communities), louvain.modularity);
+}
+
+#[test]
+fn label_propagation() {
+    // two triangles which aren't joined can't share a community
+    let text = "6\n6\n0 1\n0 2\n1 2\n3 4\n3 5\n4 5\n";
+    let wtug: WTUGraph = read_wt_ugraph(text.as_bytes()).unwrap();
+    for seed in 0..20 {
+        let result = wtug.label_propagation(seed);
+        assert_eq!(
+            result.communities,
+            vec![Some(0), Some(0), Some(0), Some(1), Some(1), Some(1)]
+        );
+        assert!(close(result.modularity, 0.5));
+    }
+
+    // the same seed gives the same communities
+    let wtug: WTUGraph = read_wt_ugraph(TRIANGLES.as_bytes()).unwrap();
+    for seed in 0..20 {
+        let result = wtug.label_propagation(seed);
+        assert_eq!(result, wtug.label_propagation(seed));
+        assert_eq!(wtug.modularity(&result.communities), result.modularity);
+    }
+
+    // the heavy edges win
+    let wtug: WeightedWTUGraph<i32> = read_weighted_wt_ugraph(PAIRS.as_bytes()).unwrap();
+    for seed in 0..20 {
+        let result = wtug.label_propagation(seed);
+        assert_eq!(result.communities, vec![Some(0), Some(0), Some(1), Some(1)]);
+    }
+}
+
+#[test]
+fn modularity() {
+    let wtug: WTUGraph = read_wt_ugraph(TRIANGLES.as_bytes()).unwrap();
+    // one community for all
+    assert!(close(wtug.modularity(&[Some(0); 6]), 0.0));
+    // a community for every vertex, also if the partition has none or is too short
+    let singletons = -(4.0 * 4.0 + 2.0 * 9.0) / 196.0;
+    assert!(close(
+        wtug.modularity(&[Some(0), Some(1), Some(2), Some(3), Some(4), Some(5)]),
+        singletons
+    ));
+    assert!(close(wtug.modularity(&[None; 6]), singletons));
+    assert!(close(wtug.modularity(&[]), singletons));
+    // the ids of the communities don't matter
+    let partition = [Some(7), Some(7), Some(7), Some(2), Some(2), Some(2)];
+    assert!(close(wtug.modularity(&partition), 5.0 / 14.0));
+
+    // a loop counts twice for the degree and once for the edges inside
+    let wtug: WTUGraph = read_wt_ugraph("2\n2\n0 0\n0 1\n".as_bytes()).unwrap();
+    // (2/4 - (3/4)^2) + (0 - (1/4)^2)
+    assert!(close(wtug.modularity(&[Some(0), Some(1)]), -0.125));
+    assert!(close(wtug.modularity(&[Some(0), Some(0)]), 0.0));
+
+    let wtug: WeightedWTUGraph<f64> = read_weighted_wt_ugraph(PAIRS.as_bytes()).unwrap();
+    // 2 * (20/42 - (21/42)^2)
+    let partition = [Some(0), Some(0), Some(1), Some(1)];
+    assert!(close(wtug.modularity(&partition), 40.0 / 42.0 - 0.5));
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut wtug: WTUGraph = read_wt_ugraph(TRIANGLES.as_bytes()).unwrap();
+    wtug.delete_vertex(2);
+    wtug.commit_edits();
+    let louvain = wtug.louvain();
+    assert_eq!(
+        louvain.communities,
+        vec![Some(0), Some(0), None, Some(1), Some(1), Some(1)]
+    );
+    let result = wtug.label_propagation(1);
+    assert_eq!(result.communities, louvain.communities);
+    assert!(close(result.modularity, louvain.modularity));
+}
+
+#[test]
+fn no_edges() {
+    let wtug: WTUGraph = read_wt_ugraph("3\n0\n".as_bytes()).unwrap();
+    let louvain = wtug.louvain();
+    assert_eq!(louvain.communities, vec![Some(0), Some(1), Some(2)]);
+    assert_eq!(louvain.modularity, 0.0);
+    assert_eq!(wtug.label_propagation(0), louvain);
+    assert_eq!(wtug.modularity(&[Some(0); 3]), 0.0);
+}
@@ -0,0 +1,207 @@
+// The algorithms behind traits::Cores and traits::DirectedCores.
+//
+// Batagelj and Zaversnik's bucket algorithm: the vertices are sorted by degree with a bucket sort, then the vertex with
+// the smallest degree is removed again and again, and every neighbor with a bigger degree moves down one bucket.
+// The degree of a vertex when it's removed is its core number, and the order of removal is a degeneracy order.
+// For in-cores the degree is the number of predecessors and removing a vertex lowers the degrees of its successors,
+// for out-cores the other way round.
+// Wt-graphs don't list the neighbors beforehand: the degrees are counted from the wavelet tree in a first pass, and the
+// neighbors of every vertex are read again when it's removed, so only the degrees and the buckets are kept in memory.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::Graph;
+use crate::wt::directed::WTDigraph;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) fn core_numbers<T, S>(graph: &S) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let neighbors = lists(&vertices, |v| search::successors(graph, v));
+    let (cores, _) = peel(neighbors.iter().map(|n| n.len()).collect(), |v| {
+        neighbors[v].clone()
+    });
+    vertices.into_iter().zip(cores).collect()
+}
+
+pub(crate) fn degeneracy_order<T, S>(graph: &S) -> Vec<T>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let neighbors = lists(&vertices, |v| search::successors(graph, v));
+    let (_, order) = peel(neighbors.iter().map(|n| n.len()).collect(), |v| {
+        neighbors[v].clone()
+    });
+    order.into_iter().map(|v| vertices[v].clone()).collect()
+}
+
+pub(crate) fn in_core_numbers<T, S>(graph: &S) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    directed_core_numbers(graph, true)
+}
+
+pub(crate) fn out_core_numbers<T, S>(graph: &S) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    directed_core_numbers(graph, false)
+}
+
+fn directed_core_numbers<T, S>(graph: &S, incoming: bool) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let successors = lists(&vertices, |v| search::successors(graph, v));
+    let predecessors = lists(&vertices, |v| {
+        graph
+            .predecessors(v)
+            .into_iter()
+            .filter(|p| graph.search_exists(p))
+            .collect()
+    });
+    let (counted, affected) = match incoming {
+        true => (predecessors, successors),
+        false => (successors, predecessors),
+    };
+    let (cores, _) = peel(counted.iter().map(|n| n.len()).collect(), |v| {
+        affected[v].clone()
+    });
+    vertices.into_iter().zip(cores).collect()
+}
+
+// the neighbors of every vertex by position, sorted, without duplicates and the vertex itself
+fn lists<T, F>(vertices: &[T], neighbors: F) -> Vec<Vec<usize>>
+where
+    T: Hash + Eq + Clone,
+    F: Fn(&T) -> Vec<T>,
+{
+    let positions: HashMap<&T, usize> = vertices.iter().enumerate().map(|(i, v)| (v, i)).collect();
+    vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let mut list: Vec<usize> = neighbors(v)
+                .iter()
+                .map(|next| positions[next])
+                .filter(|&j| j != i)
+                .collect();
+            list.sort_unstable();
+            list.dedup();
+            list
+        })
+        .collect()
+}
+
+// `dg` is the wt-digraph inside an undirected wt-graph; `label` returns the vertex of the caller at an index
+pub(crate) fn wt_core_numbers<T, L>(dg: &WTDigraph, label: L) -> HashMap<T, usize>
+where
+    T: Hash + Eq + Clone,
+    L: Fn(usize) -> Option<T>,
+{
+    let (cores, _) = wt_peel(dg);
+    (0..dg.wt_adj_len)
+        .filter(|&v| dg.vertex_exists(v))
+        .filter_map(|v| Some((label(v)?, cores[v])))
+        .collect()
+}
+
+pub(crate) fn wt_degeneracy_order<T, L>(dg: &WTDigraph, label: L) -> Vec<T>
+where
+    T: Hash + Eq + Clone,
+    L: Fn(usize) -> Option<T>,
+{
+    let (_, order) = wt_peel(dg);
+    order
+        .into_iter()
+        .filter(|&v| dg.vertex_exists(v))
+        .filter_map(label)
+        .collect()
+}
+
+// peels all indices; the ones of deleted vertices have no neighbors
+fn wt_peel(dg: &WTDigraph) -> (Vec<usize>, Vec<usize>) {
+    let exists: Vec<bool> = (0..dg.wt_adj_len).map(|v| dg.vertex_exists(v)).collect();
+    let neighbors = |v: usize| -> Vec<usize> {
+        if !exists[v] {
+            return Vec::new();
+        }
+        // each edge is stored once, so the neighbors are both the outgoing and the incoming edges
+        let mut neighbors: Vec<usize> = dg
+            .outgoing_iter(v)
+            .chain(dg.incoming_iter(v))
+            .filter(|&next| next != v && exists[next])
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    };
+    let degrees = (0..dg.wt_adj_len).map(|v| neighbors(v).len()).collect();
+    peel(degrees, neighbors)
+}
+
+// the bucket algorithm on positions 0..degrees.len(); `affected` returns the vertices whose degree drops by one when
+// the vertex is removed, each once. Returns the core number of every position and the order of removal.
+fn peel<F>(mut degrees: Vec<usize>, affected: F) -> (Vec<usize>, Vec<usize>)
+where
+    F: Fn(usize) -> Vec<usize>,
+{
+    let len = degrees.len();
+    let max = degrees.iter().copied().max().unwrap_or(0);
+    // the start of every bucket in `order`
+    let mut starts = vec![0; max + 1];
+    for &degree in degrees.iter() {
+        starts[degree] += 1;
+    }
+    let mut start = 0;
+    for bucket in starts.iter_mut() {
+        let size = *bucket;
+        *bucket = start;
+        start += size;
+    }
+    // the vertices sorted by degree, and the position of every vertex in it
+    let mut order = vec![0; len];
+    let mut positions = vec![0; len];
+    for v in 0..len {
+        positions[v] = starts[degrees[v]];
+        order[positions[v]] = v;
+        starts[degrees[v]] += 1;
+    }
+    for degree in (1..=max).rev() {
+        starts[degree] = starts[degree - 1];
+    }
+    starts[0] = 0;
+
+    for i in 0..len {
+        let v = order[i];
+        for u in affected(v) {
+            if degrees[u] <= degrees[v] {
+                continue;
+            }
+            // swap u with the first vertex of its bucket, then move the start of the bucket behind it
+            let degree = degrees[u];
+            let first = order[starts[degree]];
+            if u != first {
+                order.swap(positions[u], starts[degree]);
+                positions[first] = positions[u];
+                positions[u] = starts[degree];
+            }
+            starts[degree] += 1;
+            degrees[u] -= 1;
+        }
+    }
+    (degrees, order)
+}
@@ -0,0 +1,172 @@
+use crate::graph::undirected::UGraph;
+use crate::io::*;
+use crate::traits::{Cores, DirectedCores, Graph, Undirected, WT};
+use crate::wt::undirected::WTUGraph;
+use std::collections::HashMap;
+
+//   0 === 1     a clique 0 1 2 3 with a parallel edge 0 - 1,
+//   | \ / | \
+//   |  X  |  4 - 5 - 6     7 (self-loop)
+//   | / \ | /
+//   2 --- 3 (0 - 4 and 1 - 4)
+const TEXT: &str = "8\n12\n0 1\n0 2\n0 3\n1 2\n1 3\n2 3\n0 4\n1 4\n4 5\n5 6\n7 7\n0 1\n";
+const WEIGHTED: &str =
+    "8\n12\n0 1 1\n0 2 1\n0 3 1\n1 2 1\n1 3 1\n2 3 1\n0 4 1\n1 4 1\n4 5 1\n5 6 1\n7 7 1\n0 1 1\n";
+
+const CORES: [usize; 8] = [3, 3, 3, 3, 2, 1, 1, 0];
+
+// every vertex has at most as many neighbors after it as the biggest core number
+fn check_order<G: Undirected<usize> + Graph<usize>>(
+    graph: &G,
+    order: &[usize],
+    cores: &HashMap<usize, usize>,
+) {
+    let degeneracy = cores.values().copied().max().unwrap_or(0);
+    let mut sorted = order.to_vec();
+    sorted.sort_unstable();
+    let mut expected: Vec<usize> = cores.keys().copied().collect();
+    expected.sort_unstable();
+    assert_eq!(sorted, expected);
+    for (i, v) in order.iter().enumerate() {
+        let mut later: Vec<usize> = graph
+            .edges(*v)
+            .into_iter()
+            .filter(|w| order[i + 1..].contains(w))
+            .collect();
+        later.sort_unstable();
+        later.dedup();
+        assert!(later.len() <= degeneracy);
+        if i > 0 {
+            assert!(cores[&order[i - 1]] <= cores[v]);
+        }
+    }
+}
+
+#[test]
+fn core_numbers() {
+    let expected: HashMap<usize, usize> = CORES.into_iter().enumerate().collect();
+    let ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    assert_eq!(ug.core_numbers(), expected);
+    let wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    assert_eq!(wtug.core_numbers(), expected);
+}
+
+#[test]
+fn degeneracy_order() {
+    let ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    check_order(&ug, &ug.degeneracy_order(), &ug.core_numbers());
+    let wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    let order = wtug.degeneracy_order();
+    check_order(&wtug, &order, &wtug.core_numbers());
+    // the 3-core is at the end
+    let mut clique = order[4..].to_vec();
+    clique.sort_unstable();
+    assert_eq!(clique, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    wtug.delete_vertex(3);
+    wtug.commit_edits();
+    let expected = HashMap::from([(0, 2), (1, 2), (2, 2), (4, 2), (5, 1), (6, 1), (7, 0)]);
+    assert_eq!(wtug.core_numbers(), expected);
+    let order = wtug.degeneracy_order();
+    assert!(!order.contains(&3));
+    check_order(&wtug, &order, &expected);
+
+    let mut ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    ug.delete_vertex(3);
+    assert_eq!(ug.core_numbers(), expected);
+}
+
+// the cycle 0 -> 1 -> 2 -> 0, the self-loop 2 -> 2, and 3 -> 0, 3 -> 1; 4 has no edges
+#[test]
+fn directed_cores() {
+    let text = "5\n6\n0 1\n1 2\n2 0\n3 0\n3 1\n2 2\n";
+    let check = |graph: &dyn DirectedCores<usize>| {
+        let in_cores = graph.in_core_numbers();
+        let out_cores = graph.out_core_numbers();
+        assert_eq!(
+            in_cores,
+            HashMap::from([(0, 1), (1, 1), (2, 1), (3, 0), (4, 0)])
+        );
+        assert_eq!(
+            out_cores,
+            HashMap::from([(0, 1), (1, 1), (2, 1), (3, 1), (4, 0)])
+        );
+    };
+    check(&read_digraph(text.as_bytes()).unwrap());
+    check(&read_wt_digraph(text.as_bytes()).unwrap());
+
+    // a vertex in the 2-in-core needs two predecessors which are in it too
+    let text = "4\n7\n0 1\n1 2\n2 0\n0 2\n1 0\n2 1\n3 0\n";
+    let wtd = read_wt_digraph(text.as_bytes()).unwrap();
+    assert_eq!(
+        wtd.in_core_numbers(),
+        HashMap::from([(0, 2), (1, 2), (2, 2), (3, 0)])
+    );
+    assert_eq!(
+        wtd.out_core_numbers(),
+        HashMap::from([(0, 2), (1, 2), (2, 2), (3, 1)])
+    );
+}
+
+#[test]
+fn empty_graph() {
+    let ug = UGraph::new();
+    assert!(ug.core_numbers().is_empty());
+    assert!(ug.degeneracy_order().is_empty());
+    let wtug: WTUGraph = read_wt_ugraph("0\n0\n".as_bytes()).unwrap();
+    assert!(wtug.core_numbers().is_empty());
+    assert!(wtug.degeneracy_order().is_empty());
+}
+
+#[test]
+fn all_types() {
+    fn check<G: Cores<usize>>(graph: G) {
+        assert_eq!(graph.core_numbers()[&4], 2);
+        assert_eq!(graph.degeneracy_order().len(), 8);
+    }
+    fn check_labeled<G: Cores<String>>(graph: G) {
+        assert_eq!(graph.core_numbers()["0"], 3);
+        assert_eq!(graph.degeneracy_order().last().map(|v| v.len()), Some(1));
+    }
+    fn check_directed<G: DirectedCores<usize>>(graph: G) {
+        assert_eq!(graph.in_core_numbers()[&4], 2);
+        assert_eq!(graph.out_core_numbers()[&7], 0);
+    }
+    fn check_labeled_directed<G: DirectedCores<String>>(graph: G) {
+        assert_eq!(graph.in_core_numbers()["6"], 1);
+        assert_eq!(graph.out_core_numbers()["0"], 3);
+    }
+    check(read_ugraph(TEXT.as_bytes()).unwrap());
+    check(read_wt_ugraph(TEXT.as_bytes()).unwrap());
+    check(read_weighted_ugraph::<_, i32>(WEIGHTED.as_bytes()).unwrap());
+    check(read_weighted_wt_ugraph::<_, i32>(WEIGHTED.as_bytes()).unwrap());
+    check_labeled(read_labeled_ugraph::<_, String>(TEXT.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(TEXT.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap());
+
+    // the same edges in both directions
+    let mut symmetric = String::from("8\n24\n");
+    let mut symmetric_weighted = String::from("8\n24\n");
+    for line in TEXT.lines().skip(2) {
+        let (a, b) = line.split_once(' ').unwrap();
+        symmetric += &format!("{a} {b}\n{b} {a}\n");
+        symmetric_weighted += &format!("{a} {b} 1\n{b} {a} 1\n");
+    }
+    check_directed(read_digraph(symmetric.as_bytes()).unwrap());
+    check_directed(read_wt_digraph(symmetric.as_bytes()).unwrap());
+    check_directed(read_weighted_digraph::<_, i32>(symmetric_weighted.as_bytes()).unwrap());
+    check_directed(read_weighted_wt_digraph::<_, i32>(symmetric_weighted.as_bytes()).unwrap());
+    check_labeled_directed(read_labeled_digraph::<_, String>(symmetric.as_bytes()).unwrap());
+    check_labeled_directed(read_labeled_wt_digraph::<_, String>(symmetric.as_bytes()).unwrap());
+    check_labeled_directed(
+        read_labeled_weighted_digraph::<_, String, i32>(symmetric_weighted.as_bytes()).unwrap(),
+    );
+    check_labeled_directed(
+        read_labeled_weighted_wt_digraph::<_, String, i32>(symmetric_weighted.as_bytes()).unwrap(),
+    );
+}
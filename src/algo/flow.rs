@@ -0,0 +1,227 @@
+// The algorithm behind traits::MaxFlow: Dinic's algorithm on the SearchView of a weighted digraph.
+//
+// The residual graph isn't built. An edge can take more flow as long as its flow is below its capacity,
+// and it can give flow back (i.e. the reverse edge of the residual graph) as long as its flow is positive.
+// The edges which can give flow back to a vertex are its incoming edges, so the predecessors of the SearchView
+// are used instead of reversing the edge lists; wt-digraphs answer them with the QW-tree.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::Flow;
+use crate::GraphError;
+use num::Num;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+// an edge with its capacity and its flow so far, by the positions of its vertices
+struct Edge<W> {
+    from: usize,
+    to: usize,
+    capacity: W,
+    flow: W,
+}
+
+// an edge of the residual graph: an edge which can take more flow, or an edge which can give flow back
+#[derive(Clone, Copy)]
+struct Arc {
+    edge: usize,
+    forward: bool,
+}
+
+struct Network<W> {
+    edges: Vec<Edge<W>>,
+    arcs: Vec<Vec<Arc>>, // the arcs leaving every vertex: its outgoing edges, then its incoming edges
+}
+
+impl<W: Num + PartialOrd + Copy> Network<W> {
+    fn residual(&self, arc: Arc) -> W {
+        let edge = &self.edges[arc.edge];
+        if arc.forward {
+            edge.capacity - edge.flow
+        } else {
+            edge.flow
+        }
+    }
+
+    fn head(&self, arc: Arc) -> usize {
+        let edge = &self.edges[arc.edge];
+        if arc.forward {
+            edge.to
+        } else {
+            edge.from
+        }
+    }
+
+    fn tail(&self, arc: Arc) -> usize {
+        let edge = &self.edges[arc.edge];
+        if arc.forward {
+            edge.from
+        } else {
+            edge.to
+        }
+    }
+
+    // the number of arcs on the shortest residual path from the source to every vertex it reaches
+    fn levels(&self, source: usize) -> Vec<Option<usize>> {
+        let mut levels = vec![None; self.arcs.len()];
+        levels[source] = Some(0);
+        let mut queue = VecDeque::from([source]);
+        while let Some(vertex) = queue.pop_front() {
+            for &arc in self.arcs[vertex].iter() {
+                let head = self.head(arc);
+                if levels[head].is_none() && self.residual(arc) > W::zero() {
+                    levels[head] = Some(levels[vertex].unwrap() + 1); // safe, vertex came from the queue
+                    queue.push_back(head);
+                }
+            }
+        }
+        levels
+    }
+
+    // sends flow along shortest residual paths until there is none left (a blocking flow), returns its value.
+    // `next` is the position of the next arc to try of every vertex; arcs which lead nowhere are never tried again.
+    fn blocking_flow(&mut self, source: usize, sink: usize, levels: &mut [Option<usize>]) -> W {
+        let mut next = vec![0; self.arcs.len()];
+        let mut value = W::zero();
+        let mut path: Vec<Arc> = Vec::new();
+        let mut vertex = source;
+        loop {
+            if vertex == sink {
+                let bottleneck = path
+                    .iter()
+                    .map(|&arc| self.residual(arc))
+                    .reduce(|a, b| if b < a { b } else { a })
+                    .unwrap(); // safe, source and sink are different vertices
+                for &arc in path.iter() {
+                    let edge = &mut self.edges[arc.edge];
+                    if arc.forward {
+                        edge.flow = edge.flow + bottleneck;
+                    } else {
+                        edge.flow = edge.flow - bottleneck;
+                    }
+                }
+                value = value + bottleneck;
+                path.clear();
+                vertex = source;
+                continue;
+            }
+            let level = levels[vertex].map(|l| l + 1);
+            let arc = self.arcs[vertex][next[vertex]..]
+                .iter()
+                .position(|&arc| levels[self.head(arc)] == level && self.residual(arc) > W::zero());
+            match arc {
+                Some(offset) => {
+                    next[vertex] += offset;
+                    let arc = self.arcs[vertex][next[vertex]];
+                    path.push(arc);
+                    vertex = self.head(arc);
+                }
+                None => {
+                    // nothing more gets through this vertex in this phase
+                    next[vertex] = self.arcs[vertex].len();
+                    levels[vertex] = None;
+                    match path.pop() {
+                        Some(arc) => vertex = self.tail(arc),
+                        None => return value,
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn max_flow<T, W, S>(graph: &S, source: T, sink: T) -> Result<Flow<T, W>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = W>,
+{
+    if !(graph.search_exists(&source) && graph.search_exists(&sink)) {
+        return Err(GraphError::VertexNotFound);
+    }
+    if source == sink {
+        return Err(GraphError::SourceIsSink);
+    }
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+
+    // parallel edges share their weight, so each pair of vertices is one edge
+    let mut edges = Vec::new();
+    let mut edge_index = HashMap::new();
+    let mut arcs = vec![Vec::new(); vertices.len()];
+    for (from, vertex) in vertices.iter().enumerate() {
+        for next in search::successors(graph, vertex) {
+            let to = positions[&next];
+            if from == to || edge_index.contains_key(&(from, to)) {
+                continue; // a self-loop never carries flow
+            }
+            let capacity = graph.distance(vertex, &next);
+            if capacity < W::zero() {
+                return Err(GraphError::NegativeWeight);
+            }
+            if capacity.partial_cmp(&W::zero()) != Some(Ordering::Greater) {
+                continue; // zero or NaN
+            }
+            edge_index.insert((from, to), edges.len());
+            arcs[from].push(Arc {
+                edge: edges.len(),
+                forward: true,
+            });
+            edges.push(Edge {
+                from,
+                to,
+                capacity,
+                flow: W::zero(),
+            });
+        }
+    }
+    for (to, vertex) in vertices.iter().enumerate() {
+        let mut predecessors = graph.predecessors(vertex);
+        predecessors.retain(|v| graph.search_exists(v));
+        for previous in predecessors {
+            if let Some(&edge) = edge_index.get(&(positions[&previous], to)) {
+                arcs[to].push(Arc {
+                    edge,
+                    forward: false,
+                });
+            }
+        }
+    }
+
+    let (source, sink) = (positions[&source], positions[&sink]);
+    let mut network = Network { edges, arcs };
+    let mut value = W::zero();
+    let source_side = loop {
+        let mut levels = network.levels(source);
+        if levels[sink].is_none() {
+            break levels;
+        }
+        value = value + network.blocking_flow(source, sink, &mut levels);
+    };
+
+    Ok(Flow {
+        value,
+        edges: network
+            .edges
+            .iter()
+            .filter(|edge| edge.flow > W::zero())
+            .map(|edge| {
+                let key = (vertices[edge.from].clone(), vertices[edge.to].clone());
+                (key, edge.flow)
+            })
+            .collect(),
+        source_side: vertices
+            .iter()
+            .zip(source_side)
+            .filter(|(_, level)| level.is_some())
+            .map(|(v, _)| v.clone())
+            .collect(),
+    })
+}
@@ -0,0 +1,104 @@
+use crate::graph::weighted_directed::WeightedDigraph;
+use crate::io::*;
+use crate::traits::{Directed, Flow, Graph, MaxFlow, Weighted, WT};
+use crate::wt::weighted_directed::WeightedWTDigraph;
+use crate::GraphError;
+
+// the network of Cormen et al., with 0 as source and 5 as sink:
+// 0 -> 1: 16, 0 -> 2: 13, 1 -> 3: 12, 2 -> 1: 4, 2 -> 4: 14, 3 -> 2: 9, 3 -> 5: 20, 4 -> 3: 7, 4 -> 5: 4
+const NETWORK: &str = "6\n9\n0 1 16\n0 2 13\n1 3 12\n2 1 4\n2 4 14\n3 2 9\n3 5 20\n4 3 7\n4 5 4\n";
+
+// the flow fits the capacities, and everything which enters a vertex leaves it, except at source and sink
+fn check_flow<G>(graph: &G, flow: &Flow<usize, i32>, source: usize, sink: usize)
+where
+    G: Directed<usize> + Weighted<usize, i32>,
+{
+    let mut balance = [0; 6];
+    for (&(from, to), &f) in flow.edges.iter() {
+        assert!(f > 0 && f <= graph.weight(from, to));
+        balance[from] -= f;
+        balance[to] += f;
+    }
+    for (v, &b) in balance.iter().enumerate() {
+        match v {
+            v if v == source => assert_eq!(b, -flow.value),
+            v if v == sink => assert_eq!(b, flow.value),
+            _ => assert_eq!(b, 0),
+        }
+    }
+
+    // the capacities of the edges leaving the source side add up to the value
+    let cut: i32 = flow
+        .source_side
+        .iter()
+        .flat_map(|&from| {
+            graph
+                .outgoing_edges(from)
+                .into_iter()
+                .map(move |to| (from, to))
+        })
+        .filter(|(_, to)| !flow.source_side.contains(to))
+        .map(|(from, to)| graph.weight(from, to))
+        .sum();
+    assert_eq!(cut, flow.value);
+}
+
+#[test]
+fn max_flow() {
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(NETWORK.as_bytes()).unwrap();
+    let flow = wdg.max_flow(0, 5).unwrap();
+    assert_eq!(flow.value, 23);
+    check_flow(&wdg, &flow, 0, 5);
+    let mut source_side = flow.source_side.clone();
+    source_side.sort();
+    assert_eq!(source_side, vec![0, 1, 2, 4]);
+
+    // no edge leads back to 0
+    let flow = wdg.max_flow(5, 0).unwrap();
+    assert_eq!(flow.value, 0);
+    assert!(flow.edges.is_empty());
+    assert_eq!(flow.source_side, vec![5]);
+
+    let flow = wdg.max_flow(3, 1).unwrap();
+    assert_eq!(flow.value, 4); // 3 -> 2 -> 1
+    check_flow(&wdg, &flow, 3, 1);
+}
+
+#[test]
+fn wt_digraph() {
+    let mut wtd: WeightedWTDigraph<i32> = read_weighted_wt_digraph(NETWORK.as_bytes()).unwrap();
+    let flow = wtd.max_flow(0, 5).unwrap();
+    assert_eq!(flow.value, 23);
+    check_flow(&wtd, &flow, 0, 5);
+
+    // the edges to a deleted vertex stay in the wavelet tree, but carry no flow
+    wtd.delete_vertex(3);
+    wtd.commit_edits();
+    let flow = wtd.max_flow(0, 5).unwrap();
+    assert_eq!(flow.value, 4);
+    assert!(flow.edges.keys().all(|&(from, to)| from != 3 && to != 3));
+
+    // uncommitted edges don't count
+    wtd.add_edge(1, 5, 10);
+    assert_eq!(wtd.max_flow(0, 5).unwrap().value, 4);
+}
+
+#[test]
+fn float_capacities() {
+    let text = "4\n5\n0 1 0.5\n0 2 NaN\n1 3 1.5\n2 3 2.0\n1 2 0.25\n";
+    let wdg: WeightedDigraph<f64> = read_weighted_digraph(text.as_bytes()).unwrap();
+    let flow = wdg.max_flow(0, 3).unwrap();
+    assert_eq!(flow.value, 0.5);
+    assert!(!flow.edges.contains_key(&(0, 2)));
+}
+
+#[test]
+fn errors() {
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(NETWORK.as_bytes()).unwrap();
+    assert_eq!(wdg.max_flow(0, 6).unwrap_err(), GraphError::VertexNotFound);
+    assert_eq!(wdg.max_flow(2, 2).unwrap_err(), GraphError::SourceIsSink);
+
+    let text = "3\n2\n0 1 -1\n1 2 1\n";
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(text.as_bytes()).unwrap();
+    assert_eq!(wdg.max_flow(0, 2).unwrap_err(), GraphError::NegativeWeight);
+}
@@ -0,0 +1,195 @@
+// The algorithms behind traits::Neighborhood.
+//
+// Every graph type collects the neighbors of both vertices into sets through its SearchView.
+// Wt-graphs don't collect anything: they read the distinct neighbors of the vertex with fewer edges from the wavelet
+// tree and check with rank queries whether the other vertex has an edge to or from each of them. qwt can't intersect
+// two ranges directly, so this takes O(d log n) time, where d is the smaller degree, and no memory besides the result.
+// Outgoing edges are read from the range of the vertex, where a repeated vertex is recognized with two rank queries,
+// incoming edges with select queries, which find them in order of the sources, so repeated sources are next to each
+// other.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::{Directed, Graph};
+use crate::wt::directed::WTDigraph;
+use crate::GraphError;
+use qwt::AccessUnsigned;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+// the edges of a vertex which count as its neighbors
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Direction {
+    Outgoing,
+    Incoming,
+    Undirected, // the wt-digraph inside an undirected wt-graph, where every edge is stored in one direction
+}
+
+pub(crate) fn common_neighbors<T, S>(
+    graph: &S,
+    u: &T,
+    v: &T,
+    incoming: bool,
+) -> Result<Vec<T>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let (u_neighbors, v_neighbors) = neighbor_sets(graph, u, v, incoming)?;
+    Ok(u_neighbors.intersection(&v_neighbors).cloned().collect())
+}
+
+pub(crate) fn jaccard<T, S>(graph: &S, u: &T, v: &T) -> Result<f64, GraphError>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let (u_neighbors, v_neighbors) = neighbor_sets(graph, u, v, false)?;
+    let common = u_neighbors.intersection(&v_neighbors).count();
+    Ok(ratio(common, u_neighbors.len() + v_neighbors.len() - common))
+}
+
+fn neighbor_sets<T, S>(
+    graph: &S,
+    u: &T,
+    v: &T,
+    incoming: bool,
+) -> Result<(HashSet<T>, HashSet<T>), GraphError>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    if !(graph.search_exists(u) && graph.search_exists(v)) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let set = |vertex: &T| -> HashSet<T> {
+        match incoming {
+            true => graph
+                .predecessors(vertex)
+                .into_iter()
+                .filter(|w| graph.search_exists(w))
+                .collect(),
+            false => search::successors(graph, vertex).into_iter().collect(),
+        }
+    };
+    Ok((set(u), set(v)))
+}
+
+// `index` returns the index of a vertex of the caller, `label` the vertex at an index
+pub(crate) fn wt_common_neighbors<T, I, L>(
+    dg: &WTDigraph,
+    u: &T,
+    v: &T,
+    direction: Direction,
+    index: I,
+    label: L,
+) -> Result<Vec<T>, GraphError>
+where
+    I: Fn(&T) -> Option<usize>,
+    L: Fn(usize) -> Option<T>,
+{
+    let (u, v) = indices(dg, u, v, index)?;
+    let mut common: Vec<usize> = common(dg, u, v, direction).collect();
+    common.sort_unstable();
+    Ok(common.into_iter().filter_map(label).collect())
+}
+
+pub(crate) fn wt_jaccard<T, I>(
+    dg: &WTDigraph,
+    u: &T,
+    v: &T,
+    direction: Direction,
+    index: I,
+) -> Result<f64, GraphError>
+where
+    I: Fn(&T) -> Option<usize>,
+{
+    let (u, v) = indices(dg, u, v, index)?;
+    let common = common(dg, u, v, direction).count();
+    let union = neighbors(dg, u, direction).count() + neighbors(dg, v, direction).count() - common;
+    Ok(ratio(common, union))
+}
+
+fn indices<T, I>(dg: &WTDigraph, u: &T, v: &T, index: I) -> Result<(usize, usize), GraphError>
+where
+    I: Fn(&T) -> Option<usize>,
+{
+    match (index(u), index(v)) {
+        (Some(u), Some(v)) if dg.vertex_exists(u) && dg.vertex_exists(v) => Ok((u, v)),
+        _ => Err(GraphError::VertexNotFound),
+    }
+}
+
+fn ratio(common: usize, union: usize) -> f64 {
+    match union {
+        0 => 0.0,
+        union => common as f64 / union as f64,
+    }
+}
+
+// the common neighbors, found among the neighbors of the vertex with fewer edges
+fn common(
+    dg: &WTDigraph,
+    u: usize,
+    v: usize,
+    direction: Direction,
+) -> impl Iterator<Item = usize> + '_ {
+    let degree = |vertex: usize| match direction {
+        Direction::Outgoing => dg.out_degree(vertex),
+        Direction::Incoming => dg.in_degree(vertex),
+        Direction::Undirected => dg.undirected_degree(vertex),
+    };
+    let (smaller, bigger) = match degree(u) <= degree(v) {
+        true => (u, v),
+        false => (v, u),
+    };
+    neighbors(dg, smaller, direction).filter(move |&w| adjacent(dg, bigger, w, direction))
+}
+
+// the neighbors of a vertex which exist, each once
+fn neighbors(
+    dg: &WTDigraph,
+    vertex: usize,
+    direction: Direction,
+) -> Box<dyn Iterator<Item = usize> + '_> {
+    match direction {
+        Direction::Outgoing => Box::new(outgoing(dg, vertex)),
+        Direction::Incoming => Box::new(incoming(dg, vertex)),
+        // a vertex with edges in both directions, or a loop, is found among the incoming edges already
+        Direction::Undirected => Box::new(
+            incoming(dg, vertex).chain(
+                outgoing(dg, vertex)
+                    .filter(move |&w| w != vertex && dg.edge_count(w, vertex) == 0),
+            ),
+        ),
+    }
+}
+
+fn outgoing(dg: &WTDigraph, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+    let (start, end) = dg.outgoing_range(vertex);
+    (start..end).filter_map(move |pos| {
+        let next = dg.wt_adj.get(pos).unwrap(); // safe, pos is inside the range
+        let first = dg.occurrences(next, (start, pos)) == 0;
+        (first && dg.vertex_exists(next)).then_some(next)
+    })
+}
+
+fn incoming(dg: &WTDigraph, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+    let mut previous = None;
+    dg.incoming_iter(vertex).filter(move |&from| {
+        let first = previous != Some(from);
+        previous = Some(from);
+        first && dg.vertex_exists(from)
+    })
+}
+
+// whether `vertex` has an edge to `other`, from it, or either
+fn adjacent(dg: &WTDigraph, vertex: usize, other: usize, direction: Direction) -> bool {
+    match direction {
+        Direction::Outgoing => dg.edge_count(vertex, other) > 0,
+        Direction::Incoming => dg.edge_count(other, vertex) > 0,
+        Direction::Undirected => dg.edge_count(vertex, other) > 0 || dg.edge_count(other, vertex) > 0,
+    }
+}
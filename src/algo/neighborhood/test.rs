@@ -0,0 +1,141 @@
+use crate::graph::{directed::Digraph, undirected::UGraph};
+use crate::io::*;
+use crate::traits::{Graph, Neighborhood, Unweighted, WT};
+use crate::wt::{directed::WTDigraph, undirected::WTUGraph};
+use crate::GraphError;
+
+// 0 -> 2, 3 (twice), 4    1 -> 3, 4, 5    2, 5, 6 -> 0    6 -> 1    3 -> 3    7 has no edges
+const DIRECTED: &str = "8\n12\n0 2\n0 3\n0 3\n0 4\n1 3\n1 4\n1 5\n2 0\n5 0\n6 0\n6 1\n3 3\n";
+
+// 0 - 1, 0 - 2 (twice), 0 - 3, 1 - 2, 1 - 3, 3 - 4, and a loop at 2
+const UNDIRECTED: &str = "5\n8\n0 1\n0 2\n0 2\n0 3\n1 2\n1 3\n3 4\n2 2\n";
+
+fn sorted<G: Neighborhood<usize>>(graph: &G, u: usize, v: usize, incoming: bool) -> Vec<usize> {
+    let mut common = match incoming {
+        true => graph.common_in_neighbors(u, v).unwrap(),
+        false => graph.common_out_neighbors(u, v).unwrap(),
+    };
+    common.sort_unstable();
+    common
+}
+
+#[test]
+fn directed() {
+    let dg: Digraph = read_digraph(DIRECTED.as_bytes()).unwrap();
+    let wtd: WTDigraph = read_wt_digraph(DIRECTED.as_bytes()).unwrap();
+    for graph in [&dg as &dyn Neighborhood<usize>, &wtd] {
+        let common_out = |u, v| {
+            let mut common = graph.common_out_neighbors(u, v).unwrap();
+            common.sort_unstable();
+            common
+        };
+        let common_in = |u, v| {
+            let mut common = graph.common_in_neighbors(u, v).unwrap();
+            common.sort_unstable();
+            common
+        };
+        assert_eq!(common_out(0, 1), vec![3, 4]);
+        assert_eq!(common_out(1, 0), vec![3, 4]);
+        assert_eq!(common_out(3, 0), vec![3]);
+        assert_eq!(common_out(0, 0), vec![2, 3, 4]);
+        assert_eq!(common_out(7, 0), vec![]);
+        assert_eq!(common_in(0, 1), vec![6]);
+        assert_eq!(common_in(3, 4), vec![0, 1]);
+
+        assert_eq!(graph.jaccard(0, 1), Ok(0.5));
+        assert_eq!(graph.jaccard(0, 0), Ok(1.0));
+        assert_eq!(graph.jaccard(7, 4), Ok(0.0));
+        assert_eq!(
+            graph.common_out_neighbors(0, 8),
+            Err(GraphError::VertexNotFound)
+        );
+        assert_eq!(
+            graph.common_in_neighbors(8, 0),
+            Err(GraphError::VertexNotFound)
+        );
+        assert_eq!(graph.jaccard(8, 8), Err(GraphError::VertexNotFound));
+    }
+    // in the order of the indices
+    assert_eq!(wtd.common_in_neighbors(3, 4), Ok(vec![0, 1]));
+}
+
+#[test]
+fn undirected() {
+    let ug: UGraph = read_ugraph(UNDIRECTED.as_bytes()).unwrap();
+    let wtug: WTUGraph = read_wt_ugraph(UNDIRECTED.as_bytes()).unwrap();
+    assert_eq!(sorted(&ug, 0, 1, false), vec![2, 3]);
+    assert_eq!(sorted(&wtug, 0, 1, false), vec![2, 3]);
+    assert_eq!(sorted(&ug, 2, 3, true), vec![0, 1]);
+    assert_eq!(sorted(&wtug, 2, 3, true), vec![0, 1]);
+    assert_eq!(sorted(&ug, 2, 2, false), vec![0, 1, 2]);
+    assert_eq!(sorted(&wtug, 2, 2, false), vec![0, 1, 2]);
+    assert_eq!(ug.jaccard(0, 1), Ok(0.5));
+    assert_eq!(wtug.jaccard(0, 1), Ok(0.5));
+    assert_eq!(wtug.jaccard(4, 2), Ok(0.0));
+
+    // an edge added to a wt-graph is stored at the vertex it was added from
+    let mut wtug = wtug;
+    wtug.add_edge(4, 0);
+    wtug.commit_edits();
+    assert_eq!(wtug.common_out_neighbors(0, 3), Ok(vec![1, 4]));
+    assert_eq!(wtug.common_out_neighbors(4, 1), Ok(vec![0, 3]));
+    assert_eq!(wtug.jaccard(0, 3), Ok(0.4));
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut wtd: WTDigraph = read_wt_digraph(DIRECTED.as_bytes()).unwrap();
+    wtd.delete_vertex(3);
+    wtd.commit_edits();
+    assert_eq!(wtd.common_out_neighbors(0, 1), Ok(vec![4]));
+    assert_eq!(wtd.jaccard(0, 1), Ok(1.0 / 3.0));
+    assert_eq!(
+        wtd.common_out_neighbors(0, 3),
+        Err(GraphError::VertexNotFound)
+    );
+
+    let mut dg: Digraph = read_digraph(DIRECTED.as_bytes()).unwrap();
+    dg.delete_vertex(3);
+    assert_eq!(dg.common_out_neighbors(0, 1), Ok(vec![4]));
+    assert_eq!(dg.jaccard(0, 1), Ok(1.0 / 3.0));
+
+    let mut wtug: WTUGraph = read_wt_ugraph(UNDIRECTED.as_bytes()).unwrap();
+    wtug.delete_vertex(2);
+    wtug.commit_edits();
+    assert_eq!(wtug.common_out_neighbors(0, 1), Ok(vec![3]));
+    assert_eq!(wtug.jaccard(0, 1), Ok(1.0 / 3.0));
+}
+
+#[test]
+fn all_types() {
+    let weighted = "4\n4\n0 1 1\n0 2 1\n3 1 1\n3 2 1\n";
+    let text = "4\n4\n0 1\n0 2\n3 1\n3 2\n";
+    fn check<G: Neighborhood<usize>>(graph: G) {
+        assert_eq!(sorted(&graph, 0, 3, false), vec![1, 2]);
+        assert_eq!(graph.jaccard(0, 3), Ok(1.0));
+    }
+    fn check_labeled<G: Neighborhood<String>>(graph: G) {
+        let mut common = graph
+            .common_out_neighbors("0".to_string(), "3".to_string())
+            .unwrap();
+        common.sort();
+        assert_eq!(common, vec!["1", "2"]);
+        assert_eq!(graph.jaccard("0".to_string(), "1".to_string()), Ok(0.0));
+    }
+    check(read_digraph(text.as_bytes()).unwrap());
+    check(read_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_ugraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_wt_digraph(text.as_bytes()).unwrap());
+    check(read_wt_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_wt_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_wt_ugraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+}
@@ -0,0 +1,174 @@
+// The algorithm behind traits::PageRank: power iteration on the positions of the vertices.
+//
+// Every graph type uses the push formulation on its SearchView, except for wt-digraphs: they pull the scores along
+// the incoming edges, which the QW-tree answers with select, and read the out-degrees from starting_indices,
+// so nothing of the graph is copied.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::{Graph, PageRankOptions};
+use crate::wt::directed::WTDigraph;
+use crate::GraphError;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) fn pagerank<T, S>(
+    graph: &S,
+    options: &PageRankOptions<T>,
+) -> Result<HashMap<T, f64>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+    let successors: Vec<Vec<usize>> = vertices
+        .iter()
+        .map(|v| {
+            search::successors(graph, v)
+                .iter()
+                .map(|next| positions[next])
+                .collect()
+        })
+        .collect();
+    let exists = vec![true; vertices.len()];
+    let out_degrees: Vec<usize> = successors.iter().map(|s| s.len()).collect();
+    let teleport = teleport(options, &exists, |v| positions.get(v).copied())?;
+
+    let scores = power_iteration(&exists, &out_degrees, &teleport, options, |shares, next| {
+        for (from, targets) in successors.iter().enumerate() {
+            for &to in targets.iter() {
+                next[to] += shares[from];
+            }
+        }
+    });
+    Ok(vertices.into_iter().zip(scores).collect())
+}
+
+// the positions are the indices of `dg`; `index` and `label` translate between them and the vertices of the caller
+pub(crate) fn wt_pagerank<T, I, L>(
+    dg: &WTDigraph,
+    options: &PageRankOptions<T>,
+    index: I,
+    label: L,
+) -> Result<HashMap<T, f64>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    I: Fn(&T) -> Option<usize>,
+    L: Fn(usize) -> Option<T>,
+{
+    let exists: Vec<bool> = (0..dg.wt_adj_len).map(|v| dg.vertex_exists(v)).collect();
+    let out_degrees: Vec<usize> = (0..dg.wt_adj_len)
+        .map(|v| match exists[v] {
+            // the edges to deleted vertices are still in the tree, so they have to be left out one by one
+            true if !dg.deleted_vertices.is_empty() => {
+                dg.outgoing_iter(v).filter(|&to| exists[to]).count()
+            }
+            true => {
+                let (start, end) = dg.outgoing_range(v);
+                end - start
+            }
+            false => 0,
+        })
+        .collect();
+    let teleport = teleport(options, &exists, |v| {
+        index(v).filter(|&i| exists.get(i) == Some(&true))
+    })?;
+
+    let scores = power_iteration(&exists, &out_degrees, &teleport, options, |shares, next| {
+        for (to, score) in next.iter_mut().enumerate() {
+            if exists[to] {
+                *score += dg.incoming_iter(to).map(|from| shares[from]).sum::<f64>();
+            }
+        }
+    });
+    Ok(scores
+        .into_iter()
+        .enumerate()
+        .filter(|&(v, _)| exists[v])
+        .filter_map(|(v, score)| Some((label(v)?, score)))
+        .collect())
+}
+
+// the probability of jumping to every position, which add up to 1
+fn teleport<T, P>(
+    options: &PageRankOptions<T>,
+    exists: &[bool],
+    position: P,
+) -> Result<Vec<f64>, GraphError>
+where
+    P: Fn(&T) -> Option<usize>,
+{
+    let mut teleport = vec![0.0; exists.len()];
+    if let Some(personalization) = &options.personalization {
+        for (vertex, &p) in personalization.iter() {
+            let i = position(vertex).ok_or(GraphError::VertexNotFound)?;
+            if p.is_nan() || p < 0.0 {
+                return Err(GraphError::NegativeWeight);
+            }
+            teleport[i] += p;
+        }
+    }
+    let sum: f64 = teleport.iter().sum();
+    if sum > 0.0 {
+        teleport.iter_mut().for_each(|p| *p /= sum);
+    } else {
+        let count = exists.iter().filter(|&&e| e).count();
+        for (p, &e) in teleport.iter_mut().zip(exists) {
+            if e {
+                *p = 1.0 / count as f64;
+            }
+        }
+    }
+    Ok(teleport)
+}
+
+// `spread` adds the share of every position to the next scores of its successors
+fn power_iteration<T, F>(
+    exists: &[bool],
+    out_degrees: &[usize],
+    teleport: &[f64],
+    options: &PageRankOptions<T>,
+    spread: F,
+) -> Vec<f64>
+where
+    F: Fn(&[f64], &mut [f64]),
+{
+    let mut scores = teleport.to_vec();
+    let mut shares = vec![0.0; scores.len()];
+    let mut next = vec![0.0; scores.len()];
+    for _ in 0..options.max_iterations {
+        let mut dangling = 0.0;
+        for (v, share) in shares.iter_mut().enumerate() {
+            *share = 0.0;
+            if exists[v] {
+                match out_degrees[v] {
+                    0 => dangling += scores[v],
+                    d => *share = scores[v] / d as f64,
+                }
+            }
+        }
+        next.iter_mut().for_each(|s| *s = 0.0);
+        spread(&shares, &mut next);
+
+        let mut change = 0.0;
+        for (v, score) in next.iter_mut().enumerate() {
+            if exists[v] {
+                *score = options.damping * (*score + dangling * teleport[v])
+                    + (1.0 - options.damping) * teleport[v];
+                change += (*score - scores[v]).abs();
+            }
+        }
+        std::mem::swap(&mut scores, &mut next);
+        if change < options.tolerance {
+            break;
+        }
+    }
+    scores
+}
@@ -0,0 +1,150 @@
+use crate::graph::directed::Digraph;
+use crate::io::*;
+use crate::traits::{Graph, PageRank, PageRankOptions, WT};
+use crate::wt::directed::WTDigraph;
+use crate::GraphError;
+use std::collections::HashMap;
+
+// 0 -> 1, 0 -> 2, 1 -> 2, 2 -> 0
+const TEXT: &str = "3\n4\n0 1\n0 2\n1 2\n2 0\n";
+
+fn options() -> PageRankOptions<usize> {
+    PageRankOptions {
+        tolerance: 1e-12,
+        max_iterations: 1000,
+        ..Default::default()
+    }
+}
+
+fn assert_close(scores: &HashMap<usize, f64>, expected: &[(usize, f64)], epsilon: f64) {
+    assert_eq!(scores.len(), expected.len());
+    for &(v, score) in expected {
+        assert!(
+            (scores[&v] - score).abs() < epsilon,
+            "{}: {} != {}",
+            v,
+            scores[&v],
+            score
+        );
+    }
+}
+
+#[test]
+fn pagerank() {
+    let expected = [(0, 0.387789), (1, 0.214811), (2, 0.397400)];
+    let dg = read_digraph(TEXT.as_bytes()).unwrap();
+    assert_close(&dg.pagerank(&options()).unwrap(), &expected, 1e-6);
+    let wtd = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    assert_close(&wtd.pagerank(&options()).unwrap(), &expected, 1e-6);
+
+    // a cycle ranks every vertex the same
+    let cycle = Digraph::from_adjacency_list(3, 3, vec![vec![1], vec![2], vec![0]]);
+    let third = 1.0 / 3.0;
+    let expected = [(0, third), (1, third), (2, third)];
+    assert_close(&cycle.pagerank(&options()).unwrap(), &expected, 1e-9);
+}
+
+#[test]
+fn dangling_vertices() {
+    // 0 -> 1 -> 2, 3 -> 2, 2 and 4 are dangling
+    let adj = vec![vec![1], vec![2], vec![], vec![2], vec![]];
+    let dg = Digraph::from_adjacency_list(5, 3, adj);
+    let scores = dg.pagerank(&options()).unwrap();
+    assert!((scores.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(scores[&2] > scores[&1] && scores[&1] > scores[&0]);
+    assert!((scores[&0] - scores[&4]).abs() < 1e-9);
+
+    let wtd = WTDigraph::from_digraph(dg);
+    let wt_scores = wtd.pagerank(&options()).unwrap();
+    for (v, score) in scores {
+        assert!((wt_scores[&v] - score).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn personalization() {
+    let dg = read_digraph(TEXT.as_bytes()).unwrap();
+    let options = PageRankOptions {
+        personalization: Some(HashMap::from([(1, 2.0)])),
+        ..options()
+    };
+    let scores = dg.pagerank(&options).unwrap();
+    assert!((scores.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(scores[&1] > 0.25); // 0.214811 without personalization
+
+    // 0 -> 1, nothing leads to 2 and nothing jumps to it
+    let dg = Digraph::from_adjacency_list(3, 1, vec![vec![1], vec![], vec![]]);
+    let options = PageRankOptions {
+        personalization: Some(HashMap::from([(0, 1.0), (2, 0.0)])),
+        ..options
+    };
+    let scores = dg.pagerank(&options).unwrap();
+    assert_eq!(scores[&2], 0.0);
+    assert!((scores[&0] + scores[&1] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut dg = read_digraph(TEXT.as_bytes()).unwrap();
+    let mut wtd = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    dg.delete_vertex(1);
+    wtd.delete_vertex(1);
+    wtd.commit_edits();
+
+    // 0 <-> 2
+    let expected = [(0, 0.5), (2, 0.5)];
+    assert_close(&dg.pagerank(&options()).unwrap(), &expected, 1e-9);
+    assert_close(&wtd.pagerank(&options()).unwrap(), &expected, 1e-9);
+}
+
+#[test]
+fn errors() {
+    let wtd = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    let missing = PageRankOptions {
+        personalization: Some(HashMap::from([(3, 1.0)])),
+        ..options()
+    };
+    assert_eq!(wtd.pagerank(&missing), Err(GraphError::VertexNotFound));
+    let negative = PageRankOptions {
+        personalization: Some(HashMap::from([(0, -1.0)])),
+        ..options()
+    };
+    assert_eq!(wtd.pagerank(&negative), Err(GraphError::NegativeWeight));
+}
+
+#[test]
+fn labeled() {
+    let options = PageRankOptions {
+        personalization: Some(HashMap::from([("0".to_string(), 1.0)])),
+        ..PageRankOptions::default()
+    };
+    let check = |scores: HashMap<String, f64>| {
+        assert_eq!(scores.len(), 3);
+        assert!(scores["0"] > scores["1"]);
+    };
+    check(
+        read_labeled_digraph(TEXT.as_bytes())
+            .unwrap()
+            .pagerank(&options)
+            .unwrap(),
+    );
+    check(
+        read_labeled_wt_digraph(TEXT.as_bytes())
+            .unwrap()
+            .pagerank(&options)
+            .unwrap(),
+    );
+    let weighted = "3\n4\n0 1 1\n0 2 1\n1 2 1\n2 0 1\n";
+    check(
+        read_labeled_weighted_digraph::<_, String, i32>(weighted.as_bytes())
+            .unwrap()
+            .pagerank(&options)
+            .unwrap(),
+    );
+    check(
+        read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes())
+            .unwrap()
+            .pagerank(&options)
+            .unwrap(),
+    );
+}
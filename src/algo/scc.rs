@@ -0,0 +1,150 @@
+//! Strongly connected components and the condensation of wt-digraphs.
+//!
+//! The components are found with Kosaraju's algorithm: a depth-first search over the outgoing edges orders the
+//! vertices by the time they are finished, then a depth-first search over the incoming edges, which the QW-tree
+//! answers with a few select queries, collects the components in reverse order of that time.
+//! No copy of the adjacency is needed for the reversed graph.
+//!
+//! The components are numbered in topological order: every edge between two components goes from the component
+//! with the smaller id to the one with the bigger id. Both functions look at the graph at the last commit.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::prelude::*;
+//!
+//! // 0 <-> 1 -> 2 <-> 3
+//! let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(
+//!     4,
+//!     5,
+//!     vec![vec![1], vec![0, 2], vec![3], vec![2]],
+//! ));
+//! let components = wtd.strongly_connected_components();
+//! assert_eq!(components, vec![Some(0), Some(0), Some(1), Some(1)]);
+//!
+//! let dag = wtd.condensation();
+//! assert_eq!(dag.v_count(), 2);
+//! assert_eq!(dag.outgoing_edges(0), vec![1]);
+//! ```
+
+use crate::algo::traversal::{Dfs, Event, Neighbors};
+use crate::graph::directed::Digraph;
+use crate::traits::{Graph, Labeled};
+use crate::wt::directed::WTDigraph;
+use crate::wt::labeled_directed::LabeledWTDigraph;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+impl WTDigraph {
+    /// Returns the id of the strongly connected component of every vertex at the last commit, by index.
+    /// Deleted vertices have no component. The ids are in topological order, see the module documentation.
+    pub fn strongly_connected_components(&self) -> Vec<Option<usize>> {
+        let mut components = vec![None; self.wt_adj_len];
+        let mut vertices = (0..self.wt_adj_len).filter(|&v| self.vertex_exists(v));
+        let Some(first) = vertices.next() else {
+            return components;
+        };
+
+        // first pass: the vertices in the order they are finished
+        let mut dfs = Dfs::new(Outgoing(self), first);
+        vertices.for_each(|vertex| dfs.restart_from(vertex));
+        let finished: Vec<usize> = dfs.finished().collect();
+
+        // second pass: the reversed graph, each start vertex which hasn't been discovered begins a new component
+        let mut order = finished.into_iter().rev();
+        let mut dfs = Dfs::new(Incoming(self), order.next().unwrap()); // safe, there is a vertex
+        order.for_each(|vertex| dfs.restart_from(vertex));
+        let mut count = 0;
+        for event in dfs {
+            if let Event::Discover { vertex, parent, .. } = event {
+                if parent.is_none() {
+                    count += 1;
+                }
+                components[vertex] = Some(count - 1);
+            }
+        }
+        components
+    }
+
+    /// Returns the condensation of the graph at the last commit: a wt-digraph with one vertex per strongly connected
+    /// component (the index is the id of the component) and an edge between two components if there is at least one
+    /// edge between their vertices. The condensation has no cycles.
+    pub fn condensation(&self) -> WTDigraph {
+        condensation(self, &self.strongly_connected_components())
+    }
+}
+
+impl<L> LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    /// Returns the id of the strongly connected component of every vertex at the last commit, by label.
+    /// The ids are in topological order, see the module documentation.
+    pub fn strongly_connected_components(&self) -> HashMap<L, usize> {
+        self.dg
+            .strongly_connected_components()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(v, component)| Some((self.label(v)?.clone(), component?)))
+            .collect()
+    }
+
+    /// Returns the condensation of the graph at the last commit, see `WTDigraph::condensation`.
+    /// The vertices of the condensation are indexed by the ids of the components.
+    pub fn condensation(&self) -> WTDigraph {
+        self.dg.condensation()
+    }
+}
+
+// the outgoing edges at last commit, leaving out deleted vertices.
+// wt-digraphs keep the edges to a deleted vertex, but the search must not go there.
+struct Outgoing<'a>(&'a WTDigraph);
+
+impl Neighbors<usize> for Outgoing<'_> {
+    fn neighbors(&self, vertex: &usize) -> Vec<usize> {
+        let dg = self.0;
+        dg.outgoing_iter(*vertex)
+            .filter(|&v| dg.vertex_exists(v))
+            .collect()
+    }
+}
+
+// the incoming edges at last commit; edges from deleted vertices are left out by the commit
+struct Incoming<'a>(&'a WTDigraph);
+
+impl Neighbors<usize> for Incoming<'_> {
+    fn neighbors(&self, vertex: &usize) -> Vec<usize> {
+        let dg = self.0;
+        dg.incoming_iter(*vertex)
+            .filter(|&v| dg.vertex_exists(v))
+            .collect()
+    }
+}
+
+fn condensation(dg: &WTDigraph, components: &[Option<usize>]) -> WTDigraph {
+    let count = components.iter().flatten().max().map_or(0, |max| max + 1);
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (from, component) in components.iter().enumerate() {
+        let Some(from_component) = component else {
+            continue;
+        };
+        for to in dg.outgoing_iter(from) {
+            match components.get(to).copied().flatten() {
+                Some(to_component) if to_component != *from_component => {
+                    adj[*from_component].push(to_component)
+                }
+                _ => {}
+            }
+        }
+    }
+    let mut e_count = 0;
+    for targets in adj.iter_mut() {
+        targets.sort_unstable();
+        targets.dedup();
+        e_count += targets.len();
+    }
+    WTDigraph::from_digraph(Digraph::from_adjacency_list(count, e_count, adj))
+}
@@ -0,0 +1,123 @@
+use crate::graph::{directed::Digraph, labeled_directed::LabeledDigraph};
+use crate::traits::{Directed, Graph, WT};
+use crate::wt::{directed::WTDigraph, labeled_directed::LabeledWTDigraph};
+use std::collections::HashMap;
+
+//   0 <-> 1 -> 2 -> 3 -> 4
+//         |    ^    |    ^
+//         v    |    v    |
+//         5    +--- 6    7 (self-loop)
+fn wtd() -> WTDigraph {
+    let adj = vec![
+        vec![1],
+        vec![0, 2, 5],
+        vec![3],
+        vec![4, 6],
+        vec![],
+        vec![],
+        vec![2],
+        vec![4, 7],
+    ];
+    WTDigraph::from_digraph(Digraph::from_adjacency_list(8, 10, adj))
+}
+
+#[test]
+fn strongly_connected_components() {
+    let wtd = wtd();
+    let components = wtd.strongly_connected_components();
+    let component = |v: usize| components[v].unwrap();
+
+    assert_eq!(components.iter().flatten().max(), Some(&4)); // 5 components
+    assert_eq!(component(0), component(1));
+    assert_eq!(component(2), component(3));
+    assert_eq!(component(3), component(6));
+    assert_ne!(component(1), component(2));
+    assert_ne!(component(4), component(7));
+    assert_ne!(component(1), component(5));
+
+    // every edge goes to the same or a bigger component
+    for from in 0..8 {
+        for to in wtd.outgoing_edges(from) {
+            assert!(component(from) <= component(to));
+        }
+    }
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut wtd = wtd();
+    wtd.delete_vertex(6);
+    wtd.commit_edits();
+
+    let components = wtd.strongly_connected_components();
+    assert_eq!(components[6], None);
+    assert_ne!(components[2], components[3]); // the cycle went through 6
+    assert_eq!(components.iter().flatten().max(), Some(&5));
+
+    // the edge to the deleted vertex is ignored
+    assert_eq!(wtd.condensation().v_count(), 6);
+}
+
+#[test]
+fn condensation() {
+    let wtd = wtd();
+    let components = wtd.strongly_connected_components();
+    let component = |v: usize| components[v].unwrap();
+    let dag = wtd.condensation();
+
+    assert_eq!(dag.v_count(), 5);
+    // 0 -> 1 and 1 -> 0 become nothing, 3 -> 6 and 6 -> 2 too, 1 -> 2 and 1 -> 5 stay, 7 -> 7 is dropped
+    assert_eq!(dag.e_count(), 4);
+    assert!(dag.edge_exists(component(1), component(2)));
+    assert!(dag.edge_exists(component(1), component(5)));
+    assert!(dag.edge_exists(component(3), component(4)));
+    assert!(dag.edge_exists(component(7), component(4)));
+    assert!(!dag.edge_exists(component(7), component(7)));
+
+    // the condensation has no cycles, so every vertex is its own component
+    let mut dag_components: Vec<usize> = dag
+        .strongly_connected_components()
+        .into_iter()
+        .flatten()
+        .collect();
+    dag_components.sort();
+    assert_eq!(dag_components, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn parallel_edges_are_merged() {
+    // 0 <-> 1, both with an edge to 2
+    let adj = vec![vec![1, 2], vec![0, 2], vec![]];
+    let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(3, 4, adj));
+    let dag = wtd.condensation();
+    assert_eq!(dag.v_count(), 2);
+    assert_eq!(dag.e_count(), 1);
+    assert_eq!(dag.outgoing_edges(0), vec![1]);
+}
+
+#[test]
+fn empty_graph() {
+    let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(0, 0, vec![]));
+    assert_eq!(wtd.strongly_connected_components(), vec![]);
+    assert_eq!(wtd.condensation().v_count(), 0);
+}
+
+#[test]
+fn labeled() {
+    // a <-> b -> c
+    let ldg = LabeledDigraph::from_adjacency_list(
+        3,
+        3,
+        vec![vec![1], vec![0, 2], vec![]],
+        vec!['a', 'b', 'c'],
+    );
+    let lwtd = LabeledWTDigraph::from_labeled_digraph(ldg);
+    assert_eq!(
+        lwtd.strongly_connected_components(),
+        HashMap::from([('a', 0), ('b', 0), ('c', 1)])
+    );
+
+    let dag = lwtd.condensation();
+    assert_eq!(dag.v_count(), 2);
+    assert_eq!(dag.outgoing_edges(0), vec![1]);
+}
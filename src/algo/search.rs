@@ -0,0 +1,353 @@
+// The algorithms behind traits::GraphSearch.
+// Every graph type implements SearchView, which gives the algorithms its vertices, edges and weights,
+// and implements GraphSearch by calling the functions of this module.
+
+use crate::algo::traversal::{Bfs, Event, Neighbors};
+use crate::traits::{ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::GraphError;
+use num::Num;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+// A graph as seen by the search algorithms, at last commit.
+pub(crate) trait SearchView<T> {
+    type Distance;
+
+    // whether successors and predecessors differ, and whether distance returns the weights of the edges
+    const DIRECTED: bool;
+    const WEIGHTED: bool;
+
+    // all existing vertices, by index
+    fn search_vertices(&self) -> Vec<T>;
+
+    fn search_exists(&self, vertex: &T) -> bool;
+
+    // the vertices reachable with an edge from `vertex`
+    fn successors(&self, vertex: &T) -> Vec<T>;
+
+    // the vertices with an edge to `vertex`; the same as successors for undirected graphs
+    fn predecessors(&self, vertex: &T) -> Vec<T>;
+
+    // the weight of the edge from `from` to `to`; 1 for unweighted graphs
+    fn distance(&self, from: &T, to: &T) -> Self::Distance;
+}
+
+// the distance and the previous vertex on the shortest path of every vertex found so far
+type Tree<T, D> = HashMap<T, (D, Option<T>)>;
+
+// estimates the distance from a vertex to the target, see ShortestPathAlgorithm::AStar
+type Heuristic<'a, T, D> = &'a dyn Fn(&T, &T) -> D;
+
+// follows the edges of a SearchView with a traversal
+pub(crate) struct Successors<'a, S>(pub(crate) &'a S);
+
+impl<T, S: SearchView<T>> Neighbors<T> for Successors<'_, S> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        successors(self.0, vertex)
+    }
+}
+
+// follows the edges of a SearchView in both directions
+struct Both<'a, S>(&'a S);
+
+impl<T, S: SearchView<T>> Neighbors<T> for Both<'_, S> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = successors(self.0, vertex);
+        neighbors.extend(
+            self.0
+                .predecessors(vertex)
+                .into_iter()
+                .filter(|v| self.0.search_exists(v)),
+        );
+        neighbors
+    }
+}
+
+pub(crate) fn shortest_path<T, D, S>(
+    graph: &S,
+    from: T,
+    to: T,
+    mode: ShortestPathAlgorithm<T, D>,
+) -> Result<Option<ShortestPath<T, D>>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    if !(graph.search_exists(&from) && graph.search_exists(&to)) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let tree = match mode {
+        ShortestPathAlgorithm::Dijkstra => best_first(graph, from, Some(&to), None)?,
+        ShortestPathAlgorithm::BFS => bfs(graph, from, Some(&to)),
+        ShortestPathAlgorithm::BellmanFord => bellman_ford(graph, from)?,
+        ShortestPathAlgorithm::AStar(heuristic) => {
+            best_first(graph, from, Some(&to), Some(heuristic))?
+        }
+    };
+    Ok(path(&tree, &to))
+}
+
+pub(crate) fn shortest_paths_from<T, D, S>(
+    graph: &S,
+    from: T,
+    mode: ShortestPathAlgorithm<T, D>,
+) -> Result<ShortestPaths<T, D>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    if !graph.search_exists(&from) {
+        return Err(GraphError::VertexNotFound);
+    }
+    let tree = match mode {
+        ShortestPathAlgorithm::Dijkstra => best_first(graph, from, None, None)?,
+        ShortestPathAlgorithm::BFS => bfs(graph, from, None),
+        ShortestPathAlgorithm::BellmanFord => bellman_ford(graph, from)?,
+        ShortestPathAlgorithm::AStar(_) => {
+            let mut paths = HashMap::new();
+            for to in graph.search_vertices() {
+                if let Some(path) = shortest_path(graph, from.clone(), to.clone(), mode)? {
+                    paths.insert(to, path);
+                }
+            }
+            return Ok(paths);
+        }
+    };
+    Ok(tree
+        .keys()
+        .map(|to| (to.clone(), path(&tree, to).unwrap())) // safe, every vertex of the tree has a path
+        .collect())
+}
+
+pub(crate) fn shortest_paths<T, D, S>(
+    graph: &S,
+    mode: ShortestPathAlgorithm<T, D>,
+) -> Result<HashMap<T, ShortestPaths<T, D>>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    let mut paths = HashMap::new();
+    for from in graph.search_vertices() {
+        let from_paths = shortest_paths_from(graph, from.clone(), mode)?;
+        paths.insert(from, from_paths);
+    }
+    Ok(paths)
+}
+
+pub(crate) fn connected_components<T, S>(graph: &S) -> Vec<Vec<T>>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let mut components = Vec::new();
+    let mut vertices = graph.search_vertices().into_iter();
+    let Some(first) = vertices.next() else {
+        return components;
+    };
+    // one traversal for all components, which restarts at the next vertex which hasn't been discovered
+    let mut bfs = Bfs::new(Both(graph), first);
+    loop {
+        components.push(bfs.vertices().collect());
+        match vertices.find(|vertex| !bfs.is_discovered(vertex)) {
+            Some(vertex) => bfs.restart_from(vertex),
+            None => return components,
+        }
+    }
+}
+
+pub(crate) fn connected<T, S>(graph: &S, from: T, to: T) -> bool
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    if !(graph.search_exists(&from) && graph.search_exists(&to)) {
+        return false;
+    }
+    Bfs::new(Successors(graph), from).vertices().any(|v| v == to)
+}
+
+// the successors which exist.
+// wt-graphs don't delete the edges of a deleted vertex, so its incoming edges are still there after a commit.
+pub(crate) fn successors<T, S: SearchView<T>>(graph: &S, vertex: &T) -> Vec<T> {
+    graph
+        .successors(vertex)
+        .into_iter()
+        .filter(|v| graph.search_exists(v))
+        .collect()
+}
+
+// the path to `to` through the tree
+fn path<T, D>(tree: &Tree<T, D>, to: &T) -> Option<ShortestPath<T, D>>
+where
+    T: Hash + Eq + Clone,
+    D: Copy,
+{
+    let (distance, mut previous) = tree.get(to)?.clone();
+    let mut path = vec![to.clone()];
+    while let Some(vertex) = previous {
+        previous = tree[&vertex].1.clone();
+        path.push(vertex);
+    }
+    path.reverse();
+    Some(ShortestPath { distance, path })
+}
+
+// breadth first search, stops once `to` is discovered
+fn bfs<T, D, S>(graph: &S, from: T, to: Option<&T>) -> Tree<T, D>
+where
+    T: Hash + Eq + Clone,
+    D: Num + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    let mut tree: Tree<T, D> = HashMap::new();
+    for event in Bfs::new(Successors(graph), from) {
+        if let Event::Discover { vertex, parent, .. } = event {
+            let distance = match &parent {
+                Some(parent) => tree[parent].0 + graph.distance(parent, &vertex),
+                None => D::zero(),
+            };
+            let found = Some(&vertex) == to;
+            tree.insert(vertex, (distance, parent));
+            if found {
+                break;
+            }
+        }
+    }
+    tree
+}
+
+// an entry of the queue of best_first; the entry with the smallest estimate is the greatest
+struct Entry<T, D> {
+    estimate: D, // distance + heuristic
+    distance: D,
+    vertex: T,
+}
+
+impl<T, D: PartialOrd> PartialEq for Entry<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, D: PartialOrd> Eq for Entry<T, D> {}
+
+impl<T, D: PartialOrd> PartialOrd for Entry<T, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, D: PartialOrd> Ord for Entry<T, D> {
+    // incomparable distances (e.g. NaN) count as equal
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .partial_cmp(&self.estimate)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+// Dijkstra's algorithm, or A* if there is a heuristic. Stops once `to` is taken from the queue.
+// a vertex is expanded again if a shorter path to it is found later, so the heuristic only has to be admissible.
+fn best_first<T, D, S>(
+    graph: &S,
+    from: T,
+    to: Option<&T>,
+    heuristic: Option<Heuristic<T, D>>,
+) -> Result<Tree<T, D>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    let estimate = |vertex: &T, distance: D| match (heuristic, to) {
+        (Some(heuristic), Some(to)) => distance + heuristic(vertex, to),
+        _ => distance,
+    };
+    let mut tree: Tree<T, D> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    tree.insert(from.clone(), (D::zero(), None));
+    queue.push(Entry {
+        estimate: estimate(&from, D::zero()),
+        distance: D::zero(),
+        vertex: from,
+    });
+
+    while let Some(Entry {
+        distance, vertex, ..
+    }) = queue.pop()
+    {
+        if distance > tree[&vertex].0 {
+            continue; // outdated, a shorter path has been found since
+        }
+        if Some(&vertex) == to {
+            break;
+        }
+        for next in successors(graph, &vertex) {
+            let weight = graph.distance(&vertex, &next);
+            if weight < D::zero() {
+                return Err(GraphError::NegativeWeight);
+            }
+            let next_distance = distance + weight;
+            if tree.get(&next).is_none_or(|(d, _)| next_distance < *d) {
+                tree.insert(next.clone(), (next_distance, Some(vertex.clone())));
+                queue.push(Entry {
+                    estimate: estimate(&next, next_distance),
+                    distance: next_distance,
+                    vertex: next,
+                });
+            }
+        }
+    }
+    Ok(tree)
+}
+
+// the Bellman-Ford algorithm: relaxes all edges until nothing changes,
+// which takes at most v_count - 1 rounds if there is no negative cycle.
+fn bellman_ford<T, D, S>(graph: &S, from: T) -> Result<Tree<T, D>, GraphError>
+where
+    T: Hash + Eq + Clone,
+    D: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = D>,
+{
+    let vertices = graph.search_vertices();
+    let mut edges = Vec::new();
+    for vertex in vertices.iter() {
+        for next in successors(graph, vertex) {
+            let weight = graph.distance(vertex, &next);
+            edges.push((vertex.clone(), next, weight));
+        }
+    }
+
+    let mut tree: Tree<T, D> = HashMap::new();
+    tree.insert(from, (D::zero(), None));
+    for round in 0..vertices.len() {
+        let mut changed = false;
+        for (vertex, next, weight) in edges.iter() {
+            let Some(&(distance, _)) = tree.get(vertex) else {
+                continue;
+            };
+            let next_distance = distance + *weight;
+            if tree.get(next).is_none_or(|(d, _)| next_distance < *d) {
+                if round == vertices.len() - 1 {
+                    // still changing after v_count - 1 rounds
+                    return Err(GraphError::NegativeCycle);
+                }
+                tree.insert(next.clone(), (next_distance, Some(vertex.clone())));
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    Ok(tree)
+}
@@ -0,0 +1,215 @@
+use crate::graph::{directed::Digraph, weighted_directed::WeightedDigraph};
+use crate::io::*;
+use crate::traits::{
+    Graph, GraphSearch, ShortestPath, ShortestPathAlgorithm, Unweighted, Weighted, WT,
+};
+use crate::wt::{directed::WTDigraph, labeled_weighted_undirected::LabeledWeightedWTUGraph};
+use crate::GraphError;
+
+//   0 --1--> 1 --1--> 2
+//   |                 ^
+//   +-------5---------+      3 --2--> 4
+const WEIGHTED: &str = "5\n4\n0 1 1\n1 2 1\n0 2 5\n3 4 2\n";
+
+fn path<T, D>(distance: D, path: Vec<T>) -> Option<ShortestPath<T, D>> {
+    Some(ShortestPath { distance, path })
+}
+
+#[test]
+fn shortest_path_modes() {
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(WEIGHTED.as_bytes()).unwrap();
+    let heuristic = |_: &usize, _: &usize| 0;
+    for mode in [
+        ShortestPathAlgorithm::Dijkstra,
+        ShortestPathAlgorithm::BellmanFord,
+        ShortestPathAlgorithm::AStar(&heuristic),
+    ] {
+        assert_eq!(
+            wdg.shortest_path(0, 2, mode).unwrap(),
+            path(2, vec![0, 1, 2])
+        );
+        assert_eq!(wdg.shortest_path(0, 4, mode).unwrap(), None);
+        assert_eq!(wdg.shortest_path(3, 3, mode).unwrap(), path(0, vec![3]));
+    }
+    // the fewest edges, with the weight of that path
+    assert_eq!(
+        wdg.shortest_path(0, 2, ShortestPathAlgorithm::BFS).unwrap(),
+        path(5, vec![0, 2])
+    );
+    assert_eq!(
+        wdg.shortest_path(0, 9, ShortestPathAlgorithm::Dijkstra),
+        Err(GraphError::VertexNotFound)
+    );
+
+    let dg: Digraph = read_digraph("3\n3\n0 1\n1 2\n0 2\n".as_bytes()).unwrap();
+    assert_eq!(
+        dg.shortest_path(0, 2, ShortestPathAlgorithm::Dijkstra)
+            .unwrap(),
+        path(1, vec![0, 2])
+    );
+}
+
+#[test]
+fn a_star() {
+    // a grid of 3 x 3 vertices, the vertex in the middle (4) has no edges
+    let mut wdg: WeightedDigraph<f64> = WeightedDigraph::new();
+    for v in 0..9 {
+        wdg.add_vertex(v);
+    }
+    for (from, to) in [
+        (0, 1),
+        (1, 2),
+        (0, 3),
+        (3, 6),
+        (6, 7),
+        (7, 8),
+        (2, 5),
+        (5, 8),
+    ] {
+        wdg.add_edge(from, to, 1.0);
+    }
+    let calls = std::cell::Cell::new(0);
+    let manhattan = |v: &usize, to: &usize| {
+        calls.set(calls.get() + 1);
+        ((v % 3).abs_diff(to % 3) + (v / 3).abs_diff(to / 3)) as f64
+    };
+    let found = wdg
+        .shortest_path(0, 8, ShortestPathAlgorithm::AStar(&manhattan))
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.distance, 4.0);
+    assert_eq!(found.path.len(), 5);
+    assert!(calls.get() > 0);
+}
+
+#[test]
+fn negative_weights() {
+    let wdg: WeightedDigraph<i32> =
+        read_weighted_digraph("3\n3\n0 1 4\n0 2 1\n2 1 -2\n".as_bytes()).unwrap();
+    assert_eq!(
+        wdg.shortest_path(0, 1, ShortestPathAlgorithm::BellmanFord)
+            .unwrap(),
+        path(-1, vec![0, 2, 1])
+    );
+    assert_eq!(
+        wdg.shortest_path(0, 1, ShortestPathAlgorithm::Dijkstra),
+        Err(GraphError::NegativeWeight)
+    );
+
+    let cycle: WeightedDigraph<i32> =
+        read_weighted_digraph("4\n4\n0 1 1\n1 2 -1\n2 1 -1\n3 0 1\n".as_bytes()).unwrap();
+    assert_eq!(
+        cycle.shortest_path(0, 2, ShortestPathAlgorithm::BellmanFord),
+        Err(GraphError::NegativeCycle)
+    );
+    // the cycle can also be reached from 3
+    assert_eq!(
+        cycle.shortest_path(3, 0, ShortestPathAlgorithm::BellmanFord),
+        Err(GraphError::NegativeCycle)
+    );
+    let single: WeightedDigraph<i32> = read_weighted_digraph("1\n1\n0 0 -1\n".as_bytes()).unwrap();
+    assert_eq!(
+        single.shortest_paths_from(0, ShortestPathAlgorithm::BellmanFord),
+        Err(GraphError::NegativeCycle)
+    );
+}
+
+#[test]
+fn all_shortest_paths() {
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(WEIGHTED.as_bytes()).unwrap();
+    let from_0 = wdg
+        .shortest_paths_from(0, ShortestPathAlgorithm::Dijkstra)
+        .unwrap();
+    assert_eq!(from_0.len(), 3);
+    assert_eq!(from_0[&2].distance, 2);
+
+    let heuristic = |_: &usize, _: &usize| 0;
+    assert_eq!(
+        wdg.shortest_paths_from(0, ShortestPathAlgorithm::AStar(&heuristic))
+            .unwrap(),
+        from_0
+    );
+
+    let all = wdg.shortest_paths(ShortestPathAlgorithm::BFS).unwrap();
+    assert_eq!(all.len(), 5);
+    assert_eq!(all[&3][&4].path, vec![3, 4]);
+    assert_eq!(all[&2].len(), 1);
+}
+
+#[test]
+fn components_and_connected() {
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph(WEIGHTED.as_bytes()).unwrap();
+    assert_eq!(wdg.connected_components(), vec![vec![0, 1, 2], vec![3, 4]]);
+    assert!(wdg.connected(0, 2));
+    assert!(!wdg.connected(2, 0));
+    assert!(!wdg.connected(0, 3));
+    assert!(!wdg.connected(0, 7));
+
+    // the committed graph is searched, deleted vertices are left out
+    let mut wtd = WTDigraph::from_digraph(read_digraph("4\n2\n1 0\n2 3\n".as_bytes()).unwrap());
+    wtd.add_edge(0, 2);
+    assert_eq!(wtd.connected_components(), vec![vec![0, 1], vec![2, 3]]);
+    wtd.delete_vertex(3);
+    wtd.commit_edits();
+    assert_eq!(wtd.connected_components(), vec![vec![0, 2, 1]]);
+    assert!(wtd.connected(1, 2));
+    assert_eq!(wtd.v_count(), 3);
+}
+
+#[test]
+fn labeled_and_undirected() {
+    let lwug = read_labeled_weighted_ugraph::<_, String, f32>(
+        "4\n3\na b 1.5\nc b 0.5\na c 3\nd\n".as_bytes(),
+    )
+    .unwrap();
+    let wt = LabeledWeightedWTUGraph::from_labeled_weighted_ugraph(lwug.clone());
+    let a = "a".to_string();
+    let c = "c".to_string();
+    let expected = path(2.0, vec![a.clone(), "b".to_string(), c.clone()]);
+    assert_eq!(
+        lwug.shortest_path(c.clone(), a.clone(), ShortestPathAlgorithm::Dijkstra)
+            .unwrap()
+            .map(|p| p.distance),
+        Some(2.0)
+    );
+    assert_eq!(
+        wt.shortest_path(a.clone(), c.clone(), ShortestPathAlgorithm::BellmanFord)
+            .unwrap(),
+        expected
+    );
+    assert_eq!(wt.connected_components().len(), 2);
+    assert!(wt.connected(c.clone(), a.clone()));
+    assert!(!wt.connected(a, "d".to_string()));
+    assert_eq!(wt.weight(c, "b".to_string()), 0.5);
+}
+
+// every graph type implements GraphSearch
+#[test]
+fn all_types() {
+    let text = "3\n2\n0 1\n1 2\n";
+    let weighted = "3\n2\n0 1 2\n1 2 3\n";
+    fn check<G: GraphSearch<usize>>(graph: G) {
+        assert!(graph.connected(0, 2));
+        assert_eq!(graph.connected_components(), vec![vec![0, 1, 2]]);
+    }
+    fn check_labeled<G: GraphSearch<String>>(graph: G) {
+        assert!(graph.connected("0".to_string(), "2".to_string()));
+        assert_eq!(graph.connected_components().len(), 1);
+    }
+    check(read_digraph(text.as_bytes()).unwrap());
+    check(read_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_ugraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_wt_digraph(text.as_bytes()).unwrap());
+    check(read_wt_ugraph(text.as_bytes()).unwrap());
+    check(read_weighted_wt_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_weighted_wt_ugraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+}
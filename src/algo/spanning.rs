@@ -0,0 +1,174 @@
+// The algorithms behind traits::SpanningTree, on the SearchView of a weighted undirected graph.
+// Both work on the positions of the vertices in search_vertices instead of the vertices themselves.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::{SpanningForest, SpanningTreeAlgorithm};
+use num::Num;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) fn minimum_spanning_forest<T, W, S>(
+    graph: &S,
+    mode: SpanningTreeAlgorithm,
+) -> SpanningForest<T, W>
+where
+    T: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+    S: SearchView<T, Distance = W>,
+{
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+    // the edges of every vertex as (position of the neighbor, weight), without self-loops and NaN weights
+    let adj: Vec<Vec<(usize, W)>> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, vertex)| {
+            search::successors(graph, vertex)
+                .into_iter()
+                .map(|next| (positions[&next], graph.distance(vertex, &next)))
+                .filter(|&(j, weight)| j != i && weight.partial_cmp(&weight).is_some())
+                .collect()
+        })
+        .collect();
+
+    let edges = match mode {
+        SpanningTreeAlgorithm::Kruskal => kruskal(&adj),
+        SpanningTreeAlgorithm::Prim => prim(&adj),
+    };
+    SpanningForest {
+        weight: edges.iter().fold(W::zero(), |sum, &(_, _, w)| sum + w),
+        edges: edges
+            .into_iter()
+            .map(|(i, j, w)| (vertices[i].clone(), vertices[j].clone(), w))
+            .collect(),
+        vertices,
+    }
+}
+
+// incomparable weights count as equal
+fn compare<W: PartialOrd>(a: &W, b: &W) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+// the sets of vertices connected by the edges chosen so far
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]]; // path halving
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // returns false if both are in the same set already
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+}
+
+fn kruskal<W: PartialOrd + Copy>(adj: &[Vec<(usize, W)>]) -> Vec<(usize, usize, W)> {
+    // every undirected edge is in the lists of both its vertices, take it once
+    let mut edges: Vec<(usize, usize, W)> = adj
+        .iter()
+        .enumerate()
+        .flat_map(|(i, list)| {
+            list.iter()
+                .filter(move |e| i < e.0)
+                .map(move |&(j, w)| (i, j, w))
+        })
+        .collect();
+    edges.sort_by(|a, b| compare(&a.2, &b.2));
+
+    let mut sets = UnionFind::new(adj.len());
+    edges
+        .into_iter()
+        .filter(|&(i, j, _)| sets.union(i, j))
+        .collect()
+}
+
+// an edge leaving the tree in the queue of prim; the lightest edge is the greatest
+struct Candidate<W> {
+    weight: W,
+    from: usize,
+    to: usize,
+}
+
+impl<W: PartialOrd> PartialEq for Candidate<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: PartialOrd> Eq for Candidate<W> {}
+
+impl<W: PartialOrd> PartialOrd for Candidate<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: PartialOrd> Ord for Candidate<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&other.weight, &self.weight)
+    }
+}
+
+// grows one tree from every vertex which isn't in a tree yet
+fn prim<W: PartialOrd + Copy>(adj: &[Vec<(usize, W)>]) -> Vec<(usize, usize, W)> {
+    let mut in_tree = vec![false; adj.len()];
+    let mut edges = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for root in 0..adj.len() {
+        if in_tree[root] {
+            continue;
+        }
+        in_tree[root] = true;
+        push_edges(adj, root, &mut queue);
+        while let Some(Candidate { weight, from, to }) = queue.pop() {
+            if !in_tree[to] {
+                in_tree[to] = true;
+                edges.push((from, to, weight));
+                push_edges(adj, to, &mut queue);
+            }
+        }
+    }
+    edges
+}
+
+fn push_edges<W: Copy>(adj: &[Vec<(usize, W)>], from: usize, queue: &mut BinaryHeap<Candidate<W>>)
+where
+    Candidate<W>: Ord,
+{
+    for &(to, weight) in adj[from].iter() {
+        queue.push(Candidate { weight, from, to });
+    }
+}
@@ -0,0 +1,133 @@
+use crate::graph::{
+    labeled_weighted_undirected::LabeledWeightedUGraph, weighted_undirected::WeightedUGraph,
+};
+use crate::io::*;
+use crate::traits::{
+    Graph, SpanningForest, SpanningTree, SpanningTreeAlgorithm, Undirected, Weighted, WT,
+};
+use crate::wt::weighted_undirected::WeightedWTUGraph;
+
+//   0 --1-- 1 --2-- 2
+//   |     / |       |
+//   4   3   5       1      5 --2-- 6
+//   | /     |       |
+//   3 --6-- 4 --7-- 7
+const WEIGHTED: &str =
+    "8\n10\n0 1 1\n1 2 2\n0 3 4\n1 3 3\n1 4 5\n2 7 1\n3 4 6\n4 7 7\n5 6 2\n3 3 0\n";
+
+const MODES: [SpanningTreeAlgorithm; 2] =
+    [SpanningTreeAlgorithm::Kruskal, SpanningTreeAlgorithm::Prim];
+
+// the sum of the edges is the weight, and the edges connect the components without a cycle
+fn check_forest(forest: &SpanningForest<usize, i32>, v_count: usize, components: usize) {
+    assert_eq!(forest.edges.len(), v_count - components);
+    assert_eq!(
+        forest.weight,
+        forest.edges.iter().map(|&(_, _, w)| w).sum::<i32>()
+    );
+    let ug = WeightedUGraph::from_spanning_forest(forest.clone());
+    assert_eq!(ug.v_count(), v_count);
+    assert_eq!(ug.e_count(), v_count - components);
+}
+
+#[test]
+fn minimum_spanning_forest() {
+    let ug: WeightedUGraph<i32> = read_weighted_ugraph(WEIGHTED.as_bytes()).unwrap();
+    for mode in MODES {
+        let forest = ug.minimum_spanning_forest(mode);
+        assert_eq!(forest.vertices, (0..8).collect::<Vec<_>>());
+        assert_eq!(forest.weight, 1 + 2 + 3 + 5 + 1 + 2);
+        check_forest(&forest, 8, 2);
+        // the self-loop and the heavy edges aren't chosen
+        assert!(forest.edges.iter().all(|&(from, to, _)| from != to));
+        assert!(forest.edges.iter().all(|&(_, _, w)| w <= 5));
+    }
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut ug: WeightedUGraph<i32> = read_weighted_ugraph(WEIGHTED.as_bytes()).unwrap();
+    ug.delete_edges_from(1);
+    for mode in MODES {
+        let forest = ug.minimum_spanning_forest(mode);
+        assert_eq!(forest.weight, 4 + 6 + 7 + 1 + 2);
+        // 1 is alone now
+        check_forest(&forest, 8, 3);
+    }
+
+    let mut wtug: WeightedWTUGraph<i32> = read_weighted_wt_ugraph(WEIGHTED.as_bytes()).unwrap();
+    wtug.delete_vertex(4);
+    wtug.commit_edits();
+    for mode in MODES {
+        let forest = wtug.minimum_spanning_forest(mode);
+        assert_eq!(forest.weight, 1 + 2 + 3 + 1 + 2);
+        assert!(!forest.vertices.contains(&4));
+
+        // the index of the deleted vertex is a deleted vertex in the new graph
+        let ug = WeightedUGraph::from_spanning_forest(forest);
+        assert_eq!((0..8).filter(|&v| ug.vertex_exists(v)).count(), 7);
+        assert!(!ug.vertex_exists(4));
+        assert_eq!(ug.weight(7, 2), 1);
+    }
+}
+
+#[test]
+fn nan_weights() {
+    let text = "3\n3\n0 1 NaN\n1 2 1.5\n0 2 2.5\n";
+    let ug: WeightedUGraph<f64> = read_weighted_ugraph(text.as_bytes()).unwrap();
+    for mode in MODES {
+        let forest = ug.minimum_spanning_forest(mode);
+        assert_eq!(forest.weight, 4.0);
+        assert_eq!(forest.edges.len(), 2);
+    }
+
+    // an edge with NaN is never chosen, even if the graph isn't connected without it
+    let text = "2\n1\n0 1 NaN\n";
+    let ug: WeightedUGraph<f64> = read_weighted_ugraph(text.as_bytes()).unwrap();
+    for mode in MODES {
+        let forest = ug.minimum_spanning_forest(mode);
+        assert!(forest.edges.is_empty());
+        assert_eq!(forest.weight, 0.0);
+    }
+}
+
+#[test]
+fn empty_graph() {
+    let ug: WeightedUGraph<i32> = WeightedUGraph::new();
+    for mode in MODES {
+        let forest = ug.minimum_spanning_forest(mode);
+        assert!(forest.vertices.is_empty() && forest.edges.is_empty());
+        assert_eq!(WeightedUGraph::from_spanning_forest(forest).v_count(), 0);
+    }
+}
+
+#[test]
+fn labeled() {
+    let lug: LabeledWeightedUGraph<String, i32> =
+        read_labeled_weighted_ugraph(WEIGHTED.as_bytes()).unwrap();
+    for mode in MODES {
+        let forest = lug.minimum_spanning_forest(mode);
+        assert_eq!(forest.weight, 14);
+        assert_eq!(forest.edges.len(), 6);
+
+        let tree = LabeledWeightedUGraph::from_spanning_forest(forest);
+        assert_eq!(tree.v_count(), 8);
+        assert_eq!(tree.e_count(), 6);
+        assert_eq!(tree.weight("2".to_string(), "1".to_string()), 2);
+        assert!(!tree.edge_exists("3".to_string(), "4".to_string()));
+    }
+}
+
+#[test]
+fn all_types() {
+    fn check<T, G: SpanningTree<T, i32>>(graph: G) {
+        for mode in MODES {
+            assert_eq!(graph.minimum_spanning_forest(mode).weight, 14);
+        }
+    }
+    let text = WEIGHTED.as_bytes();
+    check(read_weighted_ugraph::<_, i32>(text).unwrap());
+    check(read_weighted_wt_ugraph::<_, i32>(text).unwrap());
+    check(read_labeled_weighted_ugraph::<_, String, i32>(text).unwrap());
+    check(read_labeled_weighted_wt_ugraph::<_, String, i32>(text).unwrap());
+}
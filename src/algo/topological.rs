@@ -0,0 +1,77 @@
+// The algorithm behind traits::TopologicalSort and traits::WTTopologicalSort.
+// A depth-first search over all vertices: the reversed order in which the vertices are finished is a topological order,
+// unless an edge leads back to a vertex on the current path, which closes a cycle. Every vertex is marked as on the path
+// or finished, so each edge is looked at once, when it is taken, and the neighbors of each vertex are fetched once.
+
+use crate::algo::search::{SearchView, Successors};
+use crate::algo::traversal::{Neighbors, OutgoingUpdated};
+use crate::traits::{WTDirected, WT};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::vec;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    OnPath,
+    Finished,
+}
+
+pub(crate) fn topological_order<T, S>(graph: &S) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    order(graph.search_vertices(), Successors(graph))
+}
+
+// `vertices` are the vertices which exist after the uncommitted edits
+pub(crate) fn topological_order_updated<T, G>(graph: &G, vertices: Vec<T>) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+    G: WT<T> + WTDirected<T>,
+{
+    order(vertices, OutgoingUpdated(graph))
+}
+
+fn order<T, N>(vertices: Vec<T>, view: N) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+    N: Neighbors<T>,
+{
+    let mut states: HashMap<T, State> = HashMap::new();
+    let mut order = Vec::new();
+    // the vertices on the current path with their remaining neighbors
+    let mut stack: Vec<(T, vec::IntoIter<T>)> = Vec::new();
+    for start in vertices {
+        if states.contains_key(&start) {
+            continue;
+        }
+        states.insert(start.clone(), State::OnPath);
+        stack.push((start.clone(), view.neighbors(&start).into_iter()));
+        while let Some((_, neighbors)) = stack.last_mut() {
+            let Some(next) = neighbors.next() else {
+                let (vertex, _) = stack.pop().unwrap(); // safe, the stack isn't empty
+                states.insert(vertex.clone(), State::Finished);
+                order.push(vertex);
+                continue;
+            };
+            match states.get(&next) {
+                Some(State::Finished) => {}
+                Some(State::OnPath) => {
+                    let start = stack.iter().position(|(v, _)| *v == next).unwrap(); // safe, next is on the path
+                    return Err(stack.drain(start..).map(|(v, _)| v).collect());
+                }
+                None => {
+                    states.insert(next.clone(), State::OnPath);
+                    let neighbors = view.neighbors(&next).into_iter();
+                    stack.push((next, neighbors));
+                }
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
@@ -0,0 +1,157 @@
+use crate::graph::directed::Digraph;
+use crate::io::*;
+use crate::traits::{
+    Directed, Graph, TopologicalSort, Unlabeled, Unweighted, WTDirected, WTTopologicalSort, WT,
+};
+use crate::wt::{directed::WTDigraph, labeled_directed::LabeledWTDigraph};
+
+//   0 -> 1 -> 3
+//   |         ^
+//   v         |
+//   2 --------+    4
+fn dag() -> Digraph {
+    let adj = vec![vec![1, 2], vec![3], vec![3], vec![], vec![]];
+    Digraph::from_adjacency_list(5, 4, adj)
+}
+
+// every edge goes from an earlier to a later vertex
+fn check_order<G: Directed<usize>>(graph: &G, order: &[usize], v_count: usize) {
+    assert_eq!(order.len(), v_count);
+    for (i, &from) in order.iter().enumerate() {
+        for to in graph.outgoing_edges(from) {
+            assert!(order[..i].iter().all(|&v| v != to));
+        }
+    }
+}
+
+// every vertex has an edge to the next one, the last to the first
+fn check_cycle<G: Graph<usize>>(graph: &G, cycle: &[usize]) {
+    assert!(!cycle.is_empty());
+    for (i, &from) in cycle.iter().enumerate() {
+        assert!(graph.edge_exists(from, cycle[(i + 1) % cycle.len()]));
+    }
+}
+
+#[test]
+fn order() {
+    let dg = dag();
+    let order = dg.topological_order().unwrap();
+    check_order(&dg, &order, 5);
+
+    let empty = Digraph::from_adjacency_list(0, 0, vec![]);
+    assert_eq!(empty.topological_order(), Ok(vec![]));
+}
+
+#[test]
+fn cycle() {
+    let mut dg = dag();
+    dg.add_edge(3, 0);
+    let cycle = dg.topological_order().unwrap_err();
+    check_cycle(&dg, &cycle);
+    assert!(cycle.contains(&0) && cycle.contains(&3));
+
+    let mut dg = dag();
+    dg.add_edge(4, 4);
+    assert_eq!(dg.topological_order(), Err(vec![4]));
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut dg = dag();
+    dg.add_edge(3, 0);
+    dg.delete_vertex(1);
+    dg.delete_vertex(2);
+    let order = dg.topological_order().unwrap();
+    check_order(&dg, &order, 3);
+
+    // the committed wt-digraph keeps the edges to deleted vertices
+    let mut dg = dag();
+    dg.add_edge(3, 0);
+    let mut wtd = WTDigraph::from_digraph(dg);
+    wtd.delete_vertex(0);
+    wtd.commit_edits();
+    let order = wtd.topological_order().unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(!order.contains(&0));
+}
+
+#[test]
+fn updated() {
+    let mut wtd = WTDigraph::from_digraph(dag());
+    wtd.add_edge(3, 0);
+    // the committed graph has no cycle yet
+    check_order(&wtd, &wtd.topological_order().unwrap(), 5);
+    let cycle = wtd.topological_order_updated().unwrap_err();
+    for (i, &from) in cycle.iter().enumerate() {
+        assert!(wtd.edge_exists_updated(from, cycle[(i + 1) % cycle.len()]));
+    }
+
+    // deleting a vertex on the cycle removes it again
+    wtd.delete_vertex(3);
+    let order = wtd.topological_order_updated().unwrap();
+    assert_eq!(order.len(), 4);
+    for (i, &from) in order.iter().enumerate() {
+        for to in wtd.outgoing_edges_updated(from) {
+            assert!(order[..i].iter().all(|&v| v != to));
+        }
+    }
+
+    // appended vertices are part of the order
+    let v = wtd.append_vertex();
+    wtd.add_edge(v, 0);
+    let order = wtd.topological_order_updated().unwrap();
+    assert_eq!(order.len(), 5);
+    assert!(order.iter().position(|&u| u == v) < order.iter().position(|&u| u == 0));
+}
+
+#[test]
+fn labeled() {
+    let text = "3\n3\n0 1\n1 2\n2 0\n";
+    let mut lwtd: LabeledWTDigraph<String> = read_labeled_wt_digraph(text.as_bytes()).unwrap();
+    let cycle = lwtd.topological_order().unwrap_err();
+    assert_eq!(cycle.len(), 3);
+
+    lwtd.delete_edge("2".to_string(), "0".to_string());
+    assert_eq!(
+        lwtd.topological_order_updated(),
+        Ok(vec!["0".to_string(), "1".to_string(), "2".to_string()])
+    );
+}
+
+#[test]
+fn all_types() {
+    let text = "3\n2\n0 1\n1 2\n";
+    let weighted = "3\n2\n0 1 2\n1 2 3\n";
+    fn check<G: TopologicalSort<usize>>(graph: G) {
+        assert_eq!(graph.topological_order(), Ok(vec![0, 1, 2]));
+    }
+    fn check_labeled<G: TopologicalSort<String>>(graph: G) {
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order, vec!["0", "1", "2"]);
+    }
+    fn check_updated<T: std::fmt::Debug, G: WTTopologicalSort<T>>(graph: G) {
+        assert_eq!(graph.topological_order_updated().unwrap().len(), 3);
+    }
+    check(read_digraph(text.as_bytes()).unwrap());
+    check(read_weighted_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check(read_wt_digraph(text.as_bytes()).unwrap());
+    check(read_weighted_wt_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+    check_updated(read_wt_digraph(text.as_bytes()).unwrap());
+    check_updated(read_weighted_wt_digraph::<_, i32>(weighted.as_bytes()).unwrap());
+    check_updated(read_labeled_wt_digraph::<_, String>(text.as_bytes()).unwrap());
+    check_updated(read_labeled_weighted_wt_digraph::<_, String, i32>(weighted.as_bytes()).unwrap());
+}
+
+#[test]
+fn long_chain() {
+    // a path the length of the graph, where a scan of the path per edge would be quadratic
+    let n = 100_000;
+    let adj = (0..n).map(|v| if v + 1 < n { vec![v + 1] } else { vec![] }).collect();
+    let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(n, n - 1, adj));
+    assert_eq!(wtd.topological_order(), Ok((0..n).collect()));
+    assert_eq!(wtd.topological_order_updated(), Ok((0..n).collect()));
+}
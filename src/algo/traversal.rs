@@ -0,0 +1,442 @@
+//! Breadth-first and depth-first traversals.
+//!
+//! `Bfs` and `Dfs` are lazy iterators over the `Event`s of a traversal: each vertex is discovered once
+//! (with its depth and the vertex it was discovered from) and finished once all its neighbors have been looked at.
+//! Stop iterating to end the traversal early; `parent` and `depth` keep answering for all vertices discovered so far.
+//!
+//! Which edges are followed is chosen by a view of the graph, see `Neighbors`:
+//!
+//! | View              | Follows                             | Graph needs to implement |
+//! |-------------------|-------------------------------------|--------------------------|
+//! | `Outgoing`        | outgoing edges at last commit       | `Graph`, `Directed`      |
+//! | `Incoming`        | incoming edges at last commit       | `Graph`, `Directed`      |
+//! | `Edges`           | edges at last commit                | `Graph`, `Undirected`    |
+//! | `OutgoingUpdated` | outgoing edges including the edits  | `WT`, `WTDirected`       |
+//! | `IncomingUpdated` | incoming edges including the edits  | `WT`, `WTDirected`       |
+//! | `EdgesUpdated`    | edges including the edits           | `WT`, `WTUndirected`     |
+//!
+//! For all graphs which aren't wavelet tree based, "at last commit" simply means the current graph.
+//! Like `outgoing_edges` etc., a traversal panics if its start vertex doesn't exist in the view.
+//! The views leave out neighbors which don't exist in them: wt-graphs keep the edges to a deleted vertex.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::prelude::*;
+//! use wt_graphs::algo::traversal::{Bfs, Dfs, Event, Outgoing, OutgoingUpdated};
+//!
+//! let mut wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(
+//!     4,
+//!     3,
+//!     vec![vec![1, 2], vec![3], vec![], vec![]],
+//! ));
+//! let order: Vec<usize> = Bfs::new(Outgoing(&wtd), 0).vertices().collect();
+//! assert_eq!(order, vec![0, 1, 2, 3]);
+//!
+//! // stop as soon as 3 is found, and walk the parent tree back to the start
+//! let mut bfs = Bfs::new(Outgoing(&wtd), 0);
+//! bfs.vertices().find(|&v| v == 3);
+//! assert_eq!(bfs.path_to(&3), Some(vec![0, 1, 3]));
+//!
+//! // the uncommitted edges are only followed in the updated view
+//! wtd.add_edge(3, 0);
+//! let events: Vec<Event<usize>> = Dfs::new(OutgoingUpdated(&wtd), 3).take(2).collect();
+//! assert_eq!(events[1], Event::Discover { vertex: 0, parent: Some(3), depth: 1 });
+//! ```
+
+use crate::traits::{Directed, Graph, Undirected, WTDirected, WTUndirected, WT};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::vec;
+
+#[cfg(test)]
+mod test;
+
+/// A view of a graph, which returns the vertices a traversal can go to from a vertex.
+///
+/// Implemented by the views `Outgoing`, `Incoming`, `Edges`, `OutgoingUpdated`, `IncomingUpdated` and `EdgesUpdated`.
+/// Implement it for your own type to traverse anything else, e.g. a filtered graph.
+pub trait Neighbors<T> {
+    /// Returns the vertices which can be reached from `vertex` with a single edge.
+    fn neighbors(&self, vertex: &T) -> Vec<T>;
+}
+
+/// Follows the outgoing edges of a directed graph at last commit.
+#[derive(Clone, Copy, Debug)]
+pub struct Outgoing<'a, G>(pub &'a G);
+
+/// Follows the incoming edges of a directed graph at last commit, i.e. traverses the reversed graph.
+#[derive(Clone, Copy, Debug)]
+pub struct Incoming<'a, G>(pub &'a G);
+
+/// Follows the edges of an undirected graph at last commit.
+#[derive(Clone, Copy, Debug)]
+pub struct Edges<'a, G>(pub &'a G);
+
+/// Follows the outgoing edges of a directed wt-graph including the uncommitted edits.
+#[derive(Clone, Copy, Debug)]
+pub struct OutgoingUpdated<'a, G>(pub &'a G);
+
+/// Follows the incoming edges of a directed wt-graph including the uncommitted edits.
+#[derive(Clone, Copy, Debug)]
+pub struct IncomingUpdated<'a, G>(pub &'a G);
+
+/// Follows the edges of an undirected wt-graph including the uncommitted edits.
+#[derive(Clone, Copy, Debug)]
+pub struct EdgesUpdated<'a, G>(pub &'a G);
+
+impl<T: Clone, G: Graph<T> + Directed<T>> Neighbors<T> for Outgoing<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.outgoing_edges(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists(v.clone()));
+        neighbors
+    }
+}
+
+impl<T: Clone, G: Graph<T> + Directed<T>> Neighbors<T> for Incoming<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.incoming_edges(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists(v.clone()));
+        neighbors
+    }
+}
+
+impl<T: Clone, G: Graph<T> + Undirected<T>> Neighbors<T> for Edges<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.edges(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists(v.clone()));
+        neighbors
+    }
+}
+
+impl<T: Clone, G: WT<T> + WTDirected<T>> Neighbors<T> for OutgoingUpdated<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.outgoing_edges_updated(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists_updated(v.clone()));
+        neighbors
+    }
+}
+
+impl<T: Clone, G: WT<T> + WTDirected<T>> Neighbors<T> for IncomingUpdated<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.incoming_edges_updated(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists_updated(v.clone()));
+        neighbors
+    }
+}
+
+impl<T: Clone, G: WT<T> + WTUndirected<T>> Neighbors<T> for EdgesUpdated<'_, G> {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        let mut neighbors = self.0.edges_updated(vertex.clone());
+        neighbors.retain(|v| self.0.vertex_exists_updated(v.clone()));
+        neighbors
+    }
+}
+
+// lets a traversal borrow a view, so the view can still be used while the traversal runs
+impl<T, N: Neighbors<T>> Neighbors<T> for &N {
+    fn neighbors(&self, vertex: &T) -> Vec<T> {
+        (*self).neighbors(vertex)
+    }
+}
+
+/// An event of a traversal.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Event<T> {
+    /// The vertex is reached for the first time, from `parent` (`None` for a start vertex),
+    /// `depth` edges away from the start.
+    Discover {
+        vertex: T,
+        parent: Option<T>,
+        depth: usize,
+    },
+    /// All neighbors of the vertex have been discovered. For `Dfs` this means their traversals are finished, too.
+    Finish { vertex: T },
+}
+
+impl<T> Event<T> {
+    /// Returns the vertex of the event.
+    pub fn vertex(&self) -> &T {
+        match self {
+            Event::Discover { vertex, .. } => vertex,
+            Event::Finish { vertex } => vertex,
+        }
+    }
+
+    /// Returns the vertex if it was discovered in this event.
+    pub fn discovered(self) -> Option<T> {
+        match self {
+            Event::Discover { vertex, .. } => Some(vertex),
+            Event::Finish { .. } => None,
+        }
+    }
+
+    /// Returns the vertex if it was finished in this event.
+    pub fn finished(self) -> Option<T> {
+        match self {
+            Event::Discover { .. } => None,
+            Event::Finish { vertex } => Some(vertex),
+        }
+    }
+}
+
+// the parent and the depth of every discovered vertex, shared by Bfs and Dfs
+#[derive(Clone, Debug)]
+struct Tree<T> {
+    nodes: HashMap<T, (Option<T>, usize)>,
+}
+
+impl<T: Clone + Hash + Eq> Tree<T> {
+    fn new() -> Self {
+        Tree {
+            nodes: HashMap::new(),
+        }
+    }
+
+    // returns the discover event, or None if the vertex was discovered before
+    fn discover(&mut self, vertex: T, parent: Option<T>, depth: usize) -> Option<Event<T>> {
+        if self.nodes.contains_key(&vertex) {
+            return None;
+        }
+        self.nodes.insert(vertex.clone(), (parent.clone(), depth));
+        Some(Event::Discover {
+            vertex,
+            parent,
+            depth,
+        })
+    }
+
+    fn depth(&self, vertex: &T) -> Option<usize> {
+        self.nodes.get(vertex).map(|(_, depth)| *depth)
+    }
+
+    fn parent(&self, vertex: &T) -> Option<&T> {
+        self.nodes
+            .get(vertex)
+            .and_then(|(parent, _)| parent.as_ref())
+    }
+
+    fn path_to(&self, vertex: &T) -> Option<Vec<T>> {
+        let mut path = vec![vertex.clone()];
+        let mut current = self.nodes.get(vertex)?;
+        while let Some(parent) = &current.0 {
+            path.push(parent.clone());
+            current = &self.nodes[parent];
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A breadth-first traversal, see the module documentation.
+///
+/// The neighbors of a vertex are discovered in the order the view returns them, directly after each other,
+/// followed by the finish event of the vertex.
+#[derive(Clone, Debug)]
+pub struct Bfs<T, N> {
+    view: N,
+    tree: Tree<T>,
+    queue: VecDeque<T>,          // discovered, but not finished vertices
+    pending: VecDeque<Event<T>>, // events of the last expanded vertex, which haven't been returned yet
+    starts: VecDeque<T>,         // start vertices, which are visited once the queue is empty
+}
+
+impl<T, N> Bfs<T, N>
+where
+    T: Clone + Hash + Eq,
+    N: Neighbors<T>,
+{
+    /// Starts a traversal of the given view at `start`.
+    pub fn new(view: N, start: T) -> Self {
+        let mut bfs = Bfs {
+            view,
+            tree: Tree::new(),
+            queue: VecDeque::new(),
+            pending: VecDeque::new(),
+            starts: VecDeque::new(),
+        };
+        bfs.restart_from(start);
+        bfs
+    }
+
+    /// Adds another start vertex, at which the traversal continues once everything reachable from the previous
+    /// start vertices is finished, e.g. to visit every vertex. Vertices which were discovered before aren't visited
+    /// again, and a start vertex which was discovered before is skipped.
+    pub fn restart_from(&mut self, start: T) {
+        self.starts.push_back(start);
+    }
+
+    /// Returns an iterator over the vertices in the order they are discovered.
+    pub fn vertices(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.filter_map(Event::discovered)
+    }
+
+    /// Returns true if the vertex has been discovered so far.
+    pub fn is_discovered(&self, vertex: &T) -> bool {
+        self.tree.depth(vertex).is_some()
+    }
+
+    /// Returns the number of edges from the start to the vertex, or `None` if it hasn't been discovered so far.
+    /// In a breadth-first traversal, this is the length of the shortest path.
+    pub fn depth(&self, vertex: &T) -> Option<usize> {
+        self.tree.depth(vertex)
+    }
+
+    /// Returns the vertex the given vertex has been discovered from.
+    /// `None` if it is a start vertex or hasn't been discovered so far.
+    pub fn parent(&self, vertex: &T) -> Option<&T> {
+        self.tree.parent(vertex)
+    }
+
+    /// Returns the path from the start through the parent tree to the vertex, or `None` if it hasn't been discovered so far.
+    pub fn path_to(&self, vertex: &T) -> Option<Vec<T>> {
+        self.tree.path_to(vertex)
+    }
+}
+
+impl<T, N> Iterator for Bfs<T, N>
+where
+    T: Clone + Hash + Eq,
+    N: Neighbors<T>,
+{
+    type Item = Event<T>;
+
+    fn next(&mut self) -> Option<Event<T>> {
+        while self.pending.is_empty() && self.queue.is_empty() {
+            let start = self.starts.pop_front()?;
+            if let Some(event) = self.tree.discover(start.clone(), None, 0) {
+                self.queue.push_back(start);
+                return Some(event);
+            }
+        }
+        if self.pending.is_empty() {
+            let vertex = self.queue.pop_front().unwrap(); // safe, see above
+            let depth = self.tree.depth(&vertex).unwrap() + 1; // safe, every queued vertex was discovered
+            for next in self.view.neighbors(&vertex) {
+                if let Some(event) = self
+                    .tree
+                    .discover(next.clone(), Some(vertex.clone()), depth)
+                {
+                    self.queue.push_back(next);
+                    self.pending.push_back(event);
+                }
+            }
+            self.pending.push_back(Event::Finish { vertex });
+        }
+        self.pending.pop_front()
+    }
+}
+
+/// A depth-first traversal, see the module documentation.
+///
+/// The neighbors of a vertex are traversed in the order the view returns them.
+/// The traversal uses its own stack, so it doesn't overflow the call stack on long paths.
+#[derive(Clone, Debug)]
+pub struct Dfs<T, N> {
+    view: N,
+    tree: Tree<T>,
+    stack: Vec<(T, vec::IntoIter<T>)>, // the vertices on the current path with their remaining neighbors
+    starts: VecDeque<T>,               // start vertices, which are visited once the stack is empty
+}
+
+impl<T, N> Dfs<T, N>
+where
+    T: Clone + Hash + Eq,
+    N: Neighbors<T>,
+{
+    /// Starts a traversal of the given view at `start`.
+    pub fn new(view: N, start: T) -> Self {
+        Dfs {
+            view,
+            tree: Tree::new(),
+            stack: Vec::new(),
+            starts: VecDeque::from([start]),
+        }
+    }
+
+    /// Adds another start vertex, at which the traversal continues once everything reachable from the previous
+    /// start vertices is finished, e.g. to visit every vertex. Vertices which were discovered before aren't visited
+    /// again, and a start vertex which was discovered before is skipped.
+    pub fn restart_from(&mut self, start: T) {
+        self.starts.push_back(start);
+    }
+
+    /// Returns an iterator over the vertices in the order they are discovered (preorder).
+    pub fn vertices(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.filter_map(Event::discovered)
+    }
+
+    /// Returns an iterator over the vertices in the order they are finished (postorder).
+    pub fn finished(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.filter_map(Event::finished)
+    }
+
+    /// Returns true if the vertex has been discovered so far.
+    pub fn is_discovered(&self, vertex: &T) -> bool {
+        self.tree.depth(vertex).is_some()
+    }
+
+    /// Returns true if the vertex has been discovered, but not finished so far, i.e. it is on the current path.
+    /// Takes time linear in the length of the path.
+    pub fn is_on_path(&self, vertex: &T) -> bool {
+        self.stack.iter().any(|(v, _)| v == vertex)
+    }
+
+    /// Returns the number of edges from the start to the vertex in the parent tree,
+    /// or `None` if it hasn't been discovered so far.
+    pub fn depth(&self, vertex: &T) -> Option<usize> {
+        self.tree.depth(vertex)
+    }
+
+    /// Returns the vertex the given vertex has been discovered from.
+    /// `None` if it is a start vertex or hasn't been discovered so far.
+    pub fn parent(&self, vertex: &T) -> Option<&T> {
+        self.tree.parent(vertex)
+    }
+
+    /// Returns the path from the start through the parent tree to the vertex, or `None` if it hasn't been discovered so far.
+    pub fn path_to(&self, vertex: &T) -> Option<Vec<T>> {
+        self.tree.path_to(vertex)
+    }
+
+    // discovers the vertex and pushes it on the stack, if it wasn't discovered before
+    fn enter(&mut self, vertex: T, parent: Option<T>, depth: usize) -> Option<Event<T>> {
+        let event = self.tree.discover(vertex.clone(), parent, depth)?;
+        let neighbors = self.view.neighbors(&vertex).into_iter();
+        self.stack.push((vertex, neighbors));
+        Some(event)
+    }
+}
+
+impl<T, N> Iterator for Dfs<T, N>
+where
+    T: Clone + Hash + Eq,
+    N: Neighbors<T>,
+{
+    type Item = Event<T>;
+
+    fn next(&mut self) -> Option<Event<T>> {
+        while self.stack.is_empty() {
+            let start = self.starts.pop_front()?;
+            if let Some(event) = self.enter(start, None, 0) {
+                return Some(event);
+            }
+        }
+        loop {
+            let depth = self.stack.len();
+            let (vertex, neighbors) = self.stack.last_mut()?;
+            match neighbors.next() {
+                Some(next) => {
+                    let parent = Some(vertex.clone());
+                    if let Some(event) = self.enter(next, parent, depth) {
+                        return Some(event);
+                    }
+                }
+                None => {
+                    let (vertex, _) = self.stack.pop().unwrap(); // safe, the stack isn't empty
+                    return Some(Event::Finish { vertex });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::algo::traversal::*;
+use crate::graph::{directed::Digraph, labeled_undirected::LabeledUGraph, undirected::UGraph};
+use crate::traits::{Graph, Labeled, Unweighted, WT};
+use crate::wt::{directed::WTDigraph, undirected::WTUGraph};
+
+//   0 -> 1 -> 3 -> 4
+//   |         ^
+//   v         |
+//   2 --------+      5 -> 0
+fn digraph() -> Digraph {
+    let adj = vec![vec![1, 2], vec![3], vec![3], vec![4], vec![], vec![0]];
+    Digraph::from_adjacency_list(6, 6, adj)
+}
+
+fn discover(vertex: usize, parent: Option<usize>, depth: usize) -> Event<usize> {
+    Event::Discover {
+        vertex,
+        parent,
+        depth,
+    }
+}
+
+fn finish(vertex: usize) -> Event<usize> {
+    Event::Finish { vertex }
+}
+
+#[test]
+fn bfs() {
+    let dg = digraph();
+    let events: Vec<Event<usize>> = Bfs::new(Outgoing(&dg), 0).collect();
+    assert_eq!(
+        events,
+        vec![
+            discover(0, None, 0),
+            discover(1, Some(0), 1),
+            discover(2, Some(0), 1),
+            finish(0),
+            discover(3, Some(1), 2),
+            finish(1),
+            finish(2),
+            discover(4, Some(3), 3),
+            finish(3),
+            finish(4),
+        ]
+    );
+
+    let mut bfs = Bfs::new(Outgoing(&dg), 0);
+    bfs.by_ref().for_each(drop);
+    assert_eq!(bfs.depth(&4), Some(3));
+    assert_eq!(bfs.parent(&3), Some(&1));
+    assert_eq!(bfs.parent(&0), None);
+    assert_eq!(bfs.path_to(&4), Some(vec![0, 1, 3, 4]));
+    assert_eq!(bfs.path_to(&5), None);
+    assert!(!bfs.is_discovered(&5));
+
+    // the reversed graph
+    let order: Vec<usize> = Bfs::new(Incoming(&dg), 3).vertices().collect();
+    assert_eq!(order, vec![3, 1, 2, 0, 5]);
+}
+
+#[test]
+fn dfs() {
+    let dg = digraph();
+    let events: Vec<Event<usize>> = Dfs::new(Outgoing(&dg), 0).collect();
+    assert_eq!(
+        events,
+        vec![
+            discover(0, None, 0),
+            discover(1, Some(0), 1),
+            discover(3, Some(1), 2),
+            discover(4, Some(3), 3),
+            finish(4),
+            finish(3),
+            finish(1),
+            discover(2, Some(0), 1),
+            finish(2),
+            finish(0),
+        ]
+    );
+
+    let postorder: Vec<usize> = Dfs::new(Outgoing(&dg), 0).finished().collect();
+    assert_eq!(postorder, vec![4, 3, 1, 2, 0]);
+
+    let mut dfs = Dfs::new(Outgoing(&dg), 0);
+    assert_eq!(dfs.vertices().find(|&v| v == 3), Some(3));
+    assert!(dfs.is_on_path(&1));
+    assert!(!dfs.is_on_path(&2));
+    assert_eq!(dfs.path_to(&3), Some(vec![0, 1, 3]));
+    assert!(!dfs.is_discovered(&2));
+}
+
+#[test]
+fn restart() {
+    let dg = digraph();
+    let mut bfs = Bfs::new(Outgoing(&dg), 3);
+    for v in 0..6 {
+        bfs.restart_from(v);
+    }
+    let order: Vec<usize> = bfs.vertices().collect();
+    assert_eq!(order, vec![3, 4, 0, 1, 2, 5]);
+    assert_eq!(bfs.depth(&5), Some(0));
+
+    let mut dfs = Dfs::new(Outgoing(&dg), 3);
+    dfs.restart_from(3);
+    dfs.restart_from(5);
+    let order: Vec<usize> = dfs.vertices().collect();
+    assert_eq!(order, vec![3, 4, 5, 0, 1, 2]);
+    assert_eq!(dfs.parent(&0), Some(&5));
+}
+
+#[test]
+fn wt_views() {
+    let mut wtd = WTDigraph::from_digraph(digraph());
+    wtd.add_edge(4, 5);
+    wtd.delete_edge(0, 1);
+
+    let committed: Vec<usize> = Bfs::new(Outgoing(&wtd), 0).vertices().collect();
+    assert_eq!(committed, vec![0, 1, 2, 3, 4]);
+    let updated: Vec<usize> = Bfs::new(OutgoingUpdated(&wtd), 0).vertices().collect();
+    assert_eq!(updated, vec![0, 2, 3, 4, 5]);
+    let updated: Vec<usize> = Dfs::new(IncomingUpdated(&wtd), 0).vertices().collect();
+    assert_eq!(updated, vec![0, 5, 4, 3, 1, 2]);
+
+    let ug = UGraph::from_adjacency_list(4, 2, vec![vec![1], vec![2], vec![], vec![]]);
+    let mut wtu = WTUGraph::from_ugraph(ug);
+    wtu.add_edge(3, 2);
+    let committed: Vec<usize> = Dfs::new(Edges(&wtu), 2).vertices().collect();
+    assert_eq!(committed, vec![2, 1, 0]);
+    let mut bfs = Bfs::new(EdgesUpdated(&wtu), 0);
+    let updated: Vec<usize> = bfs.vertices().collect();
+    assert_eq!(updated, vec![0, 1, 2, 3]);
+    assert_eq!(bfs.depth(&3), Some(3));
+
+    wtu.commit_edits();
+    assert_eq!(Bfs::new(Edges(&wtu), 0).vertices().count(), 4);
+}
+
+#[test]
+fn labeled() {
+    let mut lug: LabeledUGraph<String> = LabeledUGraph::new();
+    for label in ["a", "b", "c", "d"] {
+        lug.add_vertex(label.to_string());
+    }
+    lug.add_edge("a".to_string(), "b".to_string());
+    lug.add_edge("c".to_string(), "b".to_string());
+
+    let mut bfs = Bfs::new(Edges(&lug), "a".to_string());
+    let order: Vec<String> = bfs.vertices().collect();
+    assert_eq!(order, vec!["a", "b", "c"]);
+    assert_eq!(bfs.parent(&"c".to_string()), Some(&"b".to_string()));
+    assert!(!bfs.is_discovered(&"d".to_string()));
+    assert_eq!(lug.index(&"d".to_string()), Some(3));
+}
+
+#[test]
+#[should_panic]
+fn missing_start() {
+    let dg = digraph();
+    Bfs::new(Outgoing(&dg), 9).for_each(drop);
+}
+
+#[test]
+fn deleted_vertex() {
+    // 0 -> 1 -> 2, and 0 -> 2; the commit keeps the edges to the deleted vertex 1
+    let dg = Digraph::from_adjacency_list(3, 3, vec![vec![1, 2], vec![2], vec![]]);
+    let mut wtd = WTDigraph::from_digraph(dg);
+    wtd.delete_vertex(1);
+    let updated: Vec<usize> = Bfs::new(OutgoingUpdated(&wtd), 0).vertices().collect();
+    assert_eq!(updated, vec![0, 2]);
+    wtd.commit_edits();
+    let committed: Vec<usize> = Bfs::new(Outgoing(&wtd), 0).vertices().collect();
+    assert_eq!(committed, vec![0, 2]);
+    let committed: Vec<usize> = Dfs::new(Incoming(&wtd), 2).vertices().collect();
+    assert_eq!(committed, vec![2, 0]);
+
+    let ug = UGraph::from_adjacency_list(3, 2, vec![vec![1], vec![2], vec![]]);
+    let mut wtu = WTUGraph::from_ugraph(ug);
+    wtu.delete_vertex(1);
+    assert_eq!(Bfs::new(EdgesUpdated(&wtu), 0).vertices().count(), 1);
+    wtu.commit_edits();
+    assert_eq!(Bfs::new(Edges(&wtu), 2).vertices().count(), 1);
+}
@@ -0,0 +1,171 @@
+// The algorithms behind traits::Triangles.
+//
+// Every graph type lists the neighbors of its vertices through its SearchView, and each edge is directed from the
+// vertex with fewer neighbors to the one with more, so that every triangle is found exactly once, at its lowest vertex.
+// Wt-graphs orient the edges the same way, by (degree, index) with the degree from undirected_degree, and read the
+// neighbors of every vertex once from the QW-tree: the smaller ones with select queries, the bigger ones from its range.
+// Only the higher ranked neighbors are kept, one list per vertex, and the triangles at a vertex u are counted by marking
+// its list and walking the lists of the marked vertices. With the degree order, no list is longer than the square root
+// of twice the number of edges, so a hub doesn't make the counting quadratic.
+
+use crate::algo::search::{self, SearchView};
+use crate::traits::{Graph, TriangleCounts};
+use crate::wt::directed::WTDigraph;
+use crate::wt::iter::IncomingIter;
+use qwt::AccessUnsigned;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) fn triangle_counts<T, S>(graph: &S) -> TriangleCounts<T>
+where
+    T: Hash + Eq + Clone,
+    S: SearchView<T>,
+{
+    let vertices = graph.search_vertices();
+    let positions: HashMap<T, usize> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.clone(), i))
+        .collect();
+    // the neighbors of every vertex by position, sorted, without duplicates and the vertex itself
+    let neighbors: Vec<Vec<usize>> = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let mut neighbors: Vec<usize> = search::successors(graph, v)
+                .iter()
+                .map(|next| positions[next])
+                .filter(|&j| j != i)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+
+    let rank = |v: usize| (neighbors[v].len(), v);
+    let higher: Vec<Vec<usize>> = neighbors
+        .iter()
+        .enumerate()
+        .map(|(u, list)| {
+            list.iter()
+                .copied()
+                .filter(|&v| rank(v) > rank(u))
+                .collect()
+        })
+        .collect();
+    let mut triangles = vec![0; vertices.len()];
+    for u in 0..vertices.len() {
+        for &v in higher[u].iter() {
+            for w in intersection(&higher[u], &higher[v]) {
+                triangles[u] += 1;
+                triangles[v] += 1;
+                triangles[w] += 1;
+            }
+        }
+    }
+
+    TriangleCounts {
+        degrees: vertices
+            .iter()
+            .cloned()
+            .zip(neighbors.iter().map(|n| n.len()))
+            .collect(),
+        triangles: vertices.into_iter().zip(triangles).collect(),
+    }
+}
+
+// the common elements of two sorted lists
+fn intersection<'a>(a: &'a [usize], b: &'a [usize]) -> impl Iterator<Item = usize> + 'a {
+    let (mut i, mut j) = (0, 0);
+    std::iter::from_fn(move || {
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                    return Some(a[i - 1]);
+                }
+            }
+        }
+        None
+    })
+}
+
+// `dg` is the wt-digraph inside an undirected wt-graph; `label` returns the vertex of the caller at an index
+pub(crate) fn wt_triangle_counts<T, L>(dg: &WTDigraph, label: L) -> TriangleCounts<T>
+where
+    T: Hash + Eq + Clone,
+    L: Fn(usize) -> Option<T>,
+{
+    let len = dg.wt_adj_len;
+    let exists: Vec<bool> = (0..len).map(|v| dg.vertex_exists(v)).collect();
+    let rank = |v: usize| (dg.undirected_degree(v), v);
+    let ranks: Vec<(usize, usize)> = (0..len)
+        .map(|v| if exists[v] { rank(v) } else { (0, v) })
+        .collect();
+
+    // the higher ranked neighbors of every vertex, with the lists one after another
+    let mut degrees = vec![0; len];
+    let mut starts = Vec::with_capacity(len + 1);
+    let mut higher = Vec::new();
+    for u in 0..len {
+        starts.push(higher.len());
+        if !exists[u] {
+            continue;
+        }
+        let neighbors = neighbors(dg, u, &exists);
+        degrees[u] = neighbors.len();
+        higher.extend(neighbors.into_iter().filter(|&v| ranks[v] > ranks[u]));
+    }
+    starts.push(higher.len());
+    let higher = |v: usize| &higher[starts[v]..starts[v + 1]];
+
+    // a triangle is found at its lowest vertex u, from the edge to its middle vertex v
+    let mut triangles = vec![0; len];
+    let mut marked = vec![usize::MAX; len];
+    for u in 0..len {
+        for &v in higher(u) {
+            marked[v] = u;
+        }
+        for &v in higher(u) {
+            for &w in higher(v) {
+                if marked[w] == u {
+                    triangles[u] += 1;
+                    triangles[v] += 1;
+                    triangles[w] += 1;
+                }
+            }
+        }
+    }
+
+    let mut counts = TriangleCounts {
+        triangles: HashMap::new(),
+        degrees: HashMap::new(),
+    };
+    for v in (0..len).filter(|&v| exists[v]) {
+        if let Some(vertex) = label(v) {
+            counts.triangles.insert(vertex.clone(), triangles[v]);
+            counts.degrees.insert(vertex, degrees[v]);
+        }
+    }
+    counts
+}
+
+// the neighbors of the vertex which exist, sorted, each once and without the vertex itself.
+// the smaller ones are the sources of its incoming edges, the bigger ones are read from its range
+fn neighbors(dg: &WTDigraph, vertex: usize, exists: &[bool]) -> Vec<usize> {
+    let (start, end) = dg.outgoing_range(vertex);
+    let mut neighbors: Vec<usize> = IncomingIter::new(dg, vertex)
+        .chain((start..end).map(|pos| dg.wt_adj.get(pos).unwrap())) // safe, pos is inside the range
+        .filter(|&next| next != vertex && exists[next])
+        .collect();
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    neighbors
+}
@@ -0,0 +1,149 @@
+use crate::graph::undirected::UGraph;
+use crate::io::*;
+use crate::traits::{Graph, TriangleCounts, Triangles, Unweighted, WT};
+use crate::wt::undirected::WTUGraph;
+use std::collections::HashMap;
+
+//   0 --- 1 --- 3 --- 4 (self-loop)
+//    \   / \   /
+//     \ /   \ /
+//      2 ----+
+const TEXT: &str = "5\n7\n0 1\n0 2\n2 1\n1 3\n2 3\n3 4\n4 4\n";
+const WEIGHTED: &str = "5\n7\n0 1 1\n0 2 1\n2 1 1\n1 3 1\n2 3 1\n3 4 1\n4 4 1\n";
+
+fn check(counts: &TriangleCounts<usize>) {
+    assert_eq!(counts.triangle_count(), 2);
+    assert_eq!(
+        counts.triangles,
+        HashMap::from([(0, 1), (1, 2), (2, 2), (3, 1), (4, 0)])
+    );
+    assert_eq!(
+        counts.degrees,
+        HashMap::from([(0, 2), (1, 3), (2, 3), (3, 3), (4, 1)])
+    );
+    let local = counts.local_clustering();
+    assert_eq!(local[&0], 1.0);
+    assert_eq!(local[&1], 2.0 / 3.0);
+    assert_eq!(local[&3], 1.0 / 3.0);
+    assert_eq!(local[&4], 0.0);
+    // 6 of the 10 paths of length two are closed
+    assert_eq!(counts.global_clustering(), 0.6);
+}
+
+#[test]
+fn triangle_counts() {
+    check(&read_ugraph(TEXT.as_bytes()).unwrap().triangle_counts());
+    check(&read_wt_ugraph(TEXT.as_bytes()).unwrap().triangle_counts());
+}
+
+#[test]
+fn parallel_edges() {
+    let mut ug = read_ugraph(TEXT.as_bytes()).unwrap();
+    ug.add_edge(1, 0);
+    ug.add_edge(2, 3);
+    check(&ug.triangle_counts());
+    check(&WTUGraph::from_ugraph(ug).triangle_counts());
+}
+
+#[test]
+fn deleted_vertices() {
+    let mut ug: UGraph = read_ugraph(TEXT.as_bytes()).unwrap();
+    let mut wtug: WTUGraph = read_wt_ugraph(TEXT.as_bytes()).unwrap();
+    ug.delete_vertex(3);
+    // the edges of 3 stay in the wavelet tree
+    wtug.delete_vertex(3);
+    wtug.commit_edits();
+    for counts in [ug.triangle_counts(), wtug.triangle_counts()] {
+        assert_eq!(counts.triangle_count(), 1);
+        assert!(!counts.triangles.contains_key(&3));
+        assert_eq!(counts.degrees[&1], 2);
+        assert_eq!(counts.degrees[&4], 0);
+        assert_eq!(counts.global_clustering(), 1.0);
+    }
+}
+
+#[test]
+fn hub_and_random_edges() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // vertex 0 is a hub with the smallest index, so orienting by index alone would start every triangle there
+    let mut rng = StdRng::seed_from_u64(16);
+    let mut ug = UGraph::new();
+    ug.add_vertex(59);
+    for v in 1..60 {
+        ug.add_edge(0, v);
+    }
+    for _ in 0..150 {
+        let (u, v) = (rng.gen_range(1..60), rng.gen_range(1..60));
+        if !ug.edge_exists(u, v) {
+            ug.add_edge(u, v);
+        }
+    }
+    ug.delete_vertex(7);
+    let expected = ug.triangle_counts();
+    let mut wtug = WTUGraph::from_ugraph(ug);
+    let counts = wtug.triangle_counts();
+    assert_eq!(counts.triangles, expected.triangles);
+    assert_eq!(counts.degrees, expected.degrees);
+
+    wtug.delete_vertex(11);
+    wtug.commit_edits();
+    let counts = wtug.triangle_counts();
+    assert!(!counts.triangles.contains_key(&11));
+    assert_eq!(
+        counts.triangles[&0],
+        counts.degrees.values().sum::<usize>() / 2 - counts.degrees[&0]
+    );
+}
+
+#[test]
+fn empty_graph() {
+    let counts = UGraph::new().triangle_counts();
+    assert_eq!(counts.triangle_count(), 0);
+    assert!(counts.local_clustering().is_empty());
+    assert_eq!(counts.global_clustering(), 0.0);
+}
+
+#[test]
+fn all_types() {
+    fn check_labeled<G: Triangles<String>>(graph: G) {
+        let counts = graph.triangle_counts();
+        assert_eq!(counts.triangle_count(), 2);
+        assert_eq!(counts.triangles["1"], 2);
+        assert_eq!(counts.local_clustering()["0"], 1.0);
+    }
+    check(
+        &read_weighted_ugraph::<_, i32>(WEIGHTED.as_bytes())
+            .unwrap()
+            .triangle_counts(),
+    );
+    check(
+        &read_weighted_wt_ugraph::<_, i32>(WEIGHTED.as_bytes())
+            .unwrap()
+            .triangle_counts(),
+    );
+    check_labeled(read_labeled_ugraph::<_, String>(TEXT.as_bytes()).unwrap());
+    check_labeled(read_labeled_wt_ugraph::<_, String>(TEXT.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_ugraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap());
+    check_labeled(read_labeled_weighted_wt_ugraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap());
+}
+
+#[test]
+fn big_hub() {
+    // a star with 20000 leaves and a path through them; walking all neighbors of the hub from every leaf
+    // would take minutes
+    let n = 20001;
+    let mut adj = vec![(1..n).collect::<Vec<usize>>()];
+    adj.extend((1..n).map(|v| if v + 1 < n { vec![v + 1] } else { vec![] }));
+    let ug = UGraph::from_adjacency_list(n, 2 * n - 3, adj);
+    let counts = WTUGraph::from_ugraph(ug).triangle_counts();
+    assert_eq!(counts.triangle_count(), n - 2);
+    assert_eq!((counts.triangles[&0], counts.degrees[&0]), (n - 2, n - 1));
+    for v in [1, n - 1] {
+        assert_eq!((counts.triangles[&v], counts.degrees[&v]), (1, 2));
+    }
+    for v in 2..n - 1 {
+        assert_eq!((counts.triangles[&v], counts.degrees[&v]), (2, 3));
+    }
+}
@@ -1,4 +1,19 @@
-use crate::traits::{Directed, Graph, Unlabeled, Unweighted};
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::pagerank;
+use crate::algo::search::{self, SearchView};
+use crate::algo::topological;
+use crate::traits::{
+    Directed, Graph, TryDirected, TryGraph, TryUnweighted, Unlabeled, Unweighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::TopologicalSort;
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -176,6 +191,19 @@ impl Directed<usize> for Digraph {
             self.delete_edge(from, vertex)
         }
     }
+
+    // returns the length of the vertex' adjacency-vector
+    fn out_degree(&self, vertex: usize) -> usize {
+        self.adj[vertex].len()
+    }
+
+    // counts the edges to the given vertex in all adjacency-vectors, parallel edges count separately like in out_degree
+    fn in_degree(&self, vertex: usize) -> usize {
+        self.adj
+            .iter()
+            .map(|adj| adj.iter().filter(|&&to| to == vertex).count())
+            .sum()
+    }
 }
 impl Unlabeled<usize> for Digraph {
     // adds a new empty vertex at either the index following the last or at (the lowest available) previously freed index.
@@ -216,3 +244,177 @@ impl Unweighted<usize> for Digraph {
         self.adj[from].push(to);
     }
 }
+
+impl TryGraph<usize> for Digraph {
+    // never fails for index based graphs, see add_vertex
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        Ok(self.add_vertex(vertex))
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from, to)? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        if !(self.vertex_exists(from) && self.vertex_exists(to)) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.edge_exists(from, to))
+    }
+}
+impl TryDirected<usize> for Digraph {
+    fn try_outgoing_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.outgoing_edges(vertex))
+    }
+
+    fn try_incoming_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.incoming_edges(vertex))
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+impl TryUnweighted<usize> for Digraph {
+    fn try_add_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if !(self.vertex_exists(from) && self.vertex_exists(to)) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.add_edge(from, to);
+        Ok(())
+    }
+}
+
+impl GraphSearch<usize> for Digraph {
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<Option<ShortestPath<usize, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<ShortestPaths<usize, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl SearchView<usize> for Digraph {
+    type Distance = usize;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        (0..self.adj.len()).filter(|&v| self.vertex_exists(v)).collect()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.outgoing_edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.incoming_edges(*vertex)
+    }
+
+    fn distance(&self, _from: &usize, _to: &usize) -> usize {
+        1
+    }
+}
+
+impl TopologicalSort<usize> for Digraph {
+    fn topological_order(&self) -> Result<Vec<usize>, Vec<usize>> {
+        topological::topological_order(self)
+    }
+}
+
+impl PageRank<usize> for Digraph {
+    fn pagerank(&self, options: &PageRankOptions<usize>) -> Result<HashMap<usize, f64>, GraphError> {
+        pagerank::pagerank(self, options)
+    }
+}
+
+impl Centrality<usize> for Digraph {
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl DirectedCores<usize> for Digraph {
+    fn in_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl Neighborhood<usize> for Digraph {
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
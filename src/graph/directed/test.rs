@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use crate::graph::directed::Digraph;
-use crate::traits::{Directed, Graph, Unlabeled, Unweighted};
+use crate::graph::labeled_directed::LabeledDigraph;
+use crate::traits::{Directed, Graph, TryDirected, TryGraph, TryUnweighted, Unlabeled, Unweighted};
+use crate::wt::directed::WTDigraph;
+use crate::GraphError;
 
 #[test]
 fn new() {
@@ -141,3 +144,38 @@ fn delete_incoming_edges() {
     assert_eq!(digraph.e_count(), 0);
     assert_eq!(digraph.adj, vec![vec![]; 5]);
 }
+#[test]
+fn try_functions() {
+    let mut digraph = Digraph::from_adjacency_list(3, 1, vec![vec![1], vec![], vec![]]);
+    assert_eq!(digraph.try_edge_exists(0, 1), Ok(true));
+    assert_eq!(digraph.try_edge_exists(0, 3), Err(GraphError::VertexNotFound));
+    assert_eq!(digraph.try_add_edge(3, 0), Err(GraphError::VertexNotFound));
+    assert_eq!(digraph.try_delete_edge(1, 0), Err(GraphError::EdgeNotFound));
+    assert_eq!(digraph.try_outgoing_edges(5), Err(GraphError::VertexNotFound));
+    assert_eq!(digraph.try_delete_edge(0, 1), Ok(()));
+    assert_eq!(digraph.try_add_edge(2, 0), Ok(()));
+    assert_eq!(digraph.try_incoming_edges(0), Ok(vec![2]));
+    assert_eq!(digraph.try_delete_vertex(2), Ok(()));
+    assert_eq!(digraph.try_delete_vertex(2), Err(GraphError::VertexNotFound));
+}
+#[test]
+fn degrees() {
+    let digraph = Digraph::from_adjacency_list(3, 3, vec![vec![1, 2], vec![2], vec![]]);
+    assert_eq!(digraph.out_degree(0), 2);
+    assert_eq!(digraph.in_degree(2), 2);
+    assert_eq!(digraph.in_degree(0), 0);
+}
+#[test]
+fn parallel_edge_degrees() {
+    // parallel edges count separately in both degrees, for every digraph type
+    let adj = vec![vec![1, 1, 2], vec![2], vec![]];
+    let digraph = Digraph::from_adjacency_list(3, 4, adj.clone());
+    assert_eq!(digraph.out_degree(0), 3);
+    assert_eq!(digraph.in_degree(1), 2);
+    assert_eq!(digraph.in_degree(2), 2);
+    let ldg = LabeledDigraph::from_adjacency_list(3, 4, adj, vec!['a', 'b', 'c']);
+    assert_eq!(ldg.in_degree('b'), 2);
+    let wtdg = WTDigraph::from_digraph(digraph.clone());
+    assert_eq!(wtdg.in_degree(1), digraph.in_degree(1));
+    assert_eq!(wtdg.out_degree(0), digraph.out_degree(0));
+}
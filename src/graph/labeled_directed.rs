@@ -1,5 +1,21 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::pagerank;
+use crate::algo::search::{self, SearchView};
+use crate::algo::topological;
 use crate::graph::directed::Digraph;
-use crate::traits::{Directed, Graph, Labeled, Unlabeled, Unweighted};
+use crate::traits::{
+    Directed, Graph, Labeled, TryDirected, TryGraph, TryLabeled, TryUnweighted, Unlabeled,
+    Unweighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::TopologicalSort;
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -171,6 +187,23 @@ where
         }
         self.dg.delete_incoming_edges(vertex_index.unwrap());
     }
+
+    fn out_degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("ldg out_degree : Vertex doesn't exist");
+        }
+        self.dg.out_degree(vertex_index.unwrap())
+    }
+
+    // forwards to dg, because incoming_edges doesn't contain parallel edges
+    fn in_degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("ldg in_degree : Vertex doesn't exist");
+        }
+        self.dg.in_degree(vertex_index.unwrap())
+    }
 }
 impl<L> Labeled<L> for LabeledDigraph<L>
 where
@@ -237,3 +270,228 @@ where
         self.dg.add_edge(from_index.unwrap(), to_index.unwrap());
     }
 }
+
+impl<L> TryGraph<L> for LabeledDigraph<L>
+where
+    L: Eq + Hash + Clone,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        if self.label_index.contains_key(&vertex) {
+            return Err(GraphError::LabelExists);
+        }
+        Ok(self.add_vertex(vertex))
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from.clone(), to.clone())? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        self.dg.try_edge_exists(from_index, to_index)
+    }
+}
+impl<L> TryDirected<L> for LabeledDigraph<L>
+where
+    L: Eq + Hash + Clone,
+{
+    fn try_outgoing_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.outgoing_edges(vertex))
+    }
+
+    fn try_incoming_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.incoming_edges(vertex))
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+impl<L> TryLabeled<L> for LabeledDigraph<L>
+where
+    L: Eq + Hash + Clone,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        if self.index(&old_label).is_none() {
+            return Err(GraphError::VertexNotFound);
+        }
+        if self.index(&new_label).is_some() {
+            return Err(GraphError::LabelExists);
+        }
+        self.edit_label(old_label, new_label);
+        Ok(())
+    }
+}
+impl<L> TryUnweighted<L> for LabeledDigraph<L>
+where
+    L: Eq + Hash + Clone,
+{
+    fn try_add_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        if !(self.vertex_exists(from.clone()) && self.vertex_exists(to.clone())) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.add_edge(from, to);
+        Ok(())
+    }
+}
+
+impl<L> GraphSearch<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<Option<ShortestPath<L, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<ShortestPaths<L, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<HashMap<L, ShortestPaths<L, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L> SearchView<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = false;
+
+    // the labels of the existing vertices, by index
+    fn search_vertices(&self) -> Vec<L> {
+        self.dg
+            .search_vertices()
+            .into_iter()
+            .filter_map(|v| self.label(v).cloned())
+            .collect()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.outgoing_edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.incoming_edges(vertex.clone())
+    }
+
+    fn distance(&self, _from: &L, _to: &L) -> usize {
+        1
+    }
+}
+
+impl<L> TopologicalSort<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn topological_order(&self) -> Result<Vec<L>, Vec<L>> {
+        topological::topological_order(self)
+    }
+}
+
+impl<L> PageRank<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn pagerank(&self, options: &PageRankOptions<L>) -> Result<HashMap<L, f64>, GraphError> {
+        pagerank::pagerank(self, options)
+    }
+}
+
+impl<L> Centrality<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L> DirectedCores<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn in_core_numbers(&self) -> HashMap<L, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<L, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<L> Neighborhood<L> for LabeledDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
@@ -1,7 +1,23 @@
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::search::{self, SearchView};
+use crate::algo::triangles;
 use crate::graph::labeled_directed::LabeledDigraph;
-use crate::traits::{Graph, Labeled, Undirected, Unweighted};
+use crate::traits::{
+    Graph, Labeled, TryGraph, TryLabeled, TryUndirected, TryUnweighted, Undirected, Unweighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
 use std::hash::Hash;
 
 #[cfg(test)]
@@ -157,3 +173,203 @@ where
         }
     }
 }
+
+impl<L> TryGraph<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.ldg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.ldg.try_delete_edge(from, to)
+        } else {
+            self.ldg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.ldg.try_edge_exists(from, to)
+        } else {
+            self.ldg.try_edge_exists(to, from)
+        }
+    }
+}
+impl<L> TryUndirected<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+impl<L> TryLabeled<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.ldg.try_edit_label(old_label, new_label)
+    }
+}
+
+impl<L> TryUnweighted<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.ldg.try_add_edge(from, to)
+        } else {
+            self.ldg.try_add_edge(to, from)
+        }
+    }
+}
+
+impl<L> GraphSearch<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<Option<ShortestPath<L, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<ShortestPaths<L, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<HashMap<L, ShortestPaths<L, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L> SearchView<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.ldg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn distance(&self, _from: &L, _to: &L) -> usize {
+        1
+    }
+}
+
+impl<L> Triangles<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn triangle_counts(&self) -> TriangleCounts<L> {
+        triangles::triangle_counts(self)
+    }
+}
+
+impl<L> Centrality<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L> Cores<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn core_numbers(&self) -> HashMap<L, usize> {
+        cores::core_numbers(self)
+    }
+
+    fn degeneracy_order(&self) -> Vec<L> {
+        cores::degeneracy_order(self)
+    }
+}
+
+impl<L> Biconnectivity<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<L> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<L> Neighborhood<L> for LabeledUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
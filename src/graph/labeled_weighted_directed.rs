@@ -1,6 +1,20 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::pagerank;
+use crate::algo::search::{self, SearchView};
 use crate::graph::labeled_directed::LabeledDigraph;
-use crate::traits::{Directed, Graph, Labeled, Unweighted, Weighted};
-use num::Num;
+use crate::traits::{
+    Directed, Graph, Labeled, TryDirected, TryGraph, TryLabeled, TryWeighted, Unweighted, Weighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::TopologicalSort;
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::GraphError;
+use num::{Num, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -149,6 +163,14 @@ where
             self.delete_edge(from, vertex.clone());
         }
     }
+
+    fn out_degree(&self, vertex: L) -> usize {
+        self.ldg.out_degree(vertex)
+    }
+
+    fn in_degree(&self, vertex: L) -> usize {
+        self.ldg.in_degree(vertex)
+    }
 }
 impl<L, W> Labeled<L> for LabeledWeightedDigraph<L, W>
 where
@@ -209,7 +231,7 @@ where
         self.weights.insert((from_index, to_index), weight);
     }
 
-    fn weight(&mut self, from: L, to: L) -> W {
+    fn weight(&self, from: L, to: L) -> W {
         let from_index = self.index(&from);
         let to_index = self.index(&to);
         if from_index.is_none() {
@@ -224,3 +246,236 @@ where
         return self.weights.get(&(from_index, to_index)).unwrap().clone();
     }
 }
+
+impl<L, W> TryGraph<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Eq + Hash + Clone,
+    W: Num,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.ldg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from.clone(), to.clone())? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        self.ldg.try_edge_exists(from, to)
+    }
+}
+impl<L, W> TryDirected<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Eq + Hash + Clone,
+    W: Num,
+{
+    fn try_outgoing_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_outgoing_edges(vertex)
+    }
+
+    fn try_incoming_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_incoming_edges(vertex)
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+impl<L, W> TryLabeled<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Eq + Hash + Clone,
+    W: Num,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.ldg.try_edit_label(old_label, new_label)
+    }
+}
+impl<L, W> TryWeighted<L, W> for LabeledWeightedDigraph<L, W>
+where
+    L: Eq + Hash + Clone,
+    W: Clone + Num,
+{
+    fn try_add_edge(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        if !(self.vertex_exists(from.clone()) && self.vertex_exists(to.clone())) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.add_edge(from, to, weight);
+        Ok(())
+    }
+
+    fn try_edit_weight(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from.clone(), to.clone())? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.edit_weight(from, to, weight);
+        Ok(())
+    }
+
+    fn try_weight(&self, from: L, to: L) -> Result<W, GraphError> {
+        if !self.try_edge_exists(from.clone(), to.clone())? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        self.weights
+            .get(&(from_index, to_index))
+            .cloned()
+            .ok_or(GraphError::WeightNotFound)
+    }
+}
+
+impl<L, W> GraphSearch<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<Option<ShortestPath<L, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<ShortestPaths<L, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<HashMap<L, ShortestPaths<L, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L, W> SearchView<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.ldg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.outgoing_edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.incoming_edges(vertex.clone())
+    }
+
+    fn distance(&self, from: &L, to: &L) -> W {
+        self.weight(from.clone(), to.clone())
+    }
+}
+
+impl<L, W> TopologicalSort<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order(&self) -> Result<Vec<L>, Vec<L>> {
+        self.ldg.topological_order()
+    }
+}
+
+impl<L, W> PageRank<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn pagerank(&self, options: &PageRankOptions<L>) -> Result<HashMap<L, f64>, GraphError> {
+        pagerank::pagerank(self, options)
+    }
+}
+
+impl<L, W> Centrality<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L, W> DirectedCores<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn in_core_numbers(&self) -> HashMap<L, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<L, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<L, W> Neighborhood<L> for LabeledWeightedDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
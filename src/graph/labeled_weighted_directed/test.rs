@@ -165,7 +165,7 @@ fn edit_weight() {
 }
 #[test]
 fn get_weight() {
-    let mut lwdg = setuplwdg();
+    let lwdg = setuplwdg();
     let weights: Vec<Vec<f64>> = vec![
         vec![0.22, 0.1111111],
         vec![],
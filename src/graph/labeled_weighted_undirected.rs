@@ -1,8 +1,26 @@
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::search::{self, SearchView};
+use crate::algo::spanning;
+use crate::algo::triangles;
 use crate::graph::labeled_weighted_directed::LabeledWeightedDigraph;
-use crate::traits::{Graph, Labeled, Undirected, Weighted};
-use num::Num;
+use crate::traits::{
+    Graph, Labeled, TryGraph, TryLabeled, TryUndirected, TryWeighted, Undirected, Weighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{SpanningForest, SpanningTree, SpanningTreeAlgorithm};
+use crate::GraphError;
+use num::{Num, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
 use std::hash::Hash;
 
 #[cfg(test)]
@@ -40,6 +58,28 @@ where
             lwdg: LabeledWeightedDigraph::from_adjacency_list(v_count, e_count, adj, labels),
         };
     }
+
+    // builds a graph of the vertices and edges of the forest; the vertices are indexed in the order of forest.vertices
+    pub fn from_spanning_forest(forest: SpanningForest<L, W>) -> Self {
+        let index: HashMap<&L, usize> = forest
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (label, i))
+            .collect();
+        let mut adj = vec![Vec::new(); forest.vertices.len()];
+        for (from, to, weight) in forest.edges.iter() {
+            // edges are stored at the smaller index
+            let (from, to) = (index[from], index[to]);
+            adj[from.min(to)].push((from.max(to), weight.clone()));
+        }
+        LabeledWeightedUGraph::from_adjacency_list(
+            forest.vertices.len(),
+            forest.edges.len(),
+            adj,
+            forest.vertices,
+        )
+    }
 }
 impl<L, W> Graph<L> for LabeledWeightedUGraph<L, W>
 where
@@ -186,7 +226,7 @@ where
         }
     }
 
-    fn weight(&mut self, from: L, to: L) -> W {
+    fn weight(&self, from: L, to: L) -> W {
         if self.index(&from) <= self.index(&to) {
             self.lwdg.weight(from, to)
         } else {
@@ -194,3 +234,243 @@ where
         }
     }
 }
+
+impl<L, W> TryGraph<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.lwdg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.lwdg.try_delete_edge(from, to)
+        } else {
+            self.lwdg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.lwdg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.lwdg.try_edge_exists(from, to)
+        } else {
+            self.lwdg.try_edge_exists(to, from)
+        }
+    }
+}
+impl<L, W> TryUndirected<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num,
+{
+    fn try_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: L) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex.clone()) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+impl<L, W> TryLabeled<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.lwdg.try_edit_label(old_label, new_label)
+    }
+}
+impl<L, W> TryWeighted<L, W> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Clone + Num,
+{
+    fn try_add_edge(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.lwdg.try_add_edge(from, to, weight)
+        } else {
+            self.lwdg.try_add_edge(to, from, weight)
+        }
+    }
+
+    fn try_edit_weight(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.lwdg.try_edit_weight(from, to, weight)
+        } else {
+            self.lwdg.try_edit_weight(to, from, weight)
+        }
+    }
+
+    fn try_weight(&self, from: L, to: L) -> Result<W, GraphError> {
+        let from_index = self.index(&from).ok_or(GraphError::VertexNotFound)?;
+        let to_index = self.index(&to).ok_or(GraphError::VertexNotFound)?;
+        if from_index <= to_index {
+            self.lwdg.try_weight(from, to)
+        } else {
+            self.lwdg.try_weight(to, from)
+        }
+    }
+}
+
+impl<L, W> GraphSearch<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<Option<ShortestPath<L, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<ShortestPaths<L, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<HashMap<L, ShortestPaths<L, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L, W> SearchView<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.lwdg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn distance(&self, from: &L, to: &L) -> W {
+        self.weight(from.clone(), to.clone())
+    }
+}
+
+impl<L, W> SpanningTree<L, W> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    fn minimum_spanning_forest(&self, mode: SpanningTreeAlgorithm) -> SpanningForest<L, W> {
+        spanning::minimum_spanning_forest(self, mode)
+    }
+}
+
+impl<L, W> Triangles<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    fn triangle_counts(&self) -> TriangleCounts<L> {
+        triangles::triangle_counts(self)
+    }
+}
+
+impl<L, W> Centrality<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L, W> Cores<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    fn core_numbers(&self) -> HashMap<L, usize> {
+        cores::core_numbers(self)
+    }
+
+    fn degeneracy_order(&self) -> Vec<L> {
+        cores::degeneracy_order(self)
+    }
+}
+
+impl<L, W> Biconnectivity<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<L> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<L, W> Neighborhood<L> for LabeledWeightedUGraph<L, W>
+where
+    L: Hash + Eq + Clone + std::fmt::Display,
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
@@ -1,6 +1,22 @@
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::search::{self, SearchView};
+use crate::algo::triangles;
 use crate::graph::directed::Digraph;
-use crate::traits::{Graph, Undirected, Unlabeled, Unweighted};
+use crate::traits::{
+    Graph, TryGraph, TryUndirected, TryUnweighted, Undirected, Unlabeled, Unweighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[cfg(test)]
 mod test;
@@ -132,3 +148,160 @@ impl Unweighted<usize> for UGraph {
         }
     }
 }
+
+impl TryGraph<usize> for UGraph {
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        self.dg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.dg.try_delete_edge(from, to)
+        } else {
+            self.dg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.dg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        if from <= to {
+            self.dg.try_edge_exists(from, to)
+        } else {
+            self.dg.try_edge_exists(to, from)
+        }
+    }
+}
+
+impl TryUndirected<usize> for UGraph {
+    fn try_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+
+impl TryUnweighted<usize> for UGraph {
+    fn try_add_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.dg.try_add_edge(from, to)
+        } else {
+            self.dg.try_add_edge(to, from)
+        }
+    }
+}
+
+impl GraphSearch<usize> for UGraph {
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<Option<ShortestPath<usize, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<ShortestPaths<usize, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl SearchView<usize> for UGraph {
+    type Distance = usize;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.dg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn distance(&self, _from: &usize, _to: &usize) -> usize {
+        1
+    }
+}
+
+impl Triangles<usize> for UGraph {
+    fn triangle_counts(&self) -> TriangleCounts<usize> {
+        triangles::triangle_counts(self)
+    }
+}
+
+impl Centrality<usize> for UGraph {
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl Cores<usize> for UGraph {
+    fn core_numbers(&self) -> HashMap<usize, usize> {
+        cores::core_numbers(self)
+    }
+
+    fn degeneracy_order(&self) -> Vec<usize> {
+        cores::degeneracy_order(self)
+    }
+}
+
+impl Biconnectivity<usize> for UGraph {
+    fn biconnected_components(&self) -> BiconnectedComponents<usize> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl Neighborhood<usize> for UGraph {
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
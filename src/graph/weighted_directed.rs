@@ -1,5 +1,20 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::flow;
+use crate::algo::neighborhood;
+use crate::algo::search::{self, SearchView};
 use crate::graph::directed::Digraph;
-use crate::traits::{Directed, Graph, Unlabeled, Unweighted, Weighted};
+use crate::traits::{
+    Directed, Graph, TryDirected, TryGraph, TryWeighted, Unlabeled, Unweighted, Weighted,
+};
+use crate::traits::{Flow, MaxFlow};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::TopologicalSort;
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::GraphError;
 use num::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -58,7 +73,7 @@ impl<W> Graph<usize> for WeightedDigraph<W> {
     }
 
     fn v_count(&self) -> usize {
-        self.dg.adj_len
+        self.dg.v_count()
     }
 
     fn delete_edge(&mut self, from: usize, to: usize) {
@@ -80,7 +95,7 @@ impl<W> Graph<usize> for WeightedDigraph<W> {
             }
         }
         self.dg.adj[from].swap_remove(i_of_w);
-        self.weights.remove(&(from, i_of_w));
+        self.weights.remove(&(from, to));
         self.dg.e_count -= 1;
     }
 
@@ -89,7 +104,6 @@ impl<W> Graph<usize> for WeightedDigraph<W> {
             self.delete_incoming_edges(vertex);
             self.delete_outgoing_edges(vertex);
             self.dg.delete_vertex(vertex);
-            self.dg.adj_len -= 1;
         } else {
             panic!("wdg delete_vertex : Vertex doesn't exist")
         }
@@ -123,6 +137,14 @@ impl<W> Directed<usize> for WeightedDigraph<W> {
             self.delete_edge(from, vertex);
         }
     }
+
+    fn out_degree(&self, vertex: usize) -> usize {
+        self.dg.out_degree(vertex)
+    }
+
+    fn in_degree(&self, vertex: usize) -> usize {
+        self.dg.in_degree(vertex)
+    }
 }
 impl<W> Unlabeled<usize> for WeightedDigraph<W> {
     fn append_vertex(&mut self) -> usize {
@@ -146,7 +168,222 @@ where
         self.weights.insert((from, to), weight);
     }
 
-    fn weight(&mut self, from: usize, to: usize) -> W {
+    fn weight(&self, from: usize, to: usize) -> W {
         self.weights.get(&(from, to)).unwrap().to_owned()
     }
 }
+
+impl<W> TryGraph<usize> for WeightedDigraph<W> {
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        Ok(self.add_vertex(vertex))
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from, to)? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        self.dg.try_edge_exists(from, to)
+    }
+}
+impl<W> TryDirected<usize> for WeightedDigraph<W> {
+    fn try_outgoing_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_outgoing_edges(vertex)
+    }
+
+    fn try_incoming_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_incoming_edges(vertex)
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+impl<W> TryWeighted<usize, W> for WeightedDigraph<W>
+where
+    W: Copy,
+{
+    fn try_add_edge(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if !(self.vertex_exists(from) && self.vertex_exists(to)) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.add_edge(from, to, weight);
+        Ok(())
+    }
+
+    fn try_edit_weight(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if !self.try_edge_exists(from, to)? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.edit_weight(from, to, weight);
+        Ok(())
+    }
+
+    fn try_weight(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        if !self.try_edge_exists(from, to)? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.weights
+            .get(&(from, to))
+            .copied()
+            .ok_or(GraphError::WeightNotFound)
+    }
+}
+
+impl<W> GraphSearch<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<Option<ShortestPath<usize, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<ShortestPaths<usize, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<W> SearchView<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.dg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.outgoing_edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.incoming_edges(*vertex)
+    }
+
+    fn distance(&self, from: &usize, to: &usize) -> W {
+        self.weight(*from, *to)
+    }
+}
+
+impl<W> TopologicalSort<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order(&self) -> Result<Vec<usize>, Vec<usize>> {
+        self.dg.topological_order()
+    }
+}
+
+impl<W> MaxFlow<usize, W> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn max_flow(&self, source: usize, sink: usize) -> Result<Flow<usize, W>, GraphError> {
+        flow::max_flow(self, source, sink)
+    }
+}
+
+impl<W> PageRank<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn pagerank(&self, options: &PageRankOptions<usize>) -> Result<HashMap<usize, f64>, GraphError> {
+        self.dg.pagerank(options)
+    }
+}
+
+impl<W> Centrality<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<W> DirectedCores<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn in_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<W> Neighborhood<usize> for WeightedDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
@@ -146,7 +146,7 @@ fn edit_weight() {
 }
 #[test]
 fn get_weight() {
-    let mut wdg = setupwdg();
+    let wdg = setupwdg();
     let weights: Vec<Vec<f64>> = vec![
         vec![0.22, 0.1111111],
         vec![],
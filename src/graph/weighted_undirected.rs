@@ -1,8 +1,26 @@
-use num::Num;
+use num::{Num, ToPrimitive};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood;
+use crate::algo::search::{self, SearchView};
+use crate::algo::spanning;
+use crate::algo::triangles;
 use crate::graph::weighted_directed::WeightedDigraph;
-use crate::traits::{Graph, Undirected, Unlabeled, Weighted};
+use crate::traits::{
+    Graph, TryGraph, TryUndirected, TryWeighted, Undirected, Unlabeled, Weighted,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{SpanningForest, SpanningTree, SpanningTreeAlgorithm};
+use crate::GraphError;
 
 #[cfg(test)]
 mod test;
@@ -27,6 +45,25 @@ where
             wdg: WeightedDigraph::from_adjacency_list(v_count, e_count, adj),
         }
     }
+
+    // builds a graph of the vertices and edges of the forest; the vertices keep their indices,
+    // indices between them which aren't vertices of the forest are deleted vertices in the new graph.
+    pub fn from_spanning_forest(forest: SpanningForest<usize, W>) -> Self {
+        let v_count = forest.vertices.iter().max().map_or(0, |max| max + 1);
+        let e_count = forest.edges.len();
+        let mut adj = vec![Vec::new(); v_count];
+        for (from, to, weight) in forest.edges {
+            // edges are stored at the smaller index
+            adj[from.min(to)].push((from.max(to), weight));
+        }
+        let mut ug = WeightedUGraph::from_adjacency_list(v_count, e_count, adj);
+        let mut exists = vec![false; v_count];
+        forest.vertices.iter().for_each(|&v| exists[v] = true);
+        for vertex in (0..v_count).filter(|&v| !exists[v]) {
+            ug.wdg.dg.delete_vertex(vertex);
+        }
+        ug
+    }
 }
 
 impl<W> Graph<usize> for WeightedUGraph<W> {
@@ -114,7 +151,7 @@ where
         }
     }
 
-    fn weight(&mut self, from: usize, to: usize) -> W {
+    fn weight(&self, from: usize, to: usize) -> W {
         if from <= to {
             return self.wdg.weight(from, to);
         } else {
@@ -122,3 +159,207 @@ where
         }
     }
 }
+
+impl<W> TryGraph<usize> for WeightedUGraph<W> {
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        self.wdg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_delete_edge(from, to)
+        } else {
+            self.wdg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.wdg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        if from <= to {
+            self.wdg.try_edge_exists(from, to)
+        } else {
+            self.wdg.try_edge_exists(to, from)
+        }
+    }
+}
+impl<W> TryUndirected<usize> for WeightedUGraph<W> {
+    fn try_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: usize) -> Result<(), GraphError> {
+        if !self.vertex_exists(vertex) {
+            return Err(GraphError::VertexNotFound);
+        }
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+impl<W> TryWeighted<usize, W> for WeightedUGraph<W>
+where
+    W: Copy,
+{
+    fn try_add_edge(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_add_edge(from, to, weight)
+        } else {
+            self.wdg.try_add_edge(to, from, weight)
+        }
+    }
+
+    fn try_edit_weight(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_edit_weight(from, to, weight)
+        } else {
+            self.wdg.try_edit_weight(to, from, weight)
+        }
+    }
+
+    fn try_weight(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        if from <= to {
+            self.wdg.try_weight(from, to)
+        } else {
+            self.wdg.try_weight(to, from)
+        }
+    }
+}
+
+impl<W> GraphSearch<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<Option<ShortestPath<usize, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<ShortestPaths<usize, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<W> SearchView<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.wdg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn distance(&self, from: &usize, to: &usize) -> W {
+        self.weight(*from, *to)
+    }
+}
+
+impl<W> SpanningTree<usize, W> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn minimum_spanning_forest(&self, mode: SpanningTreeAlgorithm) -> SpanningForest<usize, W> {
+        spanning::minimum_spanning_forest(self, mode)
+    }
+}
+
+impl<W> Triangles<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn triangle_counts(&self) -> TriangleCounts<usize> {
+        triangles::triangle_counts(self)
+    }
+}
+
+impl<W> Centrality<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<W> Cores<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn core_numbers(&self) -> HashMap<usize, usize> {
+        cores::core_numbers(self)
+    }
+
+    fn degeneracy_order(&self) -> Vec<usize> {
+        cores::degeneracy_order(self)
+    }
+}
+
+impl<W> Biconnectivity<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<usize> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<W> Neighborhood<usize> for WeightedUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, false)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::common_neighbors(self, &u, &v, true)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::jaccard(self, &u, &v)
+    }
+}
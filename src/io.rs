@@ -0,0 +1,1036 @@
+//! Reading and writing graphs as text.
+//!
+//! This module offers one reader for each of the 16 wt-/graph-types. Every reader accepts any `BufRead`
+//! (a `BufReader` around a file, a byte slice, stdin, ...) and returns a `ReadError` instead of panicking if the input is malformed.
+//! The matching writers write a graph to any `Write` in the same format, so that reading the output gives back the same graph.
+//! The writers for wt-graphs write the graph at the last commit, the `_updated` writers include the uncommitted changes.
+//!
+//! The input needs to contain the graph's data in the following format.
+//! Note that the lines are numbered for clarity; do not include the line numbers in your input.
+//!
+//! | Line   | Field 1                              | Field 2             | Field 3 (weighted graphs only) |
+//! |--------|--------------------------------------|---------------------|--------------------------------|
+//! | (1)    | <number of vertices in the graph>    |          -          |               -                |
+//! | (2)    | <number of edges in the graph>       |          -          |               -                |
+//! | (3..)  | <vertex_from>                        | <vertex_to>         | <weight_as_type_W>             |
+//!
+//! The fields are separated by any whitespace (spaces or tabs), empty lines are skipped.
+//! Indexed graphs use the indices `0..number of vertices` as vertices, labeled graphs use labels of type `L`,
+//! which are given their indices in the order in which they first appear.
+//! A line containing only a single vertex declares that vertex without adding an edge,
+//! which is how vertices without edges are added to labeled graphs.
+//! The number of edge lines has to be equal to the number of edges declared in line (2),
+//! and labeled graphs have to declare exactly as many labels as vertices declared in line (1).
+//!
+//! For undirected graphs, the order of `vertex_from` and `vertex_to` doesn't matter.
+//!
+//! The edges can be followed by a line containing only `-`, after which every line holds a single vertex
+//! which is deleted after the graph has been read. Edges of deleted vertices are deleted with them.
+//! Indexed graphs use this to keep the indices of their vertices when some of them have been deleted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::io::read_weighted_digraph;
+//! use wt_graphs::prelude::*;
+//!
+//! let input = "3\n2\n0 1 0.5\n1 2 1.5\n";
+//! let mut wdg: WeightedDigraph<f64> = read_weighted_digraph(input.as_bytes()).unwrap();
+//! assert_eq!(wdg.v_count(), 3);
+//! assert_eq!(wdg.weight(1, 2), 1.5);
+//! ```
+//!
+//! To read from a file, wrap it in a `BufReader`:
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use wt_graphs::io::read_wt_digraph;
+//!
+//! let file = File::open("graph.txt").unwrap();
+//! let wtd = read_wt_digraph(BufReader::new(file)).unwrap();
+//! ```
+//!
+//! # Writing
+//!
+//! Indexed graphs are written with all indices up to the biggest index, so that every vertex keeps its index;
+//! deleted vertices are listed after the edges, so that they are deleted again when the graph is read.
+//! Labeled graphs first declare all their labels in the order of their indices, one per line, then their edges.
+//! Labels are written with `Display` and must not be empty, `-` or contain whitespace, otherwise the writer returns an error.
+//! An edge is only written if both of its vertices exist.
+//!
+//! ```rust
+//! use wt_graphs::io::{read_labeled_ugraph, write_labeled_ugraph};
+//! use wt_graphs::prelude::*;
+//!
+//! let mut lug: LabeledUGraph<String> = LabeledUGraph::new();
+//! lug.add_vertex("a".to_string());
+//! lug.add_vertex("b".to_string());
+//! lug.add_edge("b".to_string(), "a".to_string());
+//!
+//! let mut text = Vec::new();
+//! write_labeled_ugraph(&mut text, &lug).unwrap();
+//! assert_eq!(String::from_utf8(text.clone()).unwrap(), "2\n1\na\nb\na\tb\n");
+//!
+//! let read: LabeledUGraph<String> = read_labeled_ugraph(text.as_slice()).unwrap();
+//! assert!(read.edge_exists("a".to_string(), "b".to_string()));
+//! ```
+
+use crate::graph::{
+    directed::Digraph, labeled_directed::LabeledDigraph, labeled_undirected::LabeledUGraph,
+    labeled_weighted_directed::LabeledWeightedDigraph,
+    labeled_weighted_undirected::LabeledWeightedUGraph, undirected::UGraph,
+    weighted_directed::WeightedDigraph, weighted_undirected::WeightedUGraph,
+};
+use crate::traits::{Graph, Labeled, WTLabeled, WT};
+use crate::wt::{
+    directed::WTDigraph, labeled_directed::LabeledWTDigraph, labeled_undirected::LabeledWTUGraph,
+    labeled_weighted_directed::LabeledWeightedWTDigraph,
+    labeled_weighted_undirected::LabeledWeightedWTUGraph, undirected::WTUGraph,
+    weighted_directed::WeightedWTDigraph, weighted_undirected::WeightedWTUGraph,
+};
+use num::Num;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::hash::Hash;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+#[cfg(test)]
+mod test;
+
+/// Error returned by the readers in this module. Line numbers start at 1.
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the input failed.
+    Io(io::Error),
+    /// The input ended before both header lines were read.
+    MissingHeader,
+    /// A header line isn't a single non-negative integer.
+    InvalidHeader { line: usize },
+    /// An edge line has the wrong number of fields, or a field couldn't be parsed.
+    InvalidLine { line: usize },
+    /// A vertex index isn't smaller than the number of vertices,
+    /// or a labeled graph uses more labels than the number of vertices.
+    VertexOutOfRange { line: usize },
+    /// A labeled graph uses fewer labels than the number of vertices.
+    VertexCountMismatch { expected: usize, found: usize },
+    /// The number of edge lines isn't the number of edges declared in the header.
+    EdgeCountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "failed to read input: {}", e),
+            ReadError::MissingHeader => write!(f, "input ended before the header was complete"),
+            ReadError::InvalidHeader { line } => write!(f, "line {}: invalid header", line),
+            ReadError::InvalidLine { line } => write!(f, "line {}: invalid edge", line),
+            ReadError::VertexOutOfRange { line } => {
+                write!(f, "line {}: vertex exceeds the number of vertices", line)
+            }
+            ReadError::VertexCountMismatch { expected, found } => write!(
+                f,
+                "header declares {} vertices, but {} were found",
+                expected, found
+            ),
+            ReadError::EdgeCountMismatch { expected, found } => write!(
+                f,
+                "header declares {} edges, but {} were found",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+/// Reads a `Digraph`.
+pub fn read_digraph<R: BufRead>(reader: R) -> Result<Digraph, ReadError> {
+    let (v_count, e_count, adj, deleted) = read_indexed(reader, false)?;
+    let mut dg = Digraph::from_adjacency_list(v_count, e_count, adj);
+    delete(&mut dg, deleted);
+    Ok(dg)
+}
+
+/// Reads a `UGraph`.
+pub fn read_ugraph<R: BufRead>(reader: R) -> Result<UGraph, ReadError> {
+    let (v_count, e_count, adj, deleted) = read_indexed(reader, true)?;
+    let mut ug = UGraph::from_adjacency_list(v_count, e_count, adj);
+    delete(&mut ug, deleted);
+    Ok(ug)
+}
+
+/// Reads a `WeightedDigraph`.
+pub fn read_weighted_digraph<R, W>(reader: R) -> Result<WeightedDigraph<W>, ReadError>
+where
+    R: BufRead,
+    W: FromStr + Clone + Num,
+{
+    let (v_count, e_count, adj, deleted) = read_indexed_weighted(reader, false)?;
+    let mut wdg = WeightedDigraph::from_adjacency_list(v_count, e_count, adj);
+    delete(&mut wdg, deleted);
+    Ok(wdg)
+}
+
+/// Reads a `WeightedUGraph`.
+pub fn read_weighted_ugraph<R, W>(reader: R) -> Result<WeightedUGraph<W>, ReadError>
+where
+    R: BufRead,
+    W: FromStr + Clone + Num,
+{
+    let (v_count, e_count, adj, deleted) = read_indexed_weighted(reader, true)?;
+    let mut wug = WeightedUGraph::from_adjacency_list(v_count, e_count, adj);
+    delete(&mut wug, deleted);
+    Ok(wug)
+}
+
+/// Reads a `LabeledDigraph`.
+pub fn read_labeled_digraph<R, L>(reader: R) -> Result<LabeledDigraph<L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+{
+    let (v_count, e_count, adj, labels, deleted) = read_labeled(reader, false)?;
+    let mut ldg = LabeledDigraph::from_adjacency_list(v_count, e_count, adj, labels);
+    delete(&mut ldg, deleted);
+    Ok(ldg)
+}
+
+/// Reads a `LabeledUGraph`.
+pub fn read_labeled_ugraph<R, L>(reader: R) -> Result<LabeledUGraph<L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+{
+    let (v_count, e_count, adj, labels, deleted) = read_labeled(reader, true)?;
+    let mut lug = LabeledUGraph::from_adjacency_list(v_count, e_count, adj, labels);
+    delete(&mut lug, deleted);
+    Ok(lug)
+}
+
+/// Reads a `LabeledWeightedDigraph`.
+pub fn read_labeled_weighted_digraph<R, L, W>(
+    reader: R,
+) -> Result<LabeledWeightedDigraph<L, W>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+    W: FromStr + Clone + Num,
+{
+    let (v_count, e_count, adj, labels, deleted) = read_labeled_weighted(reader, false)?;
+    let mut lwdg = LabeledWeightedDigraph::from_adjacency_list(v_count, e_count, adj, labels);
+    delete(&mut lwdg, deleted);
+    Ok(lwdg)
+}
+
+/// Reads a `LabeledWeightedUGraph`.
+pub fn read_labeled_weighted_ugraph<R, L, W>(
+    reader: R,
+) -> Result<LabeledWeightedUGraph<L, W>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+    W: FromStr + Clone + Num,
+{
+    let (v_count, e_count, adj, labels, deleted) = read_labeled_weighted(reader, true)?;
+    let mut lwug = LabeledWeightedUGraph::from_adjacency_list(v_count, e_count, adj, labels);
+    delete(&mut lwug.lwdg, deleted); // the same, without requiring L: Display like Graph for LabeledWeightedUGraph
+    Ok(lwug)
+}
+
+/// Reads a `WTDigraph`.
+pub fn read_wt_digraph<R: BufRead>(reader: R) -> Result<WTDigraph, ReadError> {
+    Ok(WTDigraph::from_digraph(read_digraph(reader)?))
+}
+
+/// Reads a `WTUGraph`.
+pub fn read_wt_ugraph<R: BufRead>(reader: R) -> Result<WTUGraph, ReadError> {
+    Ok(WTUGraph::from_ugraph(read_ugraph(reader)?))
+}
+
+/// Reads a `WeightedWTDigraph`.
+pub fn read_weighted_wt_digraph<R, W>(reader: R) -> Result<WeightedWTDigraph<W>, ReadError>
+where
+    R: BufRead,
+    W: FromStr + Clone + Num,
+{
+    Ok(WeightedWTDigraph::from_weighted_digraph(
+        read_weighted_digraph(reader)?,
+    ))
+}
+
+/// Reads a `WeightedWTUGraph`.
+pub fn read_weighted_wt_ugraph<R, W>(reader: R) -> Result<WeightedWTUGraph<W>, ReadError>
+where
+    R: BufRead,
+    W: FromStr + Clone + Num,
+{
+    Ok(WeightedWTUGraph::from_weighted_ugraph(
+        read_weighted_ugraph(reader)?,
+    ))
+}
+
+/// Reads a `LabeledWTDigraph`.
+pub fn read_labeled_wt_digraph<R, L>(reader: R) -> Result<LabeledWTDigraph<L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+{
+    Ok(LabeledWTDigraph::from_labeled_digraph(
+        read_labeled_digraph(reader)?,
+    ))
+}
+
+/// Reads a `LabeledWTUGraph`.
+pub fn read_labeled_wt_ugraph<R, L>(reader: R) -> Result<LabeledWTUGraph<L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+{
+    Ok(LabeledWTUGraph::from_labeled_ugraph(read_labeled_ugraph(
+        reader,
+    )?))
+}
+
+/// Reads a `LabeledWeightedWTDigraph`.
+pub fn read_labeled_weighted_wt_digraph<R, L, W>(
+    reader: R,
+) -> Result<LabeledWeightedWTDigraph<L, W>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+    W: FromStr + Clone + Num,
+{
+    Ok(LabeledWeightedWTDigraph::from_labeled_weighted_digraph(
+        read_labeled_weighted_digraph(reader)?,
+    ))
+}
+
+/// Reads a `LabeledWeightedWTUGraph`.
+pub fn read_labeled_weighted_wt_ugraph<R, L, W>(
+    reader: R,
+) -> Result<LabeledWeightedWTUGraph<L, W>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+    W: FromStr + Clone + Num,
+{
+    Ok(LabeledWeightedWTUGraph::from_labeled_weighted_ugraph(
+        read_labeled_weighted_ugraph(reader)?,
+    ))
+}
+
+/// Writes a `Digraph`.
+pub fn write_digraph<Wr: Write>(writer: Wr, graph: &Digraph) -> io::Result<()> {
+    write_text(
+        writer,
+        graph.adj.len(),
+        false,
+        |v| graph.vertex_exists(v),
+        |v| graph.adj[v].iter().copied(),
+        indexed,
+        unweighted,
+    )
+}
+
+/// Writes a `UGraph`.
+pub fn write_ugraph<Wr: Write>(writer: Wr, graph: &UGraph) -> io::Result<()> {
+    write_digraph(writer, &graph.dg)
+}
+
+/// Writes a `WeightedDigraph`.
+pub fn write_weighted_digraph<Wr, W>(writer: Wr, graph: &WeightedDigraph<W>) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_text(
+        writer,
+        graph.dg.adj.len(),
+        false,
+        |v| graph.dg.vertex_exists(v),
+        |v| graph.dg.adj[v].iter().copied(),
+        indexed,
+        |from, to| weight(graph.weights.get(&(from, to))),
+    )
+}
+
+/// Writes a `WeightedUGraph`.
+pub fn write_weighted_ugraph<Wr, W>(writer: Wr, graph: &WeightedUGraph<W>) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_weighted_digraph(writer, &graph.wdg)
+}
+
+/// Writes a `LabeledDigraph`.
+pub fn write_labeled_digraph<Wr, L>(writer: Wr, graph: &LabeledDigraph<L>) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_text(
+        writer,
+        graph.dg.adj.len(),
+        true,
+        |v| graph.dg.vertex_exists(v),
+        |v| graph.dg.adj[v].iter().copied(),
+        |v| label(graph.label(v)),
+        unweighted,
+    )
+}
+
+/// Writes a `LabeledUGraph`.
+pub fn write_labeled_ugraph<Wr, L>(writer: Wr, graph: &LabeledUGraph<L>) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_labeled_digraph(writer, &graph.ldg)
+}
+
+/// Writes a `LabeledWeightedDigraph`.
+pub fn write_labeled_weighted_digraph<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedDigraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    let ldg = &graph.ldg;
+    write_text(
+        writer,
+        ldg.dg.adj.len(),
+        true,
+        |v| ldg.dg.vertex_exists(v),
+        |v| ldg.dg.adj[v].iter().copied(),
+        |v| label(ldg.label(v)),
+        |from, to| weight(graph.weights.get(&(from, to))),
+    )
+}
+
+/// Writes a `LabeledWeightedUGraph`.
+pub fn write_labeled_weighted_ugraph<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedUGraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    write_labeled_weighted_digraph(writer, &graph.lwdg)
+}
+
+/// Writes a `WTDigraph` at the last commit.
+pub fn write_wt_digraph<Wr: Write>(writer: Wr, graph: &WTDigraph) -> io::Result<()> {
+    write_text(
+        writer,
+        graph.wt_adj_len,
+        false,
+        |v| graph.vertex_exists(v),
+        |v| graph.outgoing_iter(v),
+        indexed,
+        unweighted,
+    )
+}
+
+/// Writes a `WTDigraph` including the uncommitted changes.
+pub fn write_wt_digraph_updated<Wr: Write>(writer: Wr, graph: &WTDigraph) -> io::Result<()> {
+    write_text(
+        writer,
+        graph.wt_adj_len_updated,
+        false,
+        |v| graph.vertex_exists_updated(v),
+        |v| graph.outgoing_iter_updated(v),
+        indexed,
+        unweighted,
+    )
+}
+
+/// Writes a `WTUGraph` at the last commit.
+pub fn write_wt_ugraph<Wr: Write>(writer: Wr, graph: &WTUGraph) -> io::Result<()> {
+    write_wt_digraph(writer, &graph.wtd)
+}
+
+/// Writes a `WTUGraph` including the uncommitted changes.
+pub fn write_wt_ugraph_updated<Wr: Write>(writer: Wr, graph: &WTUGraph) -> io::Result<()> {
+    write_wt_digraph_updated(writer, &graph.wtd)
+}
+
+/// Writes a `WeightedWTDigraph` at the last commit.
+pub fn write_weighted_wt_digraph<Wr, W>(writer: Wr, graph: &WeightedWTDigraph<W>) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_text(
+        writer,
+        graph.dg.wt_adj_len,
+        false,
+        |v| graph.dg.vertex_exists(v),
+        |v| graph.dg.outgoing_iter(v),
+        indexed,
+        |from, to| weight(graph.committed_weight(from, to)),
+    )
+}
+
+/// Writes a `WeightedWTDigraph` including the uncommitted changes.
+pub fn write_weighted_wt_digraph_updated<Wr, W>(
+    writer: Wr,
+    graph: &WeightedWTDigraph<W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_text(
+        writer,
+        graph.dg.wt_adj_len_updated,
+        false,
+        |v| graph.dg.vertex_exists_updated(v),
+        |v| graph.dg.outgoing_iter_updated(v),
+        indexed,
+        |from, to| weight(graph.updated_weight(from, to)),
+    )
+}
+
+/// Writes a `WeightedWTUGraph` at the last commit.
+pub fn write_weighted_wt_ugraph<Wr, W>(writer: Wr, graph: &WeightedWTUGraph<W>) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_weighted_wt_digraph(writer, &graph.wdg)
+}
+
+/// Writes a `WeightedWTUGraph` including the uncommitted changes.
+pub fn write_weighted_wt_ugraph_updated<Wr, W>(
+    writer: Wr,
+    graph: &WeightedWTUGraph<W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    W: Display,
+{
+    write_weighted_wt_digraph_updated(writer, &graph.wdg)
+}
+
+/// Writes a `LabeledWTDigraph` at the last commit.
+pub fn write_labeled_wt_digraph<Wr, L>(writer: Wr, graph: &LabeledWTDigraph<L>) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_text(
+        writer,
+        graph.dg.wt_adj_len,
+        true,
+        |v| graph.dg.vertex_exists(v),
+        |v| graph.dg.outgoing_iter(v),
+        |v| label(graph.label(v)),
+        unweighted,
+    )
+}
+
+/// Writes a `LabeledWTDigraph` including the uncommitted changes.
+pub fn write_labeled_wt_digraph_updated<Wr, L>(
+    writer: Wr,
+    graph: &LabeledWTDigraph<L>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_text(
+        writer,
+        graph.dg.wt_adj_len_updated,
+        true,
+        |v| graph.dg.vertex_exists_updated(v),
+        |v| graph.dg.outgoing_iter_updated(v),
+        |v| label(graph.label_updated(v)),
+        unweighted,
+    )
+}
+
+/// Writes a `LabeledWTUGraph` at the last commit.
+pub fn write_labeled_wt_ugraph<Wr, L>(writer: Wr, graph: &LabeledWTUGraph<L>) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_labeled_wt_digraph(writer, &graph.ldg)
+}
+
+/// Writes a `LabeledWTUGraph` including the uncommitted changes.
+pub fn write_labeled_wt_ugraph_updated<Wr, L>(
+    writer: Wr,
+    graph: &LabeledWTUGraph<L>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+{
+    write_labeled_wt_digraph_updated(writer, &graph.ldg)
+}
+
+/// Writes a `LabeledWeightedWTDigraph` at the last commit.
+pub fn write_labeled_weighted_wt_digraph<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedWTDigraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    let ldg = &graph.ldg;
+    write_text(
+        writer,
+        ldg.dg.wt_adj_len,
+        true,
+        |v| ldg.dg.vertex_exists(v),
+        |v| ldg.dg.outgoing_iter(v),
+        |v| label(ldg.label(v)),
+        |from, to| weight(graph.committed_weight(from, to)),
+    )
+}
+
+/// Writes a `LabeledWeightedWTDigraph` including the uncommitted changes.
+pub fn write_labeled_weighted_wt_digraph_updated<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedWTDigraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    let ldg = &graph.ldg;
+    write_text(
+        writer,
+        ldg.dg.wt_adj_len_updated,
+        true,
+        |v| ldg.dg.vertex_exists_updated(v),
+        |v| ldg.dg.outgoing_iter_updated(v),
+        |v| label(ldg.label_updated(v)),
+        |from, to| weight(graph.updated_weight(from, to)),
+    )
+}
+
+/// Writes a `LabeledWeightedWTUGraph` at the last commit.
+pub fn write_labeled_weighted_wt_ugraph<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedWTUGraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    write_labeled_weighted_wt_digraph(writer, &graph.lwdg)
+}
+
+/// Writes a `LabeledWeightedWTUGraph` including the uncommitted changes.
+pub fn write_labeled_weighted_wt_ugraph_updated<Wr, L, W>(
+    writer: Wr,
+    graph: &LabeledWeightedWTUGraph<L, W>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    L: Hash + Eq + Clone + Display,
+    W: Num + Display,
+{
+    write_labeled_weighted_wt_digraph_updated(writer, &graph.lwdg)
+}
+
+// v_count, e_count, the adjacency list and the indices of the deleted vertices as read from the input
+type AdjacencyList<T> = (usize, usize, Vec<Vec<T>>, Vec<usize>);
+// v_count, e_count, the adjacency list, the labels ordered by their index and the labels of the deleted vertices
+type LabeledAdjacencyList<T, L> = (usize, usize, Vec<Vec<T>>, Vec<L>, Vec<L>);
+
+// the adjacency lists of the indexed graphs, with v_count and e_count from the header.
+// undirected edges are stored from the smaller to the bigger index, like the undirected graphs do themselves.
+fn read_indexed<R: BufRead>(
+    reader: R,
+    undirected: bool,
+) -> Result<AdjacencyList<usize>, ReadError> {
+    let mut input = Input::new(reader)?;
+    let mut adj: Vec<Vec<usize>> = vec![vec![]; input.v_count];
+    while let Some((line, content)) = input.next_edge()? {
+        let fields = split(line, &content, false)?;
+        let from = parse_index(line, fields[0], input.v_count)?;
+        if let Some(to) = fields.get(1) {
+            let to = parse_index(line, to, input.v_count)?;
+            let (from, to) = ordered(from, to, undirected);
+            adj[from].push(to);
+            input.edges += 1;
+        }
+    }
+    input.finish()?;
+    let v_count = input.v_count;
+    let deleted = input.deleted(|line, field| parse_index(line, field, v_count))?;
+    Ok((input.v_count, input.e_count, adj, deleted))
+}
+
+fn read_indexed_weighted<R, W>(
+    reader: R,
+    undirected: bool,
+) -> Result<AdjacencyList<(usize, W)>, ReadError>
+where
+    R: BufRead,
+    W: FromStr,
+{
+    let mut input = Input::new(reader)?;
+    let mut adj: Vec<Vec<(usize, W)>> = (0..input.v_count).map(|_| Vec::new()).collect();
+    while let Some((line, content)) = input.next_edge()? {
+        let fields = split(line, &content, true)?;
+        let from = parse_index(line, fields[0], input.v_count)?;
+        if fields.len() == 3 {
+            let to = parse_index(line, fields[1], input.v_count)?;
+            let weight = parse_weight(line, fields[2])?;
+            let (from, to) = ordered(from, to, undirected);
+            adj[from].push((to, weight));
+            input.edges += 1;
+        }
+    }
+    input.finish()?;
+    let v_count = input.v_count;
+    let deleted = input.deleted(|line, field| parse_index(line, field, v_count))?;
+    Ok((input.v_count, input.e_count, adj, deleted))
+}
+
+fn read_labeled<R, L>(
+    reader: R,
+    undirected: bool,
+) -> Result<LabeledAdjacencyList<usize, L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+{
+    let mut input = Input::new(reader)?;
+    let mut labels: Labels<L> = Labels::new(input.v_count);
+    let mut adj: Vec<Vec<usize>> = vec![vec![]; input.v_count];
+    while let Some((line, content)) = input.next_edge()? {
+        let fields = split(line, &content, false)?;
+        let from = labels.index(line, fields[0])?;
+        if let Some(to) = fields.get(1) {
+            let to = labels.index(line, to)?;
+            let (from, to) = ordered(from, to, undirected);
+            adj[from].push(to);
+            input.edges += 1;
+        }
+    }
+    input.finish()?;
+    let deleted = input.deleted(|line, field| labels.index(line, field))?;
+    let labels = labels.finish()?;
+    let deleted = deleted.iter().map(|v| labels[*v].clone()).collect();
+    Ok((input.v_count, input.e_count, adj, labels, deleted))
+}
+
+fn read_labeled_weighted<R, L, W>(
+    reader: R,
+    undirected: bool,
+) -> Result<LabeledAdjacencyList<(usize, W), L>, ReadError>
+where
+    R: BufRead,
+    L: FromStr + Hash + Eq + Clone,
+    W: FromStr,
+{
+    let mut input = Input::new(reader)?;
+    let mut labels: Labels<L> = Labels::new(input.v_count);
+    let mut adj: Vec<Vec<(usize, W)>> = (0..input.v_count).map(|_| Vec::new()).collect();
+    while let Some((line, content)) = input.next_edge()? {
+        let fields = split(line, &content, true)?;
+        let from = labels.index(line, fields[0])?;
+        if fields.len() == 3 {
+            let to = labels.index(line, fields[1])?;
+            let weight = parse_weight(line, fields[2])?;
+            let (from, to) = ordered(from, to, undirected);
+            adj[from].push((to, weight));
+            input.edges += 1;
+        }
+    }
+    input.finish()?;
+    let deleted = input.deleted(|line, field| labels.index(line, field))?;
+    let labels = labels.finish()?;
+    let deleted = deleted.iter().map(|v| labels[*v].clone()).collect();
+    Ok((input.v_count, input.e_count, adj, labels, deleted))
+}
+
+// the line which separates the edges from the deleted vertices
+const DELETED: &str = "-";
+
+// the lines of the input after the header, numbered from 1 and without empty lines
+struct Input<R> {
+    lines: io::Lines<R>,
+    line: usize,    // number of the last line read
+    v_count: usize, // from the header
+    e_count: usize, // from the header
+    edges: usize,   // number of edge lines read so far
+}
+
+impl<R: BufRead> Input<R> {
+    // reads the two header lines
+    fn new(reader: R) -> Result<Self, ReadError> {
+        let mut input = Input {
+            lines: reader.lines(),
+            line: 0,
+            v_count: 0,
+            e_count: 0,
+            edges: 0,
+        };
+        input.v_count = input.header()?;
+        input.e_count = input.header()?;
+        Ok(input)
+    }
+
+    fn header(&mut self) -> Result<usize, ReadError> {
+        let (line, content) = self.next_line()?.ok_or(ReadError::MissingHeader)?;
+        content
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| ReadError::InvalidHeader { line })
+    }
+
+    fn next_line(&mut self) -> Result<Option<(usize, String)>, ReadError> {
+        for content in self.lines.by_ref() {
+            let content = content?;
+            self.line += 1;
+            if !content.trim().is_empty() {
+                return Ok(Some((self.line, content)));
+            }
+        }
+        Ok(None)
+    }
+
+    // the next line with an edge or a single vertex, None once the edges are followed by the deleted vertices
+    fn next_edge(&mut self) -> Result<Option<(usize, String)>, ReadError> {
+        match self.next_line()? {
+            Some((_, content)) if content.trim() == DELETED => Ok(None),
+            next => Ok(next),
+        }
+    }
+
+    // the deleted vertices after the edges, one per line
+    fn deleted(
+        &mut self,
+        mut vertex: impl FnMut(usize, &str) -> Result<usize, ReadError>,
+    ) -> Result<Vec<usize>, ReadError> {
+        let mut deleted = Vec::new();
+        while let Some((line, content)) = self.next_line()? {
+            match content.split_whitespace().collect::<Vec<&str>>()[..] {
+                [field] => deleted.push(vertex(line, field)?),
+                _ => return Err(ReadError::InvalidLine { line }),
+            }
+        }
+        Ok(deleted)
+    }
+
+    // checks that the number of edge lines matches the header
+    fn finish(&self) -> Result<(), ReadError> {
+        if self.edges != self.e_count {
+            return Err(ReadError::EdgeCountMismatch {
+                expected: self.e_count,
+                found: self.edges,
+            });
+        }
+        Ok(())
+    }
+}
+
+// the labels of a labeled graph in the order in which they first appear
+struct Labels<L> {
+    v_count: usize,
+    index_label: Vec<L>,
+    label_index: HashMap<L, usize>,
+}
+
+impl<L> Labels<L>
+where
+    L: FromStr + Hash + Eq + Clone,
+{
+    fn new(v_count: usize) -> Self {
+        Labels {
+            v_count,
+            index_label: Vec::with_capacity(v_count),
+            label_index: HashMap::with_capacity(v_count),
+        }
+    }
+
+    // returns the index of the label, giving it the next free index if it's new
+    fn index(&mut self, line: usize, field: &str) -> Result<usize, ReadError> {
+        let label = field
+            .parse::<L>()
+            .map_err(|_| ReadError::InvalidLine { line })?;
+        if let Some(index) = self.label_index.get(&label) {
+            return Ok(*index);
+        }
+        let index = self.index_label.len();
+        if index == self.v_count {
+            return Err(ReadError::VertexOutOfRange { line });
+        }
+        self.index_label.push(label.clone());
+        self.label_index.insert(label, index);
+        Ok(index)
+    }
+
+    // checks that every vertex got a label
+    fn finish(self) -> Result<Vec<L>, ReadError> {
+        if self.index_label.len() != self.v_count {
+            return Err(ReadError::VertexCountMismatch {
+                expected: self.v_count,
+                found: self.index_label.len(),
+            });
+        }
+        Ok(self.index_label)
+    }
+}
+
+// splits an edge line into one field (a single vertex) or two fields (an edge), or three if the graph is weighted
+fn split(line: usize, content: &str, weighted: bool) -> Result<Vec<&str>, ReadError> {
+    let fields: Vec<&str> = content.split_whitespace().collect();
+    let edge_fields = if weighted { 3 } else { 2 };
+    if fields.len() == 1 || fields.len() == edge_fields {
+        Ok(fields)
+    } else {
+        Err(ReadError::InvalidLine { line })
+    }
+}
+
+fn parse_index(line: usize, field: &str, v_count: usize) -> Result<usize, ReadError> {
+    let index = field
+        .parse::<usize>()
+        .map_err(|_| ReadError::InvalidLine { line })?;
+    if index >= v_count {
+        return Err(ReadError::VertexOutOfRange { line });
+    }
+    Ok(index)
+}
+
+fn parse_weight<W: FromStr>(line: usize, field: &str) -> Result<W, ReadError> {
+    field
+        .parse::<W>()
+        .map_err(|_| ReadError::InvalidLine { line })
+}
+
+fn ordered(from: usize, to: usize, undirected: bool) -> (usize, usize) {
+    if undirected && from > to {
+        (to, from)
+    } else {
+        (from, to)
+    }
+}
+
+// deletes the vertices listed after the edges, once each
+fn delete<T: PartialEq + Clone, G: Graph<T>>(graph: &mut G, deleted: Vec<T>) {
+    for (i, vertex) in deleted.iter().enumerate() {
+        if !deleted[..i].contains(vertex) {
+            graph.delete_vertex(vertex.clone());
+        }
+    }
+}
+
+// writes a graph in the text format.
+// the indices `0..index_space` for which `exists` is true are the vertices of the graph, `edges` returns the vertices an index has edges to.
+// indexed graphs write every index so that the indices don't change and list the other indices as deleted vertices,
+// labeled graphs declare their existing vertices in index order.
+// `vertex` returns how a vertex is written and `weight` the weight of an edge, which is None for unweighted graphs
+fn write_text<Wr, E, V, Wt>(
+    mut writer: Wr,
+    index_space: usize,
+    labeled: bool,
+    exists: impl Fn(usize) -> bool,
+    edges: impl Fn(usize) -> E,
+    vertex: impl Fn(usize) -> io::Result<V>,
+    weight: impl Fn(usize, usize) -> io::Result<Option<Wt>>,
+) -> io::Result<()>
+where
+    Wr: Write,
+    E: Iterator<Item = usize>,
+    V: Display,
+    Wt: Display,
+{
+    let vertices = (0..index_space).filter(|v| exists(*v));
+    let e_count: usize = vertices
+        .clone()
+        .map(|from| edges(from).filter(|to| exists(*to)).count())
+        .sum();
+    let v_count = if labeled {
+        vertices.clone().count()
+    } else {
+        index_space
+    };
+    writeln!(writer, "{}", v_count)?;
+    writeln!(writer, "{}", e_count)?;
+    if labeled {
+        for v in vertices.clone() {
+            let label = vertex(v)?.to_string();
+            if label.is_empty() || label == DELETED || label.contains(char::is_whitespace) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "label {:?} is empty, {:?} or contains whitespace",
+                        label, DELETED
+                    ),
+                ));
+            }
+            writeln!(writer, "{}", label)?;
+        }
+    }
+    for from in vertices {
+        for to in edges(from).filter(|to| exists(*to)) {
+            match weight(from, to)? {
+                Some(weight) => writeln!(writer, "{}\t{}\t{}", vertex(from)?, vertex(to)?, weight)?,
+                None => writeln!(writer, "{}\t{}", vertex(from)?, vertex(to)?)?,
+            }
+        }
+    }
+    let mut deleted = (0..index_space).filter(|v| !exists(*v)).peekable();
+    if !labeled && deleted.peek().is_some() {
+        writeln!(writer, "{}", DELETED)?;
+        for v in deleted {
+            writeln!(writer, "{}", v)?;
+        }
+    }
+    writer.flush()
+}
+
+fn indexed(vertex: usize) -> io::Result<usize> {
+    Ok(vertex)
+}
+
+fn unweighted(_from: usize, _to: usize) -> io::Result<Option<u8>> {
+    Ok(None)
+}
+
+fn label<L>(label: Option<&L>) -> io::Result<&L> {
+    label.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "vertex has no label"))
+}
+
+fn weight<W>(weight: Option<&W>) -> io::Result<Option<&W>> {
+    match weight {
+        Some(weight) => Ok(Some(weight)),
+        None => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "weight of edge is missing",
+        )),
+    }
+}
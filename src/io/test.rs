@@ -0,0 +1,467 @@
+use crate::io::*;
+use crate::traits::{
+    Directed, Graph, Undirected, Unlabeled, Unweighted, WTDirected, WTUndirected, WTWeighted,
+    Weighted, WT,
+};
+use std::io;
+
+#[test]
+fn read_indexed_graphs() {
+    let input = "4\n3\n0 1\n2\t\t1\n\n3 3\n";
+    let dg = read_digraph(input.as_bytes()).unwrap();
+    assert_eq!(dg.v_count(), 4);
+    assert_eq!(dg.e_count(), 3);
+    assert_eq!(dg.outgoing_edges(2), vec![1]);
+    assert_eq!(dg.incoming_edges(1), vec![0, 2]);
+    assert!(dg.edge_exists(3, 3));
+
+    // undirected edges are stored from the smaller to the bigger index
+    let ug = read_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(ug.e_count(), 3);
+    assert_eq!(ug.dg.adj[1], vec![2]);
+    assert!(ug.edge_exists(1, 2));
+
+    let wtd = read_wt_digraph(input.as_bytes()).unwrap();
+    assert_eq!(wtd.e_count(), 3);
+    assert_eq!(wtd.incoming_edges(1), vec![0, 2]);
+    assert_eq!(wtd.out_degree_updated(0), 1);
+
+    let wtu = read_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(wtu.edges(1), vec![0, 2]);
+    assert_eq!(wtu.degree_updated(3), 1); // a loop counts once
+}
+
+#[test]
+fn read_weighted_graphs() {
+    let input = "3\n2\n0 1 0.5\n2 1 1.5\n";
+    let wdg: WeightedDigraph<f64> = read_weighted_digraph(input.as_bytes()).unwrap();
+    assert_eq!(wdg.e_count(), 2);
+    assert_eq!(wdg.weight(2, 1), 1.5);
+
+    let wug: WeightedUGraph<f64> = read_weighted_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(wug.wdg.weights.get(&(1, 2)), Some(&1.5));
+
+    let wtwd: WeightedWTDigraph<f64> = read_weighted_wt_digraph(input.as_bytes()).unwrap();
+    assert_eq!(wtwd.weight(0, 1), 0.5);
+
+    let wtwu: WeightedWTUGraph<f64> = read_weighted_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(wtwu.weight(2, 1), 1.5);
+
+    // integer weights
+    let wdg: WeightedDigraph<i32> = read_weighted_digraph("2\n1\n1 0 -3\n".as_bytes()).unwrap();
+    assert_eq!(wdg.weights.get(&(1, 0)), Some(&-3));
+}
+
+#[test]
+fn read_labeled_graphs() {
+    // "d" has no edges and is declared on a line of its own
+    let input = "4\n3\nb a\nc a\na a\nd\n";
+    let ldg: LabeledDigraph<String> = read_labeled_digraph(input.as_bytes()).unwrap();
+    assert_eq!(ldg.v_count(), 4);
+    assert_eq!(ldg.e_count(), 3);
+    // labels get their indices in the order in which they first appear
+    assert_eq!(ldg.index_label, vec!["b", "a", "c", "d"]);
+    assert_eq!(ldg.incoming_edges("a".to_string()).len(), 3);
+
+    let lug: LabeledUGraph<String> = read_labeled_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(lug.ldg.dg.adj[0], vec![1]); // b - a is stored from b (0) to a (1)
+    assert_eq!(lug.ldg.dg.adj[1], vec![2, 1]);
+
+    let lwtd: LabeledWTDigraph<String> = read_labeled_wt_digraph(input.as_bytes()).unwrap();
+    assert!(lwtd.edge_exists("c".to_string(), "a".to_string()));
+    assert!(lwtd.vertex_exists("d".to_string()));
+
+    let lwtu: LabeledWTUGraph<String> = read_labeled_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(lwtu.v_count(), 4);
+    assert_eq!(lwtu.e_count(), 3);
+
+    let input = "3\n2\nx y 2\nz y 3\n";
+    let lwdg: LabeledWeightedDigraph<String, u32> =
+        read_labeled_weighted_digraph(input.as_bytes()).unwrap();
+    assert_eq!(lwdg.weight("z".to_string(), "y".to_string()), 3);
+
+    let lwug: LabeledWeightedUGraph<String, u32> =
+        read_labeled_weighted_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(lwug.lwdg.weights.get(&(1, 2)), Some(&3));
+
+    let lwwtd: LabeledWeightedWTDigraph<String, u32> =
+        read_labeled_weighted_wt_digraph(input.as_bytes()).unwrap();
+    assert_eq!(lwwtd.weight("x".to_string(), "y".to_string()), 2);
+
+    let lwwtu: LabeledWeightedWTUGraph<String, u32> =
+        read_labeled_weighted_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(lwwtu.e_count(), 2);
+}
+
+#[test]
+fn read_errors() {
+    assert!(matches!(
+        read_digraph("3\n".as_bytes()),
+        Err(ReadError::MissingHeader)
+    ));
+    assert!(matches!(
+        read_digraph("3\nthree\n".as_bytes()),
+        Err(ReadError::InvalidHeader { line: 2 })
+    ));
+    assert!(matches!(
+        read_digraph("3\n1\n0 x\n".as_bytes()),
+        Err(ReadError::InvalidLine { line: 3 })
+    ));
+    assert!(matches!(
+        read_digraph("3\n1\n0 1 2\n".as_bytes()),
+        Err(ReadError::InvalidLine { line: 3 })
+    ));
+    assert!(matches!(
+        read_digraph("3\n2\n0 1\n\n1 3\n".as_bytes()),
+        Err(ReadError::VertexOutOfRange { line: 5 })
+    ));
+    assert!(matches!(
+        read_digraph("3\n2\n0 1\n".as_bytes()),
+        Err(ReadError::EdgeCountMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+    assert!(matches!(
+        read_weighted_digraph::<_, f32>("3\n1\n0 1\n".as_bytes()),
+        Err(ReadError::InvalidLine { line: 3 })
+    ));
+    assert!(matches!(
+        read_weighted_digraph::<_, u8>("3\n1\n0 1 -1\n".as_bytes()),
+        Err(ReadError::InvalidLine { line: 3 })
+    ));
+    assert!(matches!(
+        read_labeled_digraph::<_, String>("2\n2\na b\nb c\n".as_bytes()),
+        Err(ReadError::VertexOutOfRange { line: 4 })
+    ));
+    assert!(matches!(
+        read_labeled_digraph::<_, String>("3\n1\na b\n".as_bytes()),
+        Err(ReadError::VertexCountMismatch {
+            expected: 3,
+            found: 2
+        })
+    ));
+    assert_eq!(
+        ReadError::EdgeCountMismatch {
+            expected: 2,
+            found: 1
+        }
+        .to_string(),
+        "header declares 2 edges, but 1 were found"
+    );
+}
+
+// returns what `write` writes as a string
+fn text(write: impl FnOnce(&mut Vec<u8>) -> io::Result<()>) -> String {
+    let mut buffer = Vec::new();
+    write(&mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn write_indexed_graphs() {
+    let input = "4\n3\n0\t1\n2\t1\n3\t3\n";
+    let dg = read_digraph(input.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_digraph(w, &dg)), input);
+
+    // undirected edges are written from the smaller to the bigger index
+    let ug = read_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_ugraph(w, &ug)), "4\n3\n0\t1\n1\t2\n3\t3\n");
+
+    let wtd = read_wt_digraph(input.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_wt_digraph(w, &wtd)), input);
+    assert_eq!(text(|w| write_wt_digraph_updated(w, &wtd)), input);
+
+    let input = "3\n2\n0\t1\t0.5\n2\t1\t-1.25\n";
+    let wdg: WeightedDigraph<f64> = read_weighted_digraph(input.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_weighted_digraph(w, &wdg)), input);
+    let wtwu: WeightedWTUGraph<f64> = read_weighted_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(
+        text(|w| write_weighted_wt_ugraph(w, &wtwu)),
+        "3\n2\n0\t1\t0.5\n1\t2\t-1.25\n"
+    );
+
+    // deleted vertices keep their index, but lose their edges, and are listed after the edges
+    let mut dg = read_digraph("4\n3\n0 1\n2 1\n3 3\n".as_bytes()).unwrap();
+    dg.delete_vertex(1);
+    assert_eq!(text(|w| write_digraph(w, &dg)), "4\n1\n3\t3\n-\n1\n");
+}
+
+#[test]
+fn write_labeled_graphs() {
+    let input = "3\n2\nx\ny\nz\nx\ty\t2\nz\ty\t3\n";
+    let lwdg: LabeledWeightedDigraph<String, u32> =
+        read_labeled_weighted_digraph(input.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_labeled_weighted_digraph(w, &lwdg)), input);
+
+    let lwwtu: LabeledWeightedWTUGraph<String, u32> =
+        read_labeled_weighted_wt_ugraph(input.as_bytes()).unwrap();
+    assert_eq!(
+        text(|w| write_labeled_weighted_wt_ugraph(w, &lwwtu)),
+        "3\n2\nx\ny\nz\nx\ty\t2\ny\tz\t3\n"
+    );
+
+    // labels which can't be read again are refused
+    let mut ldg: LabeledDigraph<String> = LabeledDigraph::new();
+    ldg.add_vertex("two words".to_string());
+    let error = write_labeled_digraph(Vec::new(), &ldg).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn round_trip_files() {
+    let digraph = include_str!("../../tests/files/Digraph.txt");
+    let written = text(|w| write_digraph(w, &read_digraph(digraph.as_bytes()).unwrap()));
+    let dg = read_digraph(written.as_bytes()).unwrap();
+    assert_eq!(dg.adj, read_digraph(digraph.as_bytes()).unwrap().adj);
+    assert_eq!(text(|w| write_digraph(w, &dg)), written);
+
+    let ugraph = include_str!("../../tests/files/UGraph.txt");
+    let wtu = read_wt_ugraph(ugraph.as_bytes()).unwrap();
+    let written = text(|w| write_wt_ugraph(w, &wtu));
+    let read = read_wt_ugraph(written.as_bytes()).unwrap();
+    assert_eq!(text(|w| write_wt_ugraph(w, &read)), written);
+
+    let weighted = include_str!("../../tests/files/WeightedDigraph.txt");
+    let wdg: WeightedDigraph<f64> = read_weighted_digraph(weighted.as_bytes()).unwrap();
+    let written = text(|w| write_weighted_digraph(w, &wdg));
+    let read: WeightedDigraph<f64> = read_weighted_digraph(written.as_bytes()).unwrap();
+    assert_eq!(read.weights, wdg.weights);
+    assert_eq!(text(|w| write_weighted_digraph(w, &read)), written);
+
+    let labeled = include_str!("../../tests/files/LabeledWeightedUGraph.txt");
+    let lwug: LabeledWeightedUGraph<String, f64> =
+        read_labeled_weighted_ugraph(labeled.as_bytes()).unwrap();
+    let written = text(|w| write_labeled_weighted_ugraph(w, &lwug));
+    let read: LabeledWeightedUGraph<String, f64> =
+        read_labeled_weighted_ugraph(written.as_bytes()).unwrap();
+    assert_eq!(read.lwdg.ldg.index_label, lwug.lwdg.ldg.index_label);
+    assert_eq!(read.lwdg.ldg.dg.adj, lwug.lwdg.ldg.dg.adj);
+    assert_eq!(read.lwdg.weights, lwug.lwdg.weights);
+    assert_eq!(text(|w| write_labeled_weighted_ugraph(w, &read)), written);
+}
+
+#[test]
+fn write_updated() {
+    let mut wtd = read_wt_digraph("3\n2\n0 1\n1 2\n".as_bytes()).unwrap();
+    let committed = text(|w| write_wt_digraph(w, &wtd));
+    wtd.append_vertex();
+    wtd.add_edge(3, 0);
+    wtd.delete_edge(0, 1);
+    wtd.delete_vertex(2);
+    let updated = text(|w| write_wt_digraph_updated(w, &wtd));
+    assert_eq!(updated, "4\n1\n3\t0\n-\n2\n");
+    // the committed state doesn't change until commit_edits
+    assert_eq!(text(|w| write_wt_digraph(w, &wtd)), committed);
+    wtd.commit_edits();
+    assert_eq!(text(|w| write_wt_digraph(w, &wtd)), updated);
+
+    let mut wtwd: WeightedWTDigraph<f32> =
+        read_weighted_wt_digraph("2\n1\n0 1 1.5\n".as_bytes()).unwrap();
+    wtwd.add_edge(1, 0, 2.5);
+    assert_eq!(
+        text(|w| write_weighted_wt_digraph_updated(w, &wtwd)),
+        "2\n2\n0\t1\t1.5\n1\t0\t2.5\n"
+    );
+    assert_eq!(wtwd.weight_updated(1, 0), 2.5);
+    assert_eq!(
+        text(|w| write_weighted_wt_digraph(w, &wtwd)),
+        "2\n1\n0\t1\t1.5\n"
+    );
+
+    let mut lwtu: LabeledWTUGraph<String> =
+        read_labeled_wt_ugraph("2\n1\na b\n".as_bytes()).unwrap();
+    lwtu.add_vertex("c".to_string());
+    lwtu.add_edge("c".to_string(), "a".to_string());
+    let updated = text(|w| write_labeled_wt_ugraph_updated(w, &lwtu));
+    assert_eq!(updated, "3\n2\na\nb\nc\na\tb\na\tc\n");
+    lwtu.commit_edits();
+    assert_eq!(text(|w| write_labeled_wt_ugraph(w, &lwtu)), updated);
+}
+
+#[test]
+fn read_deleted() {
+    // the vertices after "-" are deleted together with their edges
+    let dg = read_digraph("4\n3\n0 1\n1 2\n3 0\n-\n1\n\n3\n".as_bytes()).unwrap();
+    assert_eq!(dg.v_count(), 2);
+    assert_eq!(dg.e_count(), 0);
+    assert!(!dg.vertex_exists(1) && !dg.vertex_exists(3));
+    assert!(dg.vertex_exists(2));
+
+    let wtu = read_wt_ugraph("3\n1\n0 1\n-\n2\n".as_bytes()).unwrap();
+    assert_eq!(wtu.v_count(), 2);
+    assert_eq!(wtu.edges(1), vec![0]);
+
+    // labeled graphs delete labels, a label which only appears there is declared and deleted
+    let ldg: LabeledDigraph<String> = read_labeled_digraph("3\n1\na b\n-\nc\n".as_bytes()).unwrap();
+    assert_eq!(ldg.index_label, vec!["a", "b", "c"]);
+    assert_eq!(ldg.v_count(), 2);
+    assert!(!ldg.vertex_exists("c".to_string()));
+
+    assert!(matches!(
+        read_digraph("3\n0\n-\n3\n".as_bytes()),
+        Err(ReadError::VertexOutOfRange { line: 4 })
+    ));
+    assert!(matches!(
+        read_digraph("3\n1\n0 1\n-\n1 2\n".as_bytes()),
+        Err(ReadError::InvalidLine { line: 5 })
+    ));
+    // edges after "-" don't count as edges
+    assert!(matches!(
+        read_digraph("3\n1\n-\n0 1\n".as_bytes()),
+        Err(ReadError::EdgeCountMismatch {
+            expected: 1,
+            found: 0
+        })
+    ));
+}
+
+// writes `graph`, reads it again and checks that the result is written the same way
+fn round_trip<G>(
+    graph: &G,
+    write: impl Fn(&mut Vec<u8>, &G) -> io::Result<()>,
+    read: impl Fn(&[u8]) -> Result<G, ReadError>,
+) -> G {
+    let written = text(|w| write(w, graph));
+    let read = read(written.as_bytes()).unwrap();
+    assert_eq!(text(|w| write(w, &read)), written);
+    read
+}
+
+#[test]
+fn round_trip_deleted() {
+    let input = "5\n4\n0 1\n1 2\n3 4\n4 0\n";
+    let weighted = "5\n4\n0 1 0.5\n1 2 1.5\n3 4 2.5\n4 0 3.5\n";
+
+    let mut dg = read_digraph(input.as_bytes()).unwrap();
+    dg.delete_vertex(1);
+    let read = round_trip(&dg, |w, g| write_digraph(w, g), |r| read_digraph(r));
+    assert_eq!(read.adj, dg.adj);
+    assert_eq!(read.deleted_vertices, dg.deleted_vertices);
+    assert_eq!(
+        (read.v_count(), read.e_count()),
+        (dg.v_count(), dg.e_count())
+    );
+
+    let mut ug = read_ugraph(input.as_bytes()).unwrap();
+    ug.delete_vertex(4);
+    let read = round_trip(&ug, |w, g| write_ugraph(w, g), |r| read_ugraph(r));
+    assert_eq!(read.dg.adj, ug.dg.adj);
+    assert_eq!(
+        (read.v_count(), read.e_count()),
+        (ug.v_count(), ug.e_count())
+    );
+
+    let mut wdg: WeightedDigraph<f64> = read_weighted_digraph(weighted.as_bytes()).unwrap();
+    wdg.delete_vertex(0);
+    let read = round_trip(
+        &wdg,
+        |w, g| write_weighted_digraph(w, g),
+        |r| read_weighted_digraph(r),
+    );
+    assert_eq!(read.weights, wdg.weights);
+    assert_eq!(
+        (read.v_count(), read.e_count()),
+        (wdg.v_count(), wdg.e_count())
+    );
+
+    let mut wug: WeightedUGraph<f64> = read_weighted_ugraph(weighted.as_bytes()).unwrap();
+    wug.delete_vertex(2);
+    let read = round_trip(
+        &wug,
+        |w, g| write_weighted_ugraph(w, g),
+        |r| read_weighted_ugraph(r),
+    );
+    assert_eq!(read.wdg.weights, wug.wdg.weights);
+    assert_eq!(
+        (read.v_count(), read.e_count()),
+        (wug.v_count(), wug.e_count())
+    );
+
+    // wt-graphs, at the last commit and including the uncommitted changes
+    let mut wtd = read_wt_digraph(input.as_bytes()).unwrap();
+    wtd.delete_vertex(2);
+    let read = round_trip(
+        &wtd,
+        |w, g| write_wt_digraph_updated(w, g),
+        |r| read_wt_digraph(r),
+    );
+    assert_eq!(read.v_count(), wtd.v_count_updated());
+    assert!(!read.vertex_exists(2));
+    wtd.commit_edits();
+    let read = round_trip(&wtd, |w, g| write_wt_digraph(w, g), |r| read_wt_digraph(r));
+    assert_eq!(read.v_count(), wtd.v_count());
+    assert_eq!(read.deleted_vertices, wtd.deleted_vertices);
+
+    let mut wtu = read_wt_ugraph(input.as_bytes()).unwrap();
+    wtu.delete_vertex(0);
+    wtu.commit_edits();
+    let read = round_trip(&wtu, |w, g| write_wt_ugraph(w, g), |r| read_wt_ugraph(r));
+    assert_eq!(read.v_count(), wtu.v_count());
+    assert_eq!(read.edges(4), vec![3]);
+    let read = round_trip(
+        &wtu,
+        |w, g| write_wt_ugraph_updated(w, g),
+        |r| read_wt_ugraph(r),
+    );
+    assert_eq!(read.v_count(), wtu.v_count_updated());
+
+    let mut wtwd: WeightedWTDigraph<f64> = read_weighted_wt_digraph(weighted.as_bytes()).unwrap();
+    wtwd.delete_vertex(3);
+    wtwd.commit_edits();
+    let read = round_trip(
+        &wtwd,
+        |w, g| write_weighted_wt_digraph(w, g),
+        |r| read_weighted_wt_digraph(r),
+    );
+    assert_eq!(read.v_count(), wtwd.v_count());
+    assert_eq!(read.weight(1, 2), 1.5);
+    round_trip(
+        &wtwd,
+        |w, g| write_weighted_wt_digraph_updated(w, g),
+        |r| read_weighted_wt_digraph(r),
+    );
+
+    let mut wtwu: WeightedWTUGraph<f64> = read_weighted_wt_ugraph(weighted.as_bytes()).unwrap();
+    wtwu.delete_vertex(1);
+    let read = round_trip(
+        &wtwu,
+        |w, g| write_weighted_wt_ugraph_updated(w, g),
+        |r| read_weighted_wt_ugraph(r),
+    );
+    assert_eq!(read.v_count(), wtwu.v_count_updated());
+    wtwu.commit_edits();
+    let read = round_trip(
+        &wtwu,
+        |w, g| write_weighted_wt_ugraph(w, g),
+        |r| read_weighted_wt_ugraph(r),
+    );
+    assert_eq!(read.v_count(), wtwu.v_count());
+    assert_eq!(read.weight(4, 3), 2.5);
+
+    // labeled graphs only write their existing vertices, the labels stay the same
+    let labeled = "3\n2\na b\nb c\n";
+    let mut ldg: LabeledDigraph<String> = read_labeled_digraph(labeled.as_bytes()).unwrap();
+    ldg.delete_vertex("b".to_string());
+    let read = round_trip(
+        &ldg,
+        |w, g| write_labeled_digraph(w, g),
+        |r| read_labeled_digraph(r),
+    );
+    assert_eq!(
+        (read.v_count(), read.e_count()),
+        (ldg.v_count(), ldg.e_count())
+    );
+    assert!(!read.vertex_exists("b".to_string()));
+
+    let mut lwtu: LabeledWTUGraph<String> = read_labeled_wt_ugraph(labeled.as_bytes()).unwrap();
+    lwtu.delete_vertex("a".to_string());
+    lwtu.commit_edits();
+    let read = round_trip(
+        &lwtu,
+        |w, g| write_labeled_wt_ugraph(w, g),
+        |r| read_labeled_wt_ugraph(r),
+    );
+    assert_eq!(read.v_count(), lwtu.v_count());
+    assert_eq!(read.edges("c".to_string()), vec!["b".to_string()]);
+}
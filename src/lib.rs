@@ -18,23 +18,92 @@
 //! The module provides functions to:
 //! - Retrieve information about the initialized graph
 //! - perform fast operations on the initialized graph, such as outgoing edges, incoming edges, select, access, BFS, DFS, Shortest Path (fast)
+//! - count the edges between index ranges of a wt-digraph (`outgoing_in_range`, `incoming_count_from_range`, `edge_count_between`). only `incoming_count_from_range` takes two rank queries; qwt has no range queries, so the other two take a rank query per index of the target range or scan the outgoing edges, whichever is shorter
+//! - find the k-th smallest or median neighbor and the vertices with the most edges from an index range (`kth_outgoing`, `median_outgoing`, `top_k_in_degree`). qwt has no quantile or top-k queries, so these scan the edges of the vertex or the range and take O(degree · log n) or O(edges of the range · log n) time, an access query per edge
 //! - perform the same operations on an "updated" graph that includs the changes you have made. (kinda slow)
 //! - perform and query changes on the initialized graph (fast)
+//! - commit the changes, looking up only the changes of the changed vertices; every edge is still read from the QW-tree and the QW-tree is rebuilt from all edges, so a commit takes time linear in the number of edges (`commit_cost`, `commit_edits_copying`, `commit_edits_rebuild`)
+//! - commit the changes automatically after a number of edits or before reads of the changes get too expensive, and get notified when it happens (`WTCommitPolicy`, `CommitPolicy`)
 
 //! ### 3. Reading Graph Information from Files
-//! The library also offers a module called "from_file" which offers 16 different constructor-functions, one for each of the distinct wt-/graph-types that we offer.
-//! These require a specifially formatted file containing the information about the graph. please read the module description for the syntax of the file. \
+//! The library also offers a module called "io" which offers 16 different reader-functions, one for each of the distinct wt-/graph-types that we offer.
+//! These read a specifially formatted text (from a file or any other `BufRead`) containing the information about the graph. please read the module description for the syntax of the text. \
+
+//! ### 4. Algorithms
+//! The module "algo" offers graph algorithms, which work on every graph type implementing the traits they need.
+//! `algo::traversal` offers breadth-first and depth-first search on the committed or the updated state of a graph.
+//! `algo::scc` finds the strongly connected components of wt-digraphs and builds their condensation.
+//! `algo::community` finds communities in `WTUGraph` and `WeightedWTUGraph` by label propagation or the Louvain method, and evaluates the modularity of a partition.
+//! Every graph type implements the trait `GraphSearch`, which finds shortest paths (Dijkstra, BFS, Bellman-Ford, A*) and connected components.
+//! Directed graphs implement `TopologicalSort`, which returns a topological order or a cycle; wt-digraphs also check their uncommitted edits with `WTTopologicalSort`.
+//! Weighted undirected graphs implement `SpanningTree`, which finds a minimum spanning forest with Kruskal's or Prim's algorithm.
+//! `WeightedDigraph` and `WeightedWTDigraph` implement `MaxFlow`, which finds a maximum flow and a minimum cut with Dinic's algorithm.
+//! Directed graphs implement `PageRank`, which ranks the vertices by power iteration, optionally personalized.
+//! Undirected graphs implement `Triangles`, which counts triangles and computes the local and global clustering coefficients.
+//! Undirected graphs implement `Cores`, which computes core numbers and a degeneracy order; directed graphs implement `DirectedCores` for in- and out-cores.
+//! Undirected graphs implement `Biconnectivity`, which finds the articulation points, bridges and biconnected components.
+//! Every graph type implements `Neighborhood`, which finds the common out- and in-neighbors and the Jaccard similarity of two vertices.
+//! Every graph type implements `Centrality`, which computes betweenness (exact or sampled), closeness and harmonic centrality.
 
 //! With these functionalities, the wt_graphs library provides a comprehensive and efficient solution for working with both traditional and Wavelet-Tree graphs.
 
+pub mod algo;
 pub mod graph;
+pub mod io;
 pub mod prelude;
 pub mod traits;
 pub mod wt;
 
+use std::fmt;
+
 // Enum(s) used by all structures and thus publicly available
 #[derive(PartialEq, Debug, serde::Serialize, serde::Deserialize, Clone)]
 pub enum Edit<T> {
     Add(T),
     Delete(T),
 }
+
+/// Error returned by the fallible `try_*` functions (see the `Try*` traits in `traits`).
+///
+/// Wherever the panicking functions would panic, their `try_*` counterparts return one of these variants instead.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum GraphError {
+    /// The vertex (index or label) doesn't exist.
+    VertexNotFound,
+    /// There is no edge between the given vertices.
+    EdgeNotFound,
+    /// The edge already exists (only reported by graphs that don't allow parallel edges).
+    EdgeExists,
+    /// The label is already used by another vertex.
+    LabelExists,
+    /// The vertex only exists in the uncommitted changes of a wavelet tree based graph,
+    /// but the function operates on the committed graph. Call `commit_edits` first or use the `_updated` function.
+    UncommittedVertex,
+    /// The edge exists, but no weight is stored for it.
+    WeightNotFound,
+    /// The algorithm doesn't allow negative weights, but found an edge with a negative weight.
+    NegativeWeight,
+    /// There is a cycle with a negative sum of weights, so there is no shortest path.
+    NegativeCycle,
+    /// The source and the sink of a flow are the same vertex.
+    SourceIsSink,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            GraphError::VertexNotFound => "vertex doesn't exist",
+            GraphError::EdgeNotFound => "edge doesn't exist",
+            GraphError::EdgeExists => "edge already exists",
+            GraphError::LabelExists => "label is already in use",
+            GraphError::UncommittedVertex => "vertex hasn't been committed yet",
+            GraphError::WeightNotFound => "weight of edge is missing",
+            GraphError::NegativeWeight => "edge has a negative weight",
+            GraphError::NegativeCycle => "graph has a cycle with negative weight",
+            GraphError::SourceIsSink => "source and sink are the same vertex",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl std::error::Error for GraphError {}
@@ -30,6 +30,7 @@ pub mod indexed_digraph {
     pub use crate::graph::directed::Digraph;
     pub use crate::graph::weighted_directed::WeightedDigraph;
     pub use crate::traits::*; // todo (if we have time): import only necessary traits
+    pub use crate::GraphError;
     pub use crate::wt::directed::WTDigraph;
     pub use crate::wt::weighted_directed::WeightedWTDigraph;
 }
@@ -38,6 +39,7 @@ pub mod indexed_ugraph {
     pub use crate::graph::undirected::UGraph;
     pub use crate::graph::weighted_undirected::WeightedUGraph;
     pub use crate::traits::*; // todo (if we have time): import only necessary traits
+    pub use crate::GraphError;
     pub use crate::wt::undirected::WTUGraph;
     pub use crate::wt::weighted_undirected::WeightedWTUGraph;
 }
@@ -46,6 +48,7 @@ pub mod labeled_digraph {
     pub use crate::graph::labeled_directed::LabeledDigraph;
     pub use crate::graph::labeled_weighted_undirected::LabeledWeightedUGraph;
     pub use crate::traits::*; // todo (if we have time): import only necessary traits
+    pub use crate::GraphError;
     pub use crate::wt::labeled_directed::LabeledWTDigraph;
     pub use crate::wt::labeled_weighted_directed::LabeledWeightedWTDigraph;
 }
@@ -54,6 +57,7 @@ pub mod labeled_ugraph {
     pub use crate::graph::labeled_undirected::LabeledUGraph;
     pub use crate::graph::labeled_weighted_undirected::LabeledWeightedUGraph;
     pub use crate::traits::*; // todo (if we have time): import only necessary traits
+    pub use crate::GraphError;
     pub use crate::wt::labeled_undirected::LabeledWTUGraph;
     pub use crate::wt::labeled_weighted_undirected::LabeledWeightedWTUGraph;
 }
@@ -1,3 +1,8 @@
+use crate::GraphError;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
 // todo: change L to &L whereever possible!
 
 pub trait Graph<T> {
@@ -84,6 +89,27 @@ pub trait Directed<T> {
     /// existing even if they haven't been committed after creation). The deletion has to be committed.
     fn delete_incoming_edges(&mut self, vertex: T); // deletes all incoming edges of vertex; should return a Result
                                                     // deletes incoming edges of vertex
+
+    /// Returns the number of outgoing edges of vertex `vertex`. Parallel edges count separately.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    ///
+    /// The default implementation counts `outgoing_edges`. Wavelet tree based graphs answer this in constant time
+    /// from the bitmap.
+    fn out_degree(&self, vertex: T) -> usize {
+        self.outgoing_edges(vertex).len()
+    }
+
+    /// Returns the number of incoming edges of vertex `vertex`. Parallel edges count separately, like in `out_degree`,
+    /// so the in-degrees of all vertices add up to the number of edges for every graph type.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    ///
+    /// The default implementation counts `incoming_edges`, graphs whose `incoming_edges` lists a vertex with parallel
+    /// edges only once override it. Wavelet tree based graphs answer this with a single rank query on the wavelet tree.
+    fn in_degree(&self, vertex: T) -> usize {
+        self.incoming_edges(vertex).len()
+    }
 }
 pub trait Undirected<T> {
     /// For index based graphs: Returns a vector containing copies of all indices the vertex `vertex` has an edge to.
@@ -103,6 +129,16 @@ pub trait Undirected<T> {
     /// For wavelet tree based graphs the function operates on the uncommitted changes (i.e. it considers vertices as
     /// existing even if they haven't been committed after creation). The deletion has to be committed.
     fn delete_edges_from(&mut self, vertex: T);
+
+    /// Returns the number of edges connected to vertex `vertex`. A loop counts once.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    ///
+    /// The default implementation counts `edges`. Wavelet tree based graphs answer this with a few select and rank
+    /// queries.
+    fn degree(&self, vertex: T) -> usize {
+        self.edges(vertex).len()
+    }
 }
 pub trait Unlabeled<T> {
     /// Appends a new vertex and returns the index.
@@ -197,7 +233,7 @@ pub trait Weighted<T, W> {
     ///
     /// For wavelet tree based graphs, this operates only on the committed vertices (for a function that also
     /// operates on uncommitted vertices see `get_weight_updated`).
-    fn weight(&mut self, from: T, to: T) -> W;
+    fn weight(&self, from: T, to: T) -> W;
 }
 
 pub trait WT<T> {
@@ -238,6 +274,13 @@ pub trait WTUndirected<T> {
     /// For wavelet tree based graphs the function operates on the uncommitted changes (i.e. it considers vertices as
     /// existing even if they haven't been committed after creation).
     fn edges_updated(&self, vertex: T) -> Vec<T>;
+
+    /// Returns the number of edges connected to vertex `vertex` including uncommitted changes. A loop counts once.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    fn degree_updated(&self, vertex: T) -> usize {
+        self.edges_updated(vertex).len()
+    }
 }
 
 pub trait WTDirected<T> {
@@ -260,6 +303,24 @@ pub trait WTDirected<T> {
     /// For wavelet tree based graphs the function operates on the uncommitted changes (i.e. it considers vertices as
     /// existing even if they haven't been committed after creation).
     fn incoming_edges_updated(&self, vertex: T) -> Vec<T>;
+
+    /// Returns the number of outgoing edges of vertex `vertex` including uncommitted changes.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    ///
+    /// Wavelet tree based graphs only look at the uncommitted changes of `vertex` instead of its edges.
+    fn out_degree_updated(&self, vertex: T) -> usize {
+        self.outgoing_edges_updated(vertex).len()
+    }
+
+    /// Returns the number of incoming edges of vertex `vertex` including uncommitted changes.
+    ///
+    /// Panics if `vertex` doesn't exist.
+    ///
+    /// Like `incoming_edges_updated`, this has to search all uncommitted changes, but it doesn't collect the edges.
+    fn in_degree_updated(&self, vertex: T) -> usize {
+        self.incoming_edges_updated(vertex).len()
+    }
 }
 pub trait WTWeighted<T, W> {
     /// Returns the weight of the edge from vertex `from` to vertex `to`.
@@ -268,7 +329,7 @@ pub trait WTWeighted<T, W> {
     ///
     /// For wavelet tree based graphs the function operates on the uncommitted changes (i.e. it considers vertices as
     /// existing even if they haven't been committed after creation).
-    fn weight_updated(&mut self, from: T, to: T) -> W;
+    fn weight_updated(&self, from: T, to: T) -> W;
 }
 
 pub trait WTLabeled<L> {
@@ -285,21 +346,569 @@ pub trait WTLabeled<L> {
     fn index_updated(&self, label: &L) -> Option<usize>;
 }
 
+/// Commits the edits of a wavelet tree based graph automatically, see `CommitPolicy`.
+///
+/// The policy is checked once at the end of every call which edits the graph, so a call like `delete_outgoing_edges`
+/// never commits partway through. It isn't saved with the graph, and neither is the hook.
+pub trait WTCommitPolicy {
+    /// Returns the commit policy of the graph, which is `CommitPolicy::Manual` unless it was changed.
+    fn commit_policy(&self) -> CommitPolicy;
+
+    /// Sets the commit policy and commits right away if the pending edits already call for it.
+    fn set_commit_policy(&mut self, policy: CommitPolicy);
+
+    /// Sets the function called after every automatic commit, e.g. to log it. `None` removes the hook.
+    ///
+    /// Commits by `commit_edits` don't call the hook.
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>);
+}
+
+/// When a wavelet tree based graph commits its edits by itself, see `WTCommitPolicy`.
+///
+/// Pending edits are the edits of vertices and edges since the last commit, changes of weights don't count.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum CommitPolicy {
+    /// Only `commit_edits` commits.
+    #[default]
+    Manual,
+    /// Commits once there are this many pending edits.
+    AfterEdits(usize),
+    /// Commits once the number of pending edits reaches this fraction of the edges at the last commit.
+    EditFraction(f64),
+    /// Commits once a read of the uncommitted state which has to go through all pending edits, like
+    /// `incoming_edges_updated`, would cost more than this. The cost is the number of vertices with uncommitted edges
+    /// plus the number of pending edits.
+    ReadCost(usize),
+}
+
+/// Passed to the commit hook after an automatic commit.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CommitEvent {
+    /// The policy which caused the commit.
+    pub policy: CommitPolicy,
+    /// The number of pending edits before the commit.
+    pub pending_edits: usize,
+    /// The cost of a read of the uncommitted state before the commit, see `CommitPolicy::ReadCost`.
+    pub read_cost: usize,
+}
+
+/// The function called after an automatic commit, see `WTCommitPolicy::set_commit_hook`.
+pub type CommitHook = Arc<dyn Fn(&CommitEvent) + Send + Sync>;
+
+// Fallible versions of the functions above.
+// Each `try_*` function behaves like its counterpart, but returns a `GraphError` instead of panicking.
+
+pub trait TryGraph<T> {
+    /// Fallible version of `Graph::add_vertex`.
+    ///
+    /// Returns `GraphError::LabelExists` for label based graphs, if the label is already in use.
+    fn try_add_vertex(&mut self, vertex: T) -> Result<usize, GraphError>;
+
+    /// Fallible version of `Graph::delete_edge`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist and `GraphError::EdgeNotFound` if there
+    /// is no edge between them.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_delete_edge(&mut self, from: T, to: T) -> Result<(), GraphError>;
+
+    /// Fallible version of `Graph::delete_vertex`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_delete_vertex(&mut self, vertex: T) -> Result<(), GraphError>;
+
+    /// Fallible version of `Graph::edge_exists`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and returns
+    /// `GraphError::UncommittedVertex` if one of the vertices hasn't been committed yet.
+    fn try_edge_exists(&self, from: T, to: T) -> Result<bool, GraphError>;
+}
+
+pub trait TryDirected<T> {
+    /// Fallible version of `Directed::outgoing_edges`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and returns
+    /// `GraphError::UncommittedVertex` if `vertex` hasn't been committed yet.
+    fn try_outgoing_edges(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+
+    /// Fallible version of `Directed::incoming_edges`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and returns
+    /// `GraphError::UncommittedVertex` if `vertex` hasn't been committed yet.
+    fn try_incoming_edges(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+
+    /// Fallible version of `Directed::delete_outgoing_edges`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_delete_outgoing_edges(&mut self, vertex: T) -> Result<(), GraphError>;
+
+    /// Fallible version of `Directed::delete_incoming_edges`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_delete_incoming_edges(&mut self, vertex: T) -> Result<(), GraphError>;
+}
+
+pub trait TryUndirected<T> {
+    /// Fallible version of `Undirected::edges`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and returns
+    /// `GraphError::UncommittedVertex` if `vertex` hasn't been committed yet.
+    fn try_edges(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+
+    /// Fallible version of `Undirected::delete_edges_from`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_delete_edges_from(&mut self, vertex: T) -> Result<(), GraphError>;
+}
+
+pub trait TryLabeled<L> {
+    /// Fallible version of `Labeled::edit_label`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `old_label` doesn't exist and `GraphError::LabelExists` if `new_label`
+    /// is already in use.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError>;
+}
+
+pub trait TryUnweighted<T> {
+    /// Fallible version of `Unweighted::add_edge`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist.
+    /// Wavelet tree based graphs don't allow parallel edges and return `GraphError::EdgeExists` instead.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_add_edge(&mut self, from: T, to: T) -> Result<(), GraphError>;
+}
+
+pub trait TryWeighted<T, W> {
+    /// Fallible version of `Weighted::add_edge`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist.
+    /// Wavelet tree based graphs don't allow parallel edges and return `GraphError::EdgeExists` instead.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_add_edge(&mut self, from: T, to: T, weight: W) -> Result<(), GraphError>;
+
+    /// Fallible version of `Weighted::edit_weight`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist and `GraphError::EdgeNotFound` if there
+    /// is no edge between them.
+    ///
+    /// For wavelet tree based graphs the function operates on the uncommitted changes.
+    fn try_edit_weight(&mut self, from: T, to: T, weight: W) -> Result<(), GraphError>;
+
+    /// Fallible version of `Weighted::weight`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist, `GraphError::EdgeNotFound` if there
+    /// is no edge between them and `GraphError::WeightNotFound` if the edge has no weight.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and returns
+    /// `GraphError::UncommittedVertex` if one of the vertices hasn't been committed yet.
+    fn try_weight(&self, from: T, to: T) -> Result<W, GraphError>;
+}
+
+pub trait TryWTDirected<T> {
+    /// Fallible version of `WTDirected::outgoing_edges_updated`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist (including uncommitted changes).
+    fn try_outgoing_edges_updated(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+
+    /// Fallible version of `WTDirected::incoming_edges_updated`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist (including uncommitted changes).
+    fn try_incoming_edges_updated(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+}
+
+pub trait TryWTUndirected<T> {
+    /// Fallible version of `WTUndirected::edges_updated`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `vertex` doesn't exist (including uncommitted changes).
+    fn try_edges_updated(&self, vertex: T) -> Result<Vec<T>, GraphError>;
+}
+
+pub trait TryWTWeighted<T, W> {
+    /// Fallible version of `WTWeighted::weight_updated`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist, `GraphError::EdgeNotFound` if there
+    /// is no edge between them and `GraphError::WeightNotFound` if the edge has no weight (including uncommitted changes).
+    fn try_weight_updated(&self, from: T, to: T) -> Result<W, GraphError>;
+}
+
 // additional graph functionality
-// didn't get finished before deadline
-// pub enum ShortestPathAlgorithm {
-//     Dijkstra,
-//     BFS,
-//     BellmanFord,
-//     AStar,
-// }
 
-// pub trait GraphSearch {
-//     fn shortest_path(&self, from: usize, to: usize, mode: ShortestPathAlgorithm) -> Vec<usize>; // returns the shortest path from `from` to `to` using breadth first search
+/// The algorithm used by the functions of `GraphSearch`.
+///
+/// `D` is the type of the distances, see `GraphSearch::Distance`.
+pub enum ShortestPathAlgorithm<'a, T, D> {
+    /// Dijkstra's algorithm. Fails with `GraphError::NegativeWeight` if it reaches an edge with a negative weight.
+    Dijkstra,
+    /// Breadth first search, which finds the path with the fewest edges and ignores the weights.
+    /// The distance is the sum of the weights on that path, which might not be the smallest possible.
+    BFS,
+    /// The Bellman-Ford algorithm, which allows negative weights.
+    /// Fails with `GraphError::NegativeCycle` if a cycle with a negative sum of weights can be reached from the start.
+    BellmanFord,
+    /// A* search, which is guided by the heuristic. It gets a vertex and the target and has to estimate the
+    /// distance between them without overestimating it. Fails like `Dijkstra` on negative weights.
+    AStar(&'a dyn Fn(&T, &T) -> D),
+}
+
+impl<T, D> Clone for ShortestPathAlgorithm<'_, T, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, D> Copy for ShortestPathAlgorithm<'_, T, D> {}
+
+/// A path found by `GraphSearch`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShortestPath<T, D> {
+    /// The sum of the weights of the edges on the path; the number of edges for unweighted graphs.
+    pub distance: D,
+    /// The vertices on the path, starting with the start and ending with the target.
+    pub path: Vec<T>,
+}
 
-//     fn shortest_paths(&self, mode: ShortestPathAlgorithm) -> Vec<Vec<usize>>; // shortest paths from all vertices to all other vertices
+/// The shortest paths from a vertex to all vertices which can be reached from it (including itself), by target.
+pub type ShortestPaths<T, D> = HashMap<T, ShortestPath<T, D>>;
 
-//     fn connected_components(&self) -> Vec<Vec<usize>>; // returns all groups of vertices that are connected; makes no sense for directed graphs; default: bfs
+pub trait GraphSearch<T> {
+    /// The type of the distances: the weight type for weighted graphs, `usize` (the number of edges) for all others.
+    type Distance;
+
+    /// Returns the shortest path from `from` to `to`, or `None` if `to` can't be reached from `from`.
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `from` or `to` don't exist,
+    /// and the errors of the algorithm (see `ShortestPathAlgorithm`).
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn shortest_path(
+        &self,
+        from: T,
+        to: T,
+        mode: ShortestPathAlgorithm<T, Self::Distance>,
+    ) -> Result<Option<ShortestPath<T, Self::Distance>>, GraphError>;
+
+    /// Returns the shortest paths from `from` to all vertices which can be reached from it.
+    ///
+    /// Returns `GraphError::VertexNotFound` if `from` doesn't exist, and the errors of the algorithm.
+    /// A* runs one search per vertex, since the heuristic depends on the target.
+    fn shortest_paths_from(
+        &self,
+        from: T,
+        mode: ShortestPathAlgorithm<T, Self::Distance>,
+    ) -> Result<ShortestPaths<T, Self::Distance>, GraphError>;
+
+    /// Returns the shortest paths from all vertices to all vertices which can be reached from them, by start.
+    ///
+    /// Returns the errors of the algorithm.
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<T, Self::Distance>,
+    ) -> Result<HashMap<T, ShortestPaths<T, Self::Distance>>, GraphError>;
+
+    /// Returns the groups of vertices which are connected by edges, ignoring the direction of the edges
+    /// (i.e. the weakly connected components of directed graphs).
+    ///
+    /// The components are ordered by their first vertex by index. Each component starts with that vertex and
+    /// continues in breadth first order.
+    fn connected_components(&self) -> Vec<Vec<T>>;
+
+    /// Returns true if there is a path from `from` to `to`, following the direction of the edges in directed graphs.
+    /// Returns false if either `from` or `to` don't exist.
+    fn connected(&self, from: T, to: T) -> bool;
+}
 
-//     fn connected(&self, from: usize, to: usize) -> bool; // is a connected to b? default: bfs
-// }
+pub trait TopologicalSort<T> {
+    /// Returns all vertices in an order in which every edge goes from an earlier to a later vertex.
+    ///
+    /// If there is no such order, returns `Err` with a cycle instead: its vertices in the order of its edges,
+    /// the last vertex has an edge to the first. A vertex with an edge to itself is a cycle of one vertex.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn topological_order(&self) -> Result<Vec<T>, Vec<T>>;
+}
+
+pub trait WTTopologicalSort<T> {
+    /// Like `TopologicalSort::topological_order`, but on the graph with all uncommitted edits applied.
+    ///
+    /// Use this to find out whether the uncommitted edits would introduce a cycle, before `commit_edits` rebuilds the
+    /// wavelet tree.
+    fn topological_order_updated(&self) -> Result<Vec<T>, Vec<T>>;
+}
+
+/// The algorithm used by `SpanningTree::minimum_spanning_forest`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SpanningTreeAlgorithm {
+    /// Kruskal's algorithm: takes the edges by increasing weight, unless they would close a cycle,
+    /// which is checked with a union-find.
+    Kruskal,
+    /// Prim's algorithm: grows a tree from a vertex by always taking the lightest edge leaving it,
+    /// which is found with a binary heap.
+    Prim,
+}
+
+/// A minimum spanning forest, found by `SpanningTree`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SpanningForest<T, W> {
+    /// All vertices of the graph, the forest spans each of them.
+    pub vertices: Vec<T>,
+    /// The chosen edges with their weights.
+    pub edges: Vec<(T, T, W)>,
+    /// The sum of the weights of the chosen edges.
+    pub weight: W,
+}
+
+pub trait SpanningTree<T, W> {
+    /// Returns a minimum spanning forest: for every connected component a tree of edges, which connects all its
+    /// vertices with the smallest possible sum of weights. Use `WeightedUGraph::from_spanning_forest` or
+    /// `LabeledWeightedUGraph::from_spanning_forest` to turn it into a graph.
+    ///
+    /// Weights only need to be partially ordered. Edges with a weight which isn't comparable to itself,
+    /// i.e. a floating point NaN, are never chosen, as if they didn't exist. If any other two weights
+    /// can't be compared, they are treated as equal.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn minimum_spanning_forest(&self, mode: SpanningTreeAlgorithm) -> SpanningForest<T, W>;
+}
+
+/// A maximum flow, found by `MaxFlow`.
+#[derive(Clone, Debug)]
+pub struct Flow<T, W> {
+    /// The amount of flow from the source to the sink.
+    pub value: W,
+    /// The flow on every edge which carries any, by (from, to).
+    pub edges: HashMap<(T, T), W>,
+    /// The vertices which can still be reached from the source in the residual graph. They are the source side of a
+    /// minimum cut: the edges from them to all other vertices are saturated, and their capacities add up to `value`.
+    pub source_side: Vec<T>,
+}
+
+pub trait MaxFlow<T, W> {
+    /// Returns a maximum flow from `source` to `sink`, using the weights of the edges as their capacities
+    /// (Dinic's algorithm).
+    ///
+    /// Returns `GraphError::VertexNotFound` if either `source` or `sink` don't exist, `GraphError::SourceIsSink` if they
+    /// are the same vertex, and `GraphError::NegativeWeight` if an edge has a negative capacity. Edges with a capacity
+    /// which isn't comparable to zero, i.e. a floating point NaN, carry no flow.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn max_flow(&self, source: T, sink: T) -> Result<Flow<T, W>, GraphError>;
+}
+
+/// The parameters of `PageRank::pagerank`. `PageRankOptions::default()` uses a damping of 0.85, a tolerance of 1e-6,
+/// at most 100 iterations and no personalization.
+#[derive(Clone, Debug)]
+pub struct PageRankOptions<T> {
+    /// The probability of following an edge instead of jumping to a random vertex.
+    pub damping: f64,
+    /// The iteration stops once the scores change by less than this in total (the sum of the absolute changes).
+    pub tolerance: f64,
+    /// The iteration stops after this many steps, even if the scores still change by more than the tolerance.
+    pub max_iterations: usize,
+    /// The probabilities of jumping to each vertex, which don't need to add up to 1. Vertices which aren't in the map
+    /// are never jumped to. `None` jumps to every vertex with the same probability.
+    pub personalization: Option<HashMap<T, f64>>,
+}
+
+impl<T> Default for PageRankOptions<T> {
+    fn default() -> Self {
+        PageRankOptions {
+            damping: 0.85,
+            tolerance: 1e-6,
+            max_iterations: 100,
+            personalization: None,
+        }
+    }
+}
+
+pub trait PageRank<T> {
+    /// Returns the PageRank of every vertex, computed by power iteration. The scores add up to 1.
+    ///
+    /// The weights of weighted graphs are ignored. A vertex without outgoing edges (a dangling vertex) passes its score
+    /// on like a jump, i.e. by the personalization.
+    ///
+    /// Returns `GraphError::VertexNotFound` if the personalization contains a vertex which doesn't exist,
+    /// and `GraphError::NegativeWeight` if it contains a negative or NaN probability. If all its probabilities are 0,
+    /// it is ignored.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn pagerank(&self, options: &PageRankOptions<T>) -> Result<HashMap<T, f64>, GraphError>;
+}
+
+/// The triangles of an undirected graph, found by `Triangles`. Self-loops and parallel edges are ignored.
+#[derive(Clone, Debug)]
+pub struct TriangleCounts<T> {
+    /// The number of triangles every vertex is part of.
+    pub triangles: HashMap<T, usize>,
+    /// The number of neighbors of every vertex, not counting the vertex itself.
+    pub degrees: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq + Clone> TriangleCounts<T> {
+    /// Returns the number of triangles in the graph.
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.values().sum::<usize>() / 3
+    }
+
+    /// Returns the local clustering coefficient of every vertex: the share of the pairs of its neighbors which are
+    /// neighbors of each other. It is 0 for vertices with less than two neighbors.
+    pub fn local_clustering(&self) -> HashMap<T, f64> {
+        self.triangles
+            .iter()
+            .map(|(vertex, &triangles)| {
+                let pairs = pairs(self.degrees[vertex]);
+                let coefficient = match pairs {
+                    0 => 0.0,
+                    pairs => triangles as f64 / pairs as f64,
+                };
+                (vertex.clone(), coefficient)
+            })
+            .collect()
+    }
+
+    /// Returns the global clustering coefficient (transitivity): the share of the paths of length two which are
+    /// closed by a third edge. It is 0 if there is no such path.
+    pub fn global_clustering(&self) -> f64 {
+        let closed: usize = self.triangles.values().sum();
+        match self.degrees.values().map(|&d| pairs(d)).sum::<usize>() {
+            0 => 0.0,
+            paths => closed as f64 / paths as f64,
+        }
+    }
+}
+
+// the number of pairs of `n` things
+fn pairs(n: usize) -> usize {
+    n * n.saturating_sub(1) / 2
+}
+
+pub trait Triangles<T> {
+    /// Counts the triangles of the graph, exactly.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn triangle_counts(&self) -> TriangleCounts<T>;
+}
+
+pub trait Cores<T> {
+    /// Returns the core number of every vertex: the biggest `k` such that the vertex belongs to the k-core, the
+    /// biggest subgraph in which every vertex has at least `k` neighbors. Self-loops and parallel edges are ignored.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn core_numbers(&self) -> HashMap<T, usize>;
+
+    /// Returns the vertices in a degeneracy order: every vertex has at most `d` neighbors after it, where `d`, the
+    /// degeneracy of the graph, is the biggest core number. The core numbers don't decrease along the order, so every
+    /// k-core is a suffix of it.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn degeneracy_order(&self) -> Vec<T>;
+}
+
+pub trait DirectedCores<T> {
+    /// Returns the in-core number of every vertex: the biggest `k` such that the vertex belongs to the k-in-core, the
+    /// biggest subgraph in which every vertex has edges from at least `k` other vertices.
+    /// Self-loops and parallel edges are ignored.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn in_core_numbers(&self) -> HashMap<T, usize>;
+
+    /// Returns the out-core number of every vertex: the biggest `k` such that the vertex belongs to the k-out-core,
+    /// the biggest subgraph in which every vertex has edges to at least `k` other vertices.
+    /// Self-loops and parallel edges are ignored.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn out_core_numbers(&self) -> HashMap<T, usize>;
+}
+
+/// The cut vertices, cut edges and biconnected components of an undirected graph, found by `Biconnectivity`.
+/// Self-loops are ignored; an edge with a parallel edge is never a bridge.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BiconnectedComponents<T> {
+    /// The vertices whose deletion leaves more connected components, in the order of their indices.
+    pub articulation_points: Vec<T>,
+    /// The edges whose deletion leaves more connected components, each with the end with the smaller index first,
+    /// sorted by the indices.
+    pub bridges: Vec<(T, T)>,
+    /// The vertices of every biconnected component, the biggest subgraphs which stay connected when any one vertex is
+    /// deleted. Every edge is in exactly one component; an articulation point is in several, a vertex without edges
+    /// in none. The vertices of a component and the components are in the order of the indices.
+    pub components: Vec<Vec<T>>,
+}
+
+pub trait Biconnectivity<T> {
+    /// Finds the articulation points, bridges and biconnected components of the graph.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn biconnected_components(&self) -> BiconnectedComponents<T>;
+}
+
+pub trait Neighborhood<T> {
+    /// Returns the vertices which both `u` and `v` have an edge to, each once; in undirected graphs the common
+    /// neighbors. Wavelet tree based graphs return them in the order of their indices, all others in no particular
+    /// order. Returns `GraphError::VertexNotFound` if `u` or `v` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn common_out_neighbors(&self, u: T, v: T) -> Result<Vec<T>, GraphError>;
+
+    /// Returns the vertices which have an edge to both `u` and `v`, each once; in undirected graphs the same as
+    /// `common_out_neighbors`. Returns `GraphError::VertexNotFound` if `u` or `v` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn common_in_neighbors(&self, u: T, v: T) -> Result<Vec<T>, GraphError>;
+
+    /// Returns the Jaccard similarity of `u` and `v`: the number of their common out-neighbors (see
+    /// `common_out_neighbors`) divided by the number of vertices which are an out-neighbor of either.
+    /// 0 if neither has an edge. Returns `GraphError::VertexNotFound` if `u` or `v` doesn't exist.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn jaccard(&self, u: T, v: T) -> Result<f64, GraphError>;
+}
+
+/// The measure computed by `Centrality::centrality`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CentralityMeasure {
+    /// Brandes' betweenness: the number of shortest paths between other vertices which go through the vertex, where
+    /// `k` shortest paths between the same two vertices count `1/k` each. In undirected graphs every path is counted
+    /// once, not once per direction. The scores aren't normalized.
+    Betweenness,
+    /// Betweenness estimated from the shortest paths starting at `sources` vertices, which are chosen at random with
+    /// the given seed. The scores are scaled up to the number of vertices. Exact if `sources` is at least the
+    /// number of vertices.
+    BetweennessSampled { sources: usize, seed: u64 },
+    /// The number of vertices reachable from the vertex (besides itself) divided by the sum of their distances,
+    /// scaled by the share of the other vertices which are reachable (as proposed by Wasserman and Faust).
+    /// 0 if no other vertex can be reached.
+    Closeness,
+    /// The sum of `1 / distance` over all other vertices, where unreachable vertices add 0.
+    Harmonic,
+}
+
+pub trait Centrality<T> {
+    /// Returns the centrality of every vertex.
+    ///
+    /// Distances follow the direction of the edges, from the vertex to the others, and are the sums of the weights
+    /// in weighted graphs, the number of edges in all others. Returns `GraphError::NegativeWeight` if a weighted graph
+    /// has an edge with a negative weight.
+    ///
+    /// For wavelet tree based graphs, this operates only on the committed vertices and edges.
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<T, f64>, GraphError>;
+}
@@ -1,6 +1,10 @@
 pub mod directed;
+pub mod iter;
 pub mod labeled_directed;
 pub mod labeled_weighted_directed;
+pub mod mapped;
+pub mod persist;
+pub(crate) mod policy;
 pub mod weighted_directed;
 
 pub mod labeled_undirected;
@@ -1,10 +1,29 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::pagerank;
+use crate::algo::search::{self, SearchView};
+use crate::algo::topological;
 use crate::graph::directed::Digraph;
-use crate::traits::{Directed, Graph, Unlabeled, Unweighted, WTDirected, WT};
-use crate::Edit;
+use crate::traits::{
+    Directed, Graph, TryDirected, TryGraph, TryUnweighted, TryWTDirected, Unlabeled, Unweighted,
+    WTDirected, WT,
+};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::{TopologicalSort, WTTopologicalSort};
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::wt::iter::{IncomingIter, IncomingUpdatedIter, OutgoingIter, OutgoingUpdatedIter};
+use crate::wt::policy::AutoCommit;
+use crate::{Edit, GraphError};
 use core::panic;
-use qwt::{AccessUnsigned, RankUnsigned, SelectUnsigned, QWT256};
+use qwt::{AccessUnsigned, RankUnsigned, QWT256};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ops::Range;
 use vers_vecs::{BitVec, RsVec};
 // 1 MAJOR if WTGraph has no edges, subtract overflow in qwt crate
 
@@ -21,17 +40,20 @@ mod test;
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WTDigraph {
     pub(crate) wt_adj_len: usize,                      // last index + 1
-    e_count: usize,                                    // number of edges
+    pub(crate) e_count: usize,                         // number of edges
     pub(crate) wt_adj_len_updated: usize,              // last index + 1 updated
     pub(crate) e_count_updated: usize,                 // number of edges
-    wt_adj: QWT256<usize>,                             // the wavelet tree adjacency list
-    starting_indices: RsVec,                           // starting indices of each
+    pub(crate) wt_adj: QWT256<usize>,                  // the wavelet tree adjacency list
+    pub(crate) starting_indices: RsVec,                // starting indices of each
     pub(crate) deleted_vertices: HashMap<usize, bool>, // key: index of vertex, value true (meaning, vertex is deleted); can never be false (gets reset when shrinking but not when committing)
     // todo: change every function that uses this
     pub(crate) deleted_vertices_uncommitted: HashMap<usize, bool>, // saves only changes to deleted_vertices; true means vertex is deleted, false means it got readded
     // todo: change every function that uses this
-    adj_uncommitted: HashMap<usize, Vec<Edit<usize>>>, // changes to outgoing edges
+    pub(crate) adj_uncommitted: HashMap<usize, Vec<Edit<usize>>>, // changes to outgoing edges
     pub(crate) has_uncommitted_edits: bool,
+    pub(crate) pending_edits: usize, // edits of vertices and edges since last commit, see traits::CommitPolicy
+    #[serde(skip)]
+    pub(crate) auto_commit: AutoCommit,
 }
 
 impl WTDigraph {
@@ -68,6 +90,8 @@ impl WTDigraph {
             adj_uncommitted: HashMap::new(),
             deleted_vertices_uncommitted: HashMap::new(), // changed from HashMap::new() to Vec::new()
             has_uncommitted_edits: false,
+            pending_edits: 0,
+            auto_commit: AutoCommit::default(),
         };
     }
 
@@ -93,8 +117,508 @@ impl WTDigraph {
             adj_uncommitted: HashMap::new(),
             deleted_vertices_uncommitted: HashMap::new(), // changed from HashMap::new() to Vec::new()
             has_uncommitted_edits: false,
+            pending_edits: 0,
+            auto_commit: AutoCommit::default(),
         };
     }
+
+    // returns an error if `vertex` doesn't exist at the last commit,
+    // telling apart vertices which only exist in the uncommitted changes
+    pub(crate) fn check_committed(&self, vertex: usize) -> Result<(), GraphError> {
+        if self.vertex_exists(vertex) {
+            return Ok(());
+        }
+        if self.vertex_exists_updated(vertex) {
+            return Err(GraphError::UncommittedVertex);
+        }
+        Err(GraphError::VertexNotFound)
+    }
+
+    // returns an iterator over the outgoing edges of the given vertex, see wt::iter.
+    // unlike outgoing_edges, this doesn't allocate a new vector.
+    // panics if the vertex doesn't exist
+    pub fn outgoing_iter(&self, vertex: usize) -> OutgoingIter<'_> {
+        if !self.vertex_exists(vertex) {
+            panic!("outgoing_iter: Vertex {} doesn't exist.", vertex);
+        }
+        OutgoingIter::new(self, vertex)
+    }
+
+    // returns an iterator over the incoming edges of the given vertex, see wt::iter.
+    // unlike incoming_edges, this doesn't allocate a new vector.
+    // panics if the vertex doesn't exist
+    pub fn incoming_iter(&self, vertex: usize) -> IncomingIter<'_> {
+        if !self.vertex_exists(vertex) {
+            panic!("incoming_iter: Vertex {} doesn't exist.", vertex);
+        }
+        IncomingIter::new(self, vertex)
+    }
+
+    // returns an iterator over the outgoing edges of the given vertex, which exist and weren't deleted, or were created since last commit.
+    // panics if the vertex doesn't exist
+    pub fn outgoing_iter_updated(&self, vertex: usize) -> OutgoingUpdatedIter<'_> {
+        if !self.vertex_exists_updated(vertex) {
+            panic!("Vertex {vertex} doesn't exist!");
+        }
+        OutgoingUpdatedIter::new(self, vertex)
+    }
+
+    // returns an iterator over the incoming edges of the given vertex, which exist and weren't deleted, or were created since last commit.
+    // like incoming_edges_updated, this has to search all uncommitted changes. Commit first and use incoming_iter instead, if possible.
+    // panics if the vertex doesn't exist
+    pub fn incoming_iter_updated(&self, vertex: usize) -> IncomingUpdatedIter<'_> {
+        if !self.vertex_exists_updated(vertex) {
+            panic!("Vertex {vertex} doesn't exist!");
+        }
+        IncomingUpdatedIter::new(self, vertex)
+    }
+
+    // returns the range of the given vertex' outgoing edges in wt_adj at last commit.
+    // the vertex must exist at last commit.
+    pub(crate) fn outgoing_range(&self, vertex: usize) -> (usize, usize) {
+        let start = self.starting_indices.select1(vertex) - vertex;
+        let end = self.starting_indices.select1(vertex + 1) - (vertex + 1);
+
+        if start > self.wt_adj.len() || start == end {
+            return (start, start);
+        }
+        (start, end)
+    }
+
+    // returns the number of edges from `from` to `to` at last commit, using two rank queries.
+    // this can only be more than one if the wt-digraph was built from a digraph with parallel edges.
+    pub(crate) fn edge_count(&self, from: usize, to: usize) -> usize {
+        if from >= self.wt_adj_len {
+            return 0;
+        }
+        self.occurrences(to, self.outgoing_range(from))
+    }
+
+    // returns the outgoing edges of the given vertex at last commit which go to a vertex in `range`, sorted by index.
+    // a vertex is returned once for every edge to it; edges to deleted vertices are left out.
+    // not a range query: qwt has none, so this is either one rank query per index in `range` or a linear scan of the
+    // vertex' outgoing edges followed by a sort, whichever is shorter. O(min(|range|, out-degree) * log n).
+    // panics if the vertex doesn't exist
+    pub fn outgoing_in_range(&self, vertex: usize, range: Range<usize>) -> Vec<usize> {
+        if !self.vertex_exists(vertex) {
+            panic!("outgoing_in_range: Vertex {} doesn't exist.", vertex);
+        }
+        let positions = self.outgoing_range(vertex);
+        let range = self.clamp(range);
+        if range.len() < positions.1 - positions.0 {
+            return range
+                .filter(|&to| self.vertex_exists(to))
+                .flat_map(|to| std::iter::repeat_n(to, self.occurrences(to, positions)))
+                .collect();
+        }
+        let mut edges: Vec<usize> = (positions.0..positions.1)
+            .map(|pos| self.wt_adj.get(pos).unwrap()) // safe, pos is inside the range
+            .filter(|to| range.contains(to) && self.vertex_exists(*to))
+            .collect();
+        edges.sort_unstable();
+        edges
+    }
+
+    // returns the number of edges at last commit from a vertex in `range` to the given vertex.
+    // the outgoing edges of consecutive vertices are consecutive in wt_adj, so these are two rank queries.
+    // like edge_count_between, edges from and to deleted vertices are left out: the vertex itself has to exist, and a
+    // commit drops the outgoing edges of the vertices it deletes, so deleted vertices in `range` have no edges to count.
+    // vertices deleted since last commit still count, like every other query at last commit.
+    // panics if the vertex doesn't exist
+    pub fn incoming_count_from_range(&self, vertex: usize, range: Range<usize>) -> usize {
+        if !self.vertex_exists(vertex) {
+            panic!("incoming_count_from_range: Vertex {} doesn't exist.", vertex);
+        }
+        self.occurrences(vertex, self.block_range(range))
+    }
+
+    // returns the number of edges at last commit from a vertex in `from` to a vertex in `to`.
+    // edges to deleted vertices are left out, and deleted vertices in `from` have no edges, see incoming_count_from_range.
+    // not a range query: like outgoing_in_range, this is either one rank query per index in `to`, or a linear scan of
+    // all outgoing edges of `from`, whichever is shorter. O(min(|to|, edges of `from`) * log n).
+    pub fn edge_count_between(&self, from: Range<usize>, to: Range<usize>) -> usize {
+        let positions = self.block_range(from);
+        let to = self.clamp(to);
+        if to.len() < positions.1 - positions.0 {
+            return to
+                .filter(|&to| self.vertex_exists(to))
+                .map(|to| self.occurrences(to, positions))
+                .sum();
+        }
+        (positions.0..positions.1)
+            .map(|pos| self.wt_adj.get(pos).unwrap()) // safe, pos is inside the range
+            .filter(|next| to.contains(next) && self.vertex_exists(*next))
+            .count()
+    }
+
+    // returns the k-th smallest (counting from 0) vertex the given vertex has an outgoing edge to at last commit,
+    // or None if there are no more than k edges. parallel edges count once each, edges to deleted vertices are left out.
+    // a scan, not a wavelet tree query: qwt has no quantile queries, so all outgoing edges are read with an access
+    // query each and the k-th is selected from them. O(out-degree * log n) time and O(out-degree) memory.
+    // panics if the vertex doesn't exist
+    pub fn kth_outgoing(&self, vertex: usize, k: usize) -> Option<usize> {
+        self.kth_existing(self.outgoing_iter(vertex), |_| Some(k))
+    }
+
+    // returns the median of the vertices the given vertex has an outgoing edge to at last commit, the smaller one of
+    // the two in the middle for an even number of edges. None if there are no edges.
+    // a scan like kth_outgoing, O(out-degree * log n).
+    // panics if the vertex doesn't exist
+    pub fn median_outgoing(&self, vertex: usize) -> Option<usize> {
+        self.kth_existing(self.outgoing_iter(vertex), |len| {
+            len.checked_sub(1).map(|last| last / 2)
+        })
+    }
+
+    // returns the k vertices with the most incoming edges at last commit from vertices in `range`, with the number of
+    // these edges; the most first, ties by index. vertices without such edges and deleted vertices aren't returned.
+    // a scan, not a wavelet tree query: qwt can't list the most frequent values in a range, so all outgoing edges of
+    // `range` (one block in wt_adj) are read with an access query each, counted and sorted by their count.
+    // O(b * log n) time and O(b) memory, where b is the number of these edges.
+    pub fn top_k_in_degree(&self, range: Range<usize>, k: usize) -> Vec<(usize, usize)> {
+        let (start, end) = self.block_range(range);
+        let edges = (start..end).map(|pos| self.wt_adj.get(pos).unwrap()); // safe, pos is inside the range
+        self.top_k_existing(edges, k)
+    }
+
+    // returns the k-th smallest of the edges to vertices which exist, where k depends on their number
+    pub(crate) fn kth_existing<I, F>(&self, edges: I, k: F) -> Option<usize>
+    where
+        I: Iterator<Item = usize>,
+        F: FnOnce(usize) -> Option<usize>,
+    {
+        let mut edges: Vec<usize> = edges.filter(|&to| self.vertex_exists(to)).collect();
+        let k = k(edges.len()).filter(|&k| k < edges.len())?;
+        Some(*edges.select_nth_unstable(k).1)
+    }
+
+    // returns the k vertices which exist and are the most frequent in `edges`, see top_k_in_degree
+    pub(crate) fn top_k_existing<I>(&self, edges: I, k: usize) -> Vec<(usize, usize)>
+    where
+        I: Iterator<Item = usize>,
+    {
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for to in edges.filter(|&to| self.vertex_exists(to)) {
+            *counts.entry(to).or_insert(0) += 1;
+        }
+        let mut counts: Vec<(usize, usize)> = counts.into_iter().collect();
+        counts.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        counts.truncate(k);
+        counts
+    }
+
+    // returns how often `vertex` is in wt_adj between start (included) and end (excluded), with two rank queries
+    pub(crate) fn occurrences(&self, vertex: usize, (start, end): (usize, usize)) -> usize {
+        if start >= end {
+            return 0;
+        }
+        // rank is None if `vertex` is bigger than every vertex in wt_adj
+        match (self.wt_adj.rank(vertex, end), self.wt_adj.rank(vertex, start)) {
+            (Some(rank_end), Some(rank_start)) => rank_end - rank_start,
+            _ => 0,
+        }
+    }
+
+    // returns the range in wt_adj of the outgoing edges of all vertices in `range` at last commit
+    fn block_range(&self, range: Range<usize>) -> (usize, usize) {
+        let range = self.clamp(range);
+        if range.is_empty() {
+            return (0, 0);
+        }
+        let start = self.starting_indices.select1(range.start) - range.start;
+        let end = self.starting_indices.select1(range.end) - range.end;
+        (start, end)
+    }
+
+    // cuts off the indices which don't belong to a vertex at last commit
+    fn clamp(&self, range: Range<usize>) -> Range<usize> {
+        let end = range.end.min(self.wt_adj_len);
+        range.start.min(end)..end
+    }
+
+    // returns the number of committed edges from `from` to `to`, which were deleted since last commit.
+    // every Delete(to) in the changes of `from` removes one of them.
+    fn deleted_edge_count(&self, from: usize, to: usize) -> usize {
+        let deletions = match self.adj_uncommitted.get(&from) {
+            Some(changes) => changes
+                .iter()
+                .filter(|change| **change == Edit::Delete(to))
+                .count(),
+            None => 0,
+        };
+        if deletions == 0 {
+            return 0;
+        }
+        deletions.min(self.edge_count(from, to))
+    }
+
+    // returns the number of edges from `from` to `to` including the uncommitted changes
+    pub(crate) fn edge_count_updated(&self, from: usize, to: usize) -> usize {
+        let mut count = 0;
+        if self.vertex_exists(from) {
+            count = self.edge_count(from, to) - self.deleted_edge_count(from, to);
+        }
+        if let Some(changes) = self.adj_uncommitted.get(&from) {
+            count += changes
+                .iter()
+                .filter(|change| **change == Edit::Add(to))
+                .count();
+        }
+        count
+    }
+
+    // returns the degree of the given vertex, if this wt-digraph holds the edges of an undirected graph (see wt::undirected).
+    // every edge is either incoming or outgoing, except for loops, which are both and only count once.
+    pub(crate) fn undirected_degree(&self, vertex: usize) -> usize {
+        self.in_degree(vertex) + self.out_degree(vertex) - self.edge_count(vertex, vertex)
+    }
+
+    // same as undirected_degree, but including the uncommitted changes
+    pub(crate) fn undirected_degree_updated(&self, vertex: usize) -> usize {
+        self.in_degree_updated(vertex) + self.out_degree_updated(vertex)
+            - self.edge_count_updated(vertex, vertex)
+    }
+
+    // returns an error if `vertex` doesn't exist including the uncommitted changes
+    pub(crate) fn check_updated(&self, vertex: usize) -> Result<(), GraphError> {
+        if self.vertex_exists_updated(vertex) {
+            return Ok(());
+        }
+        Err(GraphError::VertexNotFound)
+    }
+
+    // returns the number of edits of vertices and edges since the last commit, see traits::CommitPolicy
+    pub fn pending_edits(&self) -> usize {
+        self.pending_edits
+    }
+
+    // returns the cost of a read which goes through all uncommitted changes, like incoming_iter_updated:
+    // the number of vertices with uncommitted edges plus the number of pending edits. see CommitPolicy::ReadCost
+    pub fn updated_read_cost(&self) -> usize {
+        self.adj_uncommitted.len() + self.pending_edits
+    }
+
+    // deletes the edge like delete_edge, but doesn't commit. functions which delete several edges use it
+    // and call commit_if_due once at the end, so that a single call never commits partway through.
+    pub(crate) fn delete_edge_uncommitted(&mut self, from: usize, to: usize) {
+        if !self.edge_exists_updated(from, to) {
+            panic!("Edge from {} to {} doesn't exist!", from, to);
+        }
+        match self.adj_uncommitted.get_mut(&from) {
+            Some(adj) => {
+                let i: Option<usize> = adj.iter().position(|x| x == &Edit::Add(to)); // safe because we know it exists
+
+                match i {
+                    Some(_added_e) => {
+                        adj.swap_remove(i.unwrap());
+                    }
+                    None => {}
+                }
+
+                adj.push(Edit::Delete(to));
+                self.e_count_updated -= 1;
+            }
+
+            None => {
+                self.adj_uncommitted.insert(from, vec![Edit::Delete(to)]);
+                self.e_count_updated -= 1;
+            }
+        }
+
+        self.has_uncommitted_edits = true;
+        self.pending_edits += 1;
+    }
+
+    // commits the edits if the commit policy calls for it, after every call which edits the graph
+    pub(crate) fn commit_if_due(&mut self) {
+        if let Some(event) = self.auto_commit.due(self) {
+            self.commit_edits();
+            self.auto_commit.notify(&event);
+        }
+    }
+
+    // returns how much work commit_edits_copying and commit_edits_rebuild have to do, see CommitCost.
+    // commit_edits uses it to choose between them.
+    pub fn commit_cost(&self) -> CommitCost {
+        let committed = self.wt_adj_len.min(self.wt_adj_len_updated);
+        let mut rebuilt_vertices = 0;
+        let mut rebuilt_edges = 0;
+        let mut skipped_edges = 0; // committed edges of the changed vertices, which aren't copied
+        let mut copied_ranges = 0;
+        let mut next = 0; // the first vertex after the last changed one
+        for v in self.changed_vertices() {
+            if next < v {
+                copied_ranges += 1;
+            }
+            next = v + 1;
+            let (start, end) = self.outgoing_range(v);
+            skipped_edges += end - start;
+            if self.vertex_exists_updated(v) {
+                rebuilt_vertices += 1;
+                rebuilt_edges += self.out_degree_updated(v);
+            }
+        }
+        if next < committed {
+            copied_ranges += 1;
+        }
+        for v in committed..self.wt_adj_len_updated {
+            if self.vertex_exists_updated(v) {
+                rebuilt_vertices += 1;
+                rebuilt_edges += self.out_degree_updated(v);
+            }
+        }
+        let copied_edges = self.copied_edge_count() - skipped_edges;
+        CommitCost {
+            vertices: self.wt_adj_len_updated,
+            copied_ranges,
+            copied_edges,
+            rebuilt_vertices,
+            rebuilt_edges,
+            built_edges: copied_edges + rebuilt_edges,
+        }
+    }
+
+    // commits like commit_edits_rebuild, but only collects the outgoing edges of the vertices with uncommitted changes
+    // one by one. the edges of all other vertices are copied from the last commit range by range, see CommitCost.
+    // this isn't an incremental commit: every copied edge is still read from the QW-tree, and the QW-tree is built
+    // again from the whole new sequence.
+    pub fn commit_edits_copying(&mut self) {
+        let committed = self.wt_adj_len.min(self.wt_adj_len_updated); // vertices which can be copied
+        let mut sequence: Vec<usize> = Vec::with_capacity(self.e_count_updated);
+        let mut bv = BitVec::with_capacity(self.wt_adj_len_updated + self.e_count_updated);
+
+        // the changed vertices, followed by the vertices added since the last commit
+        let mut rebuilt = self.changed_vertices();
+        rebuilt.extend(committed..self.wt_adj_len_updated);
+        let mut next = 0; // the first vertex which isn't in bv yet
+        for v in rebuilt {
+            self.copy_committed(next..v, &mut sequence, &mut bv);
+            bv.append(true);
+            if self.vertex_exists_updated(v) {
+                for to in self.outgoing_iter_updated(v) {
+                    bv.append(false);
+                    sequence.push(to);
+                }
+            }
+            next = v + 1;
+        }
+        self.copy_committed(next..committed, &mut sequence, &mut bv);
+        self.finish_commit(sequence, bv);
+    }
+
+    // collects the outgoing edges of every vertex including the uncommitted changes and rebuilds the QW-tree from them.
+    // cheaper than commit_edits_copying if most vertices have changed, see CommitCost::prefers_rebuild.
+    pub fn commit_edits_rebuild(&mut self) {
+        // build new sequence and bitvec
+
+        let mut sequence: Vec<usize> = Vec::new();
+        let mut bv = BitVec::new();
+
+        for v in 0..self.wt_adj_len_updated {
+            bv.append(true); // appends a 1 to mark the beginning of a new vertex
+
+            if !self.vertex_exists_updated(v) {
+                continue;
+            }
+
+            let adj: Vec<usize> = self.outgoing_edges_updated(v);
+            for i in 0..adj.len() {
+                bv.append(false); // appends a 0 to bitmap for every element in adj
+                sequence.push(adj[i]); // moves all elements of adj into sequence
+            }
+        }
+        self.finish_commit(sequence, bv);
+    }
+
+    // applies all other changes and replaces the QW-tree, after a commit has built the new sequence and bitvec
+    fn finish_commit(&mut self, mut sequence: Vec<usize>, bv: BitVec) {
+        self.wt_adj_len = self.wt_adj_len_updated;
+        // e_count_updated still counts the outgoing edges of deleted vertices, which aren't in the sequence
+        self.e_count = sequence.len();
+        // update deleted_vertices
+        for (vertex, change) in self.deleted_vertices_uncommitted.iter() {
+            if *change {
+                // i.e. if it was deleted
+                self.deleted_vertices.insert(*vertex, true);
+            } else {
+                // i.e. if it was readded
+                self.deleted_vertices.remove(vertex);
+            }
+        }
+        self.adj_uncommitted = HashMap::new(); // reset adj_uncommitted
+        self.wt_adj = QWT256::new(&mut sequence);
+        self.starting_indices = RsVec::from_bit_vec(bv);
+
+        self.discard_edits(); // reset all uncommitted changes
+    }
+
+    // the vertices of the last commit whose outgoing edges can't be copied by commit_edits_copying, sorted.
+    // these are the vertices with uncommitted edges, and the vertices deleted or added again since the last commit.
+    // vertices deleted before the last commit have no edges left, so their empty ranges are copied like the others.
+    fn changed_vertices(&self) -> Vec<usize> {
+        let committed = self.wt_adj_len.min(self.wt_adj_len_updated);
+        let mut changed: Vec<usize> = self
+            .adj_uncommitted
+            .keys()
+            .chain(self.deleted_vertices_uncommitted.keys())
+            .copied()
+            .filter(|v| *v < committed)
+            .collect();
+        changed.sort_unstable();
+        changed.dedup();
+        changed
+    }
+
+    // the number of committed edges of the vertices which are kept by a commit
+    fn copied_edge_count(&self) -> usize {
+        let committed = self.wt_adj_len.min(self.wt_adj_len_updated);
+        self.starting_indices.select1(committed) - committed
+    }
+
+    // appends the committed outgoing edges of `vertices` to sequence and their bits to bv
+    fn copy_committed(&self, vertices: Range<usize>, sequence: &mut Vec<usize>, bv: &mut BitVec) {
+        if vertices.is_empty() {
+            return;
+        }
+        let start = self.starting_indices.select1(vertices.start);
+        let end = self.starting_indices.select1(vertices.end);
+        // the edges of a vertex v start at select1(v) - v in wt_adj
+        sequence.extend(
+            (start - vertices.start..end - vertices.end).map(|i| self.wt_adj.get(i).unwrap()), // safe, i < e_count
+        );
+        // vers-vecs' get_bits returns 0 for 64 bits which don't start at a word boundary, so the bits are copied in halves
+        let mut pos = start;
+        while pos < end {
+            let len = (end - pos).min(32);
+            bv.append_bits(self.starting_indices.get_bits_unchecked(pos, len), len);
+            pos += len;
+        }
+    }
+}
+
+// The work of a commit of a wt-digraph, see WTDigraph::commit_cost.
+// Both commits read all `built_edges` one by one, the kept ones with an access query on the QW-tree each, and build the
+// QW-tree from scratch from them. That is the same work for both and almost all of a commit: the access queries take
+// about three quarters of the time, the build most of the rest. The commits only differ in the work per vertex:
+// commit_edits_rebuild looks up the uncommitted changes and the range of each of the `vertices`,
+// commit_edits_copying only those of the `rebuilt_vertices`, and finds the range of each of the `copied_ranges`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CommitCost {
+    pub vertices: usize,         // vertices after the commit, including deleted ones
+    pub copied_ranges: usize,    // ranges of unchanged vertices copied from the last commit by commit_edits_copying
+    pub copied_edges: usize,     // edges copied from the last commit by commit_edits_copying
+    pub rebuilt_vertices: usize, // vertices whose outgoing edges are collected one by one by commit_edits_copying
+    pub rebuilt_edges: usize,    // outgoing edges of rebuilt_vertices
+    pub built_edges: usize,      // edges read and built into the new QW-tree, by either commit
+}
+
+impl CommitCost {
+    // whether commit_edits_rebuild does less work than commit_edits_copying, counting a range lookup for every
+    // vertex it collects against one for every vertex and range commit_edits_copying collects or copies.
+    // the edges are left out, they are the same for both.
+    pub fn prefers_rebuild(&self) -> bool {
+        self.rebuilt_vertices + self.copied_ranges >= self.vertices
+    }
 }
 
 impl Graph<usize> for WTDigraph {
@@ -107,6 +631,7 @@ impl Graph<usize> for WTDigraph {
     fn add_vertex(&mut self, vertex: usize) -> usize {
         // use at own risk
         self.has_uncommitted_edits = true;
+        self.pending_edits += 1;
         self.adj_uncommitted.insert(vertex, Vec::new());
         // case 1 : vertex does exist; deleted_vertices_uncommitted does not contain vertex
         // case 2 : vertex does exist, deleted_vertices_uncommitted does contain verte
@@ -117,31 +642,30 @@ impl Graph<usize> for WTDigraph {
             if self.deleted_vertices_uncommitted.contains_key(&vertex) {
                 // case 2
                 self.deleted_vertices_uncommitted.remove(&vertex);
-                return vertex;
             } else {
                 // case 1
-                self.delete_incoming_edges(vertex);
+                for from in self.incoming_edges_updated(vertex) {
+                    self.delete_edge_uncommitted(from, vertex);
+                }
                 self.deleted_vertices_uncommitted.insert(vertex, false);
-                return vertex;
             }
         } else {
             if self.deleted_vertices_uncommitted.contains_key(&vertex) {
                 // case 4
                 self.deleted_vertices_uncommitted.insert(vertex, false);
                 // self.wt_adj_len_updated += 1;
-                return vertex;
             } else {
                 // case 3
                 if vertex >= self.wt_adj_len_updated {
                     self.deleted_vertices_uncommitted.insert(vertex, false);
                     self.wt_adj_len_updated += vertex - self.wt_adj_len_updated + 1;
-                    return vertex;
                 } else {
                     self.deleted_vertices_uncommitted.insert(vertex, false);
-                    return vertex;
                 }
             }
         }
+        self.commit_if_due();
+        vertex
     }
 
     // return the number of edges in the graph at the last commit.
@@ -158,31 +682,8 @@ impl Graph<usize> for WTDigraph {
     // iterates over 'from's enty in 'adj_uncommited' and deletes entries who match Add(to).
     // if not present, enters a new entry with 'from's key and Delete(to) in 'adj_uncommited'.
     fn delete_edge(&mut self, from: usize, to: usize) {
-        if !self.edge_exists_updated(from, to) {
-            panic!("Edge from {} to {} doesn't exist!", from, to);
-        }
-        match self.adj_uncommitted.get_mut(&from) {
-            Some(adj) => {
-                let i: Option<usize> = adj.iter().position(|x| x == &Edit::Add(to)); // safe because we know it exists
-
-                match i {
-                    Some(_added_e) => {
-                        adj.swap_remove(i.unwrap());
-                    }
-                    None => {}
-                }
-
-                adj.push(Edit::Delete(to));
-                self.e_count_updated -= 1;
-            }
-
-            None => {
-                self.adj_uncommitted.insert(from, vec![Edit::Delete(to)]);
-                self.e_count_updated -= 1;
-            }
-        }
-
-        self.has_uncommitted_edits = true;
+        self.delete_edge_uncommitted(from, to);
+        self.commit_if_due();
     }
 
     // deletes the vertex at the given index
@@ -196,6 +697,8 @@ impl Graph<usize> for WTDigraph {
         self.deleted_vertices_uncommitted.insert(vertex, true);
 
         self.has_uncommitted_edits = true;
+        self.pending_edits += 1;
+        self.commit_if_due();
     }
 
     // checks if the given vertex exists
@@ -212,63 +715,52 @@ impl Graph<usize> for WTDigraph {
     }
 
     // returns if there is an edge from `from` to `to`
+    // counts the occurrences of `to` in the outgoing edges of `from` with two rank queries, instead of searching them
     fn edge_exists(&self, from: usize, to: usize) -> bool {
         if !(self.vertex_exists(from) && self.vertex_exists(to)) {
             return false;
         }
 
-        if self.outgoing_edges(from).contains(&to) {
-            return true;
-        }
-
-        return false;
+        return self.edge_count(from, to) > 0;
     }
 }
 impl Directed<usize> for WTDigraph {
     // return all outgoing edges of the given vertex in a vector
-    // should probably be changed to return an iterator instea
+    // use outgoing_iter to avoid the allocation
     fn outgoing_edges(&self, vertex: usize) -> Vec<usize> {
         if !self.vertex_exists(vertex) {
             panic!("outgoing_edges: Vertex {} doesn't exist.", vertex);
         }
 
-        let mut outgoing: Vec<usize> = Vec::new();
-
-        let start = self.starting_indices.select1(vertex) - vertex;
-        let end = self.starting_indices.select1(vertex + 1) - (vertex + 1);
-
-        if start > self.wt_adj.len() || start == end {
-            return Vec::new();
-        }
-
-        for i in start..end {
-            outgoing.push(self.wt_adj.get(i).unwrap()); // is it safe to unwrap here? I think it should be
-        }
-
-        return outgoing;
+        return self.outgoing_iter(vertex).collect();
     }
 
-    // return all outgoing edges of the given vertex in a vector
-    // should probably be changed to return an iterator instead
+    // return all incoming edges of the given vertex in a vector
+    // use incoming_iter to avoid the allocation
     fn incoming_edges(&self, vertex: usize) -> Vec<usize> {
         // returns a list of vertices that have outgoing edges to `vertex`
         if !self.vertex_exists(vertex) {
             panic!("incoming_edges: Vertex {} doesn't exist.", vertex);
         }
-        if self.e_count == 0 {
-            return Vec::new(); // if e_count is 0, number will result in subtract overflow
-        }
-        let mut incoming: Vec<usize> = Vec::new();
-        let number: usize = self.wt_adj.rank(vertex, self.wt_adj.len()).unwrap(); // safe to unwrap because vertex exists
 
-        for i in 1..number + 1 {
-            let index_in_wt = self.wt_adj.select(vertex, i).unwrap();
-            let pos_in_bitmap = self.starting_indices.select0(index_in_wt);
-            let incoming_edge = self.starting_indices.rank1(pos_in_bitmap) - 1;
-            incoming.push(incoming_edge);
+        return self.incoming_iter(vertex).collect();
+    }
+
+    // the gap between the vertex' 1 and the following 1 in starting_indices, i.e. two select1 calls
+    fn out_degree(&self, vertex: usize) -> usize {
+        if !self.vertex_exists(vertex) {
+            panic!("out_degree: Vertex {} doesn't exist.", vertex);
         }
+        let (start, end) = self.outgoing_range(vertex);
+        end - start
+    }
 
-        return incoming;
+    // the number of occurrences of the vertex in wt_adj, i.e. a single rank query
+    fn in_degree(&self, vertex: usize) -> usize {
+        if !self.vertex_exists(vertex) {
+            panic!("in_degree: Vertex {} doesn't exist.", vertex);
+        }
+        self.incoming_iter(vertex).len()
     }
 
     // deletes all outgoing edges of the given vertex
@@ -281,10 +773,11 @@ impl Directed<usize> for WTDigraph {
         let outgoing: Vec<usize> = self.outgoing_edges_updated(vertex);
 
         for to in outgoing {
-            self.delete_edge(vertex, to); // this function call updates e_count_updated
+            self.delete_edge_uncommitted(vertex, to); // this function call updates e_count_updated
         }
 
         self.has_uncommitted_edits = true;
+        self.commit_if_due();
     }
 
     // deletes all incoming edges of the given vertex
@@ -297,10 +790,11 @@ impl Directed<usize> for WTDigraph {
         let incoming: Vec<usize> = self.incoming_edges_updated(vertex); // empty list if there are no incoming edges
 
         for from in incoming {
-            self.delete_edge(from, vertex); // this function call updates e_count_updated
+            self.delete_edge_uncommitted(from, vertex); // this function call updates e_count_updated
         }
 
         self.has_uncommitted_edits = true;
+        self.commit_if_due();
     }
 }
 
@@ -361,6 +855,7 @@ impl Unlabeled<usize> for WTDigraph {
         }
         // apply all other changes
         self.wt_adj_len = self.v_count_updated();
+        self.e_count = sequence.len();
 
         // update deleted_vertices
         for (vertex, change) in self.deleted_vertices_uncommitted.iter() {
@@ -407,6 +902,8 @@ impl Unweighted<usize> for WTDigraph {
 
         self.has_uncommitted_edits = true;
         self.e_count_updated += 1; // added this line, else adding an edge doesn't update e_count_updated
+        self.pending_edits += 1;
+        self.commit_if_due();
     }
 }
 
@@ -414,43 +911,13 @@ impl WT<usize> for WTDigraph {
     // collect and apply all changes in adj_uncommited. rebuild QW-tree. expensive!
     // set v_count to v_count_updated, e_count to e_count_updated, if present change labels, weights [...].
     // some changes like deleted vertices are conserved
+    // copies the unchanged vertices unless commit_cost prefers a rebuild, see commit_edits_copying and commit_edits_rebuild
     fn commit_edits(&mut self) {
-        // build new sequence and bitvec
-
-        let mut sequence: Vec<usize> = Vec::new();
-        let mut bv = BitVec::new();
-
-        for v in 0..self.wt_adj_len_updated {
-            bv.append(true); // appends a 1 to mark the beginning of a new vertex
-
-            if !self.vertex_exists_updated(v) {
-                continue;
-            }
-
-            let adj: Vec<usize> = self.outgoing_edges_updated(v);
-            for i in 0..adj.len() {
-                bv.append(false); // appends a 0 to bitmap for every element in adj
-                sequence.push(adj[i]); // moves all elements of adj into sequence
-            }
-        }
-        // apply all other changes
-        self.wt_adj_len = self.wt_adj_len_updated;
-        self.e_count = self.e_count_updated;
-        // update deleted_vertices
-        for (vertex, change) in self.deleted_vertices_uncommitted.iter() {
-            if *change {
-                // i.e. if it was deleted
-                self.deleted_vertices.insert(*vertex, true);
-            } else {
-                // i.e. if it was readded
-                self.deleted_vertices.remove(vertex);
-            }
+        if self.commit_cost().prefers_rebuild() {
+            self.commit_edits_rebuild();
+        } else {
+            self.commit_edits_copying();
         }
-        self.adj_uncommitted = HashMap::new(); // reset adj_uncommitted
-        self.wt_adj = QWT256::new(&mut sequence);
-        self.starting_indices = RsVec::from_bit_vec(bv);
-
-        self.discard_edits(); // reset all uncommitted changes
     }
 
     // this function needs documentation
@@ -461,6 +928,7 @@ impl WT<usize> for WTDigraph {
         self.deleted_vertices_uncommitted = HashMap::new();
         self.adj_uncommitted = HashMap::new();
         self.has_uncommitted_edits = false;
+        self.pending_edits = 0;
     }
 
     // return true if the vertex still exists and wasn't deleted, or if it was created since since last commit.
@@ -492,10 +960,8 @@ impl WT<usize> for WTDigraph {
             return false;
         }
 
-        if self.outgoing_edges_updated(from).contains(&to) {
-            return true;
-        }
-        return false;
+        // like edge_exists, but also applies the uncommitted changes of `from`
+        return self.edge_count_updated(from, to) > 0;
     }
 
     // return the recent number of vertices in the graph
@@ -525,134 +991,277 @@ impl WT<usize> for WTDigraph {
 
 impl WTDirected<usize> for WTDigraph {
     // return all outgoing edges of the given vertex in a vector, which exist and weren't deleted, or were created since since last commit.
-    // should probably be changed to return an iterator instead
+    // use outgoing_iter_updated to avoid the allocation
     fn outgoing_edges_updated(&self, vertex: usize) -> Vec<usize> {
         if !self.vertex_exists_updated(vertex) {
             panic!("Vertex {vertex} doesn't exist!");
         }
-        let mut outgoing: Vec<usize> = Vec::new();
-        if self.vertex_exists(vertex) {
-            outgoing = self.outgoing_edges(vertex);
+
+        return self.outgoing_iter_updated(vertex).collect();
+    }
+
+    // return all incoming edges of the given vertex in a vector, which exist and weren't deleted, or were created since since last commit.
+    // use incoming_iter_updated to avoid the allocation
+    fn incoming_edges_updated(&self, vertex: usize) -> Vec<usize> {
+        // this is a very expensive function!
+        // It is strongly recommend to commit and call incoming_edges instead!
+        if !self.vertex_exists_updated(vertex) {
+            panic!("Vertex {vertex} doesn't exist!");
         }
-        let binding = Vec::new();
-        let changes: &Vec<Edit<usize>> = self.adj_uncommitted.get(&vertex).unwrap_or(&binding); // if there are no changes, this is an empty list
 
-        for change in changes {
+        return self.incoming_iter_updated(vertex).collect();
+    }
+
+    // applies the vertex' uncommitted changes to out_degree
+    fn out_degree_updated(&self, vertex: usize) -> usize {
+        if !self.vertex_exists_updated(vertex) {
+            panic!("Vertex {vertex} doesn't exist!");
+        }
+        let mut degree = 0;
+        if self.vertex_exists(vertex) {
+            degree = self.out_degree(vertex);
+        }
+        let changes: &[Edit<usize>] = match self.adj_uncommitted.get(&vertex) {
+            Some(changes) => changes,
+            None => return degree,
+        };
+        for (i, change) in changes.iter().enumerate() {
             match change {
-                Edit::Add(change) => {
-                    outgoing.push(change.clone()); // changed change to change.clone() here and 4 lines below
-                }
-                Edit::Delete(change) => {
-                    let index_of_change = outgoing.iter().position(|&x| x == change.clone()); // this returns the index of the first (and hopefully only) time, `change` appears in outgoing; panics, if change not in outgoing ... which shouldn't happen
-                    match index_of_change {
-                        Some(_) => {
-                            outgoing.remove(index_of_change.unwrap());
-                        }
-                        None => {}
+                Edit::Add(_) => degree += 1,
+                Edit::Delete(to) => {
+                    // every `to` is only subtracted once
+                    if !changes[..i].contains(change) && self.vertex_exists(vertex) {
+                        degree -= self.deleted_edge_count(vertex, *to);
                     }
                 }
             }
         }
-
-        return outgoing;
+        degree
     }
 
-    // return all incoming edges of the given vertex in a vector, which exist and weren't deleted, or were created since since last commit.
-    // should probably be changed to return an iterator instead
-    fn incoming_edges_updated(&self, vertex: usize) -> Vec<usize> {
-        // this is a very expensive function!
-        // It is strongly recommend to commit and call incoming_edges instead!
+    // applies all uncommitted changes containing the vertex to in_degree
+    fn in_degree_updated(&self, vertex: usize) -> usize {
         if !self.vertex_exists_updated(vertex) {
             panic!("Vertex {vertex} doesn't exist!");
         }
-        let mut incoming: Vec<usize> = Vec::new();
+        let mut degree = 0;
         if self.vertex_exists(vertex) {
-            incoming = self.incoming_edges(vertex);
+            degree = self.in_degree(vertex);
         }
+        for (from, changes) in self.adj_uncommitted.iter() {
+            degree += changes
+                .iter()
+                .filter(|change| **change == Edit::Add(vertex))
+                .count();
+            if self.vertex_exists(vertex) {
+                degree -= self.deleted_edge_count(*from, vertex);
+            }
+        }
+        degree
+    }
+}
 
-        // this should be an empty list, if there are no incoming edges
-
-        let mut changes: Vec<Edit<usize>> = Vec::new(); // changed from 'let mut changes: &Vec<Edit<usize>>;'
+impl TryGraph<usize> for WTDigraph {
+    // never fails for index based graphs, see add_vertex
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        Ok(self.add_vertex(vertex))
+    }
 
-        // we need to iterate over every vertex that has been changed since the last commit,
-        // unwrap every change and see whether it contains our vertex
-        for (v, v_adj) in self.adj_uncommitted.iter() {
-            for change in v_adj {
-                // check whether the change contains our `vertex`
-                match change {
-                    Edit::Add(w) => {
-                        if w == &vertex {
-                            changes.push(Edit::Add(v.clone())); // if change contains `vertex`, `vertex`'s incoming edge from v has changed
-                        };
-                    }
-                    Edit::Delete(w) => {
-                        if w == &vertex {
-                            changes.push(Edit::Delete(v.clone())); // changed v to v.clone() here and above
-                        };
-                    }
-                }
-            }
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        self.check_updated(from)?;
+        self.check_updated(to)?;
+        if !self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeNotFound);
         }
+        self.delete_edge(from, to);
+        Ok(())
+    }
 
-        // if I haven't missed anything, that should be all changes to the incoming edges
-        // now we need to apply the changes:
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.check_updated(vertex)?;
+        self.delete_vertex(vertex);
+        Ok(())
+    }
 
-        for change in changes {
-            // used binding `changes` isn't initialized ; `changes` used here but it isn't initialized
-            match change {
-                Edit::Add(change) => {
-                    incoming.push(change.clone()); // changed change to change.clone() here and 4 lines below
-                }
-                Edit::Delete(change) => {
-                    let index_of_change: usize =
-                        incoming.iter().position(|&x| x == change.clone()).unwrap(); // this returns the index of the first (and hopefully only) time, `change` appears in incoming; panics, if change not in outgoing ... which shouldn't happen
-                    incoming.remove(index_of_change);
-                }
-            }
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        self.check_committed(from)?;
+        self.check_committed(to)?;
+        Ok(self.edge_exists(from, to))
+    }
+}
+
+impl TryDirected<usize> for WTDigraph {
+    fn try_outgoing_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_committed(vertex)?;
+        Ok(self.outgoing_edges(vertex))
+    }
+
+    fn try_incoming_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_committed(vertex)?;
+        Ok(self.incoming_edges(vertex))
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.check_updated(vertex)?;
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.check_updated(vertex)?;
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+
+impl TryUnweighted<usize> for WTDigraph {
+    fn try_add_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        self.check_updated(from)?;
+        self.check_updated(to)?;
+        if self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeExists);
         }
+        self.add_edge(from, to);
+        Ok(())
+    }
+}
+
+impl TryWTDirected<usize> for WTDigraph {
+    fn try_outgoing_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_updated(vertex)?;
+        Ok(self.outgoing_edges_updated(vertex))
+    }
+
+    fn try_incoming_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.check_updated(vertex)?;
+        Ok(self.incoming_edges_updated(vertex))
+    }
+}
+
+impl GraphSearch<usize> for WTDigraph {
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<Option<ShortestPath<usize, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<ShortestPaths<usize, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl SearchView<usize> for WTDigraph {
+    type Distance = usize;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        (0..self.wt_adj_len).filter(|&v| self.vertex_exists(v)).collect()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.outgoing_edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.incoming_edges(*vertex)
+    }
+
+    fn distance(&self, _from: &usize, _to: &usize) -> usize {
+        1
+    }
+}
+
+impl TopologicalSort<usize> for WTDigraph {
+    fn topological_order(&self) -> Result<Vec<usize>, Vec<usize>> {
+        topological::topological_order(self)
+    }
+}
 
-        return incoming;
+impl WTTopologicalSort<usize> for WTDigraph {
+    fn topological_order_updated(&self) -> Result<Vec<usize>, Vec<usize>> {
+        let vertices = (0..self.wt_adj_len_updated)
+            .filter(|&v| self.vertex_exists_updated(v))
+            .collect();
+        topological::topological_order_updated(self, vertices)
     }
 }
 
-// Optional methods:
-
-// impl<L> GraphSearch for WTDigraph<L> {
-//     fn connected(&self, from: usize, to: usize) -> bool {
-//         // is a connected to b?
-//         let mut list_of_outgoing_edges: VecDeque<usize> = VecDeque::new();
-//         let mut visited: Vec<usize> = Vec::new();
-//         list_of_outgoing_edges.append(&mut self.outgoing_edges(from).into());
-//         visited.push(from);
-//         while !list_of_outgoing_edges.is_empty() {
-//             let v = list_of_outgoing_edges.pop_front().unwrap();
-//             visited.push(v);
-//             if v == to {
-//                 return true;
-//             }
-//             for item in self.outgoing_edges(v) {
-//                 if !visited.contains(&item) {
-//                     // if vertex was not yet visited, add it to the queue
-//                     if !list_of_outgoing_edges.contains(&item) {
-//                         list_of_outgoing_edges.push_back(item);
-//                     }
-//                 }
-//             }
-//         }
-//         false
-//     }
-
-//     fn shortest_path(&self, from: usize, to: usize, mode: ShortestPathAlgorithm) -> Vec<usize> {
-//         todo!()
-//     }
-
-//     fn shortest_paths(&self, mode: ShortestPathAlgorithm) -> Vec<Vec<usize>> {
-//         todo!()
-//     }
-
-//     fn connected_components(&self) -> Vec<Vec<usize>> {
-//         todo!()
-//     }
-// }
-
-// WT-Weighted Digraph - definition and methods
+impl PageRank<usize> for WTDigraph {
+    fn pagerank(&self, options: &PageRankOptions<usize>) -> Result<HashMap<usize, f64>, GraphError> {
+        pagerank::wt_pagerank(self, options, |&v| Some(v), Some)
+    }
+}
+
+impl Centrality<usize> for WTDigraph {
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl DirectedCores<usize> for WTDigraph {
+    fn in_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl Neighborhood<usize> for WTDigraph {
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(self, &u, &v, Direction::Outgoing, |&v| Some(v), Some)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(self, &u, &v, Direction::Incoming, |&v| Some(v), Some)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(self, &u, &v, Direction::Outgoing, |&v| Some(v))
+    }
+}
+
+impl WTCommitPolicy for WTDigraph {
+    fn commit_policy(&self) -> CommitPolicy {
+        self.auto_commit.policy
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.auto_commit.policy = policy;
+        self.commit_if_due();
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.auto_commit.hook = hook;
+    }
+}
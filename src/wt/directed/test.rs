@@ -270,3 +270,376 @@ fn updated_incoming_edges() {
         }
     }
 }
+#[test]
+fn try_functions() {
+    let mut dg = Digraph::new();
+    dg.add_vertex(2);
+    dg.add_edge(0, 1);
+    let mut wtdg = WTDigraph::from_digraph(dg);
+    assert_eq!(wtdg.try_edge_exists(0, 1), Ok(true));
+    assert_eq!(wtdg.try_outgoing_edges(5), Err(GraphError::VertexNotFound));
+    assert_eq!(wtdg.try_add_edge(0, 1), Err(GraphError::EdgeExists));
+    assert_eq!(wtdg.try_delete_edge(1, 0), Err(GraphError::EdgeNotFound));
+
+    let new_vertex = wtdg.append_vertex();
+    assert_eq!(wtdg.try_outgoing_edges(new_vertex), Err(GraphError::UncommittedVertex));
+    assert_eq!(wtdg.try_add_edge(new_vertex, 0), Ok(()));
+    assert_eq!(wtdg.try_outgoing_edges_updated(new_vertex), Ok(vec![0]));
+
+    // deleting an uncommitted edge mustn't panic in incoming_edges_updated
+    assert_eq!(wtdg.try_delete_edge(new_vertex, 0), Ok(()));
+    assert_eq!(wtdg.try_incoming_edges_updated(0), Ok(vec![]));
+
+    wtdg.commit_edits();
+    assert_eq!(wtdg.try_outgoing_edges(new_vertex), Ok(vec![]));
+}
+#[test]
+fn iterators() {
+    let dg = Digraph::from_adjacency_list(4, 5, vec![vec![1, 1, 2], vec![0], vec![1], vec![]]);
+    let mut wtdg = WTDigraph::from_digraph(dg);
+    assert_eq!(wtdg.outgoing_iter(0).collect::<Vec<usize>>(), vec![1, 1, 2]);
+    assert_eq!(wtdg.outgoing_iter(0).len(), 3);
+    assert_eq!(wtdg.incoming_iter(1).collect::<Vec<usize>>(), vec![0, 0, 2]);
+    assert_eq!(wtdg.outgoing_iter(3).count(), 0);
+    // 3 is bigger than every vertex in wt_adj
+    assert_eq!(wtdg.incoming_iter(3).count(), 0);
+
+    wtdg.delete_edge(0, 1); // deletes only one of the parallel edges
+    wtdg.add_edge(3, 1);
+    wtdg.add_edge(0, 3);
+    wtdg.delete_edge(0, 3);
+    wtdg.add_edge(0, 0);
+    for v in 0..4 {
+        assert_eq!(
+            wtdg.outgoing_iter_updated(v).collect::<Vec<usize>>(),
+            wtdg.outgoing_edges_updated(v)
+        );
+        assert_eq!(
+            wtdg.incoming_iter_updated(v).collect::<Vec<usize>>(),
+            wtdg.incoming_edges_updated(v)
+        );
+    }
+    assert_eq!(wtdg.outgoing_edges_updated(0), vec![1, 2, 0]);
+    assert_eq!(wtdg.incoming_edges_updated(1), vec![0, 2, 3]);
+    assert_eq!(wtdg.incoming_edges_updated(3), vec![]);
+
+    wtdg.commit_edits();
+    assert_eq!(wtdg.outgoing_iter(0).collect::<Vec<usize>>(), vec![1, 2, 0]);
+    assert_eq!(wtdg.incoming_iter(1).collect::<Vec<usize>>(), vec![0, 2, 3]);
+}
+#[test]
+fn degrees() {
+    let dg = Digraph::from_adjacency_list(4, 5, vec![vec![1, 1, 2], vec![0], vec![1], vec![]]);
+    let mut wtdg = WTDigraph::from_digraph(dg);
+    assert_eq!(wtdg.out_degree(0), 3);
+    assert_eq!(wtdg.in_degree(1), 3);
+    assert_eq!(wtdg.out_degree(3), 0);
+    assert_eq!(wtdg.in_degree(3), 0);
+
+    wtdg.delete_edge(0, 1);
+    wtdg.add_edge(3, 1);
+    wtdg.add_edge(0, 3);
+    wtdg.delete_edge(0, 3);
+    wtdg.delete_edge(0, 2);
+    wtdg.add_edge(0, 2);
+    wtdg.delete_edge(0, 2);
+    let new_vertex = wtdg.append_vertex();
+    wtdg.add_edge(new_vertex, 1);
+    for v in 0..5 {
+        assert_eq!(wtdg.out_degree_updated(v), wtdg.outgoing_edges_updated(v).len());
+        assert_eq!(wtdg.in_degree_updated(v), wtdg.incoming_edges_updated(v).len());
+    }
+    assert_eq!(wtdg.in_degree_updated(1), 4);
+
+    wtdg.commit_edits();
+    for v in 0..5 {
+        assert_eq!(wtdg.out_degree(v), wtdg.outgoing_edges(v).len());
+        assert_eq!(wtdg.in_degree(v), wtdg.incoming_edges(v).len());
+    }
+}
+#[test]
+fn edge_exists_hub() {
+    // vertex 0 is connected to every other vertex, in both directions
+    let n = 1000;
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    adj[0] = (1..n).collect();
+    for v_adj in adj.iter_mut().skip(1) {
+        v_adj.push(0);
+    }
+    let dg = Digraph::from_adjacency_list(n, 2 * (n - 1), adj);
+    let mut wtdg = WTDigraph::from_digraph(dg);
+    for v in 1..n {
+        assert!(wtdg.edge_exists(0, v));
+        assert!(wtdg.edge_exists(v, 0));
+        assert!(!wtdg.edge_exists(v, v));
+    }
+    assert!(!wtdg.edge_exists(0, 0));
+    assert!(!wtdg.edge_exists(0, n)); // doesn't exist
+
+    wtdg.delete_edge(0, 500);
+    wtdg.add_edge(500, 501);
+    assert!(!wtdg.edge_exists_updated(0, 500));
+    assert!(wtdg.edge_exists_updated(500, 501));
+    assert!(wtdg.edge_exists(0, 500));
+    assert!(!wtdg.edge_exists(500, 501));
+}
+#[test]
+fn range_queries() {
+    let adj = vec![
+        vec![1, 5, 3, 3, 7],
+        vec![0, 2],
+        vec![],
+        vec![6, 1, 2, 4, 5, 0, 7],
+        vec![3],
+        vec![5],
+        vec![0, 1],
+        vec![6],
+    ];
+    let mut wtdg = WTDigraph::from_digraph(Digraph::from_adjacency_list(8, 19, adj.clone()));
+    // compares every window with the edges in the adjacency lists,
+    // which uses rank queries for small windows and reads the edges for big ones
+    let check = |wtdg: &WTDigraph| {
+        let exists = |v: usize| wtdg.vertex_exists(v);
+        for lo in 0..=9 {
+            for hi in lo..=9 {
+                let window = lo..hi;
+                for v in (0..8).filter(|&v| exists(v)) {
+                    let mut expected: Vec<usize> = wtdg
+                        .outgoing_edges(v)
+                        .into_iter()
+                        .filter(|to| window.contains(to) && exists(*to))
+                        .collect();
+                    expected.sort_unstable();
+                    assert_eq!(wtdg.outgoing_in_range(v, window.clone()), expected);
+                    let incoming = wtdg
+                        .incoming_edges(v)
+                        .into_iter()
+                        .filter(|from| window.contains(from))
+                        .count();
+                    assert_eq!(wtdg.incoming_count_from_range(v, window.clone()), incoming);
+                }
+                for from in [0..8, 0..1, 3..4, 2..6, 5..20, 4..4] {
+                    let expected: usize = from
+                        .clone()
+                        .filter(|&v| v < 8 && exists(v))
+                        .map(|v| wtdg.outgoing_in_range(v, window.clone()).len())
+                        .sum();
+                    assert_eq!(wtdg.edge_count_between(from, window.clone()), expected);
+                }
+            }
+        }
+    };
+    check(&wtdg);
+    assert_eq!(wtdg.outgoing_in_range(0, 3..6), vec![3, 3, 5]);
+    assert_eq!(wtdg.outgoing_in_range(3, 0..100), vec![0, 1, 2, 4, 5, 6, 7]);
+    assert_eq!(wtdg.incoming_count_from_range(5, 0..4), 2);
+    assert_eq!(wtdg.incoming_count_from_range(3, 0..8), 3);
+    assert_eq!(wtdg.edge_count_between(0..4, 4..8), 6);
+    assert_eq!(wtdg.edge_count_between(0..8, 0..8), 19);
+
+    // the edges of a deleted vertex are gone, the edges to it are left out
+    wtdg.delete_vertex(3);
+    wtdg.add_edge(2, 7);
+    wtdg.commit_edits();
+    check(&wtdg);
+    assert_eq!(wtdg.outgoing_in_range(0, 3..6), vec![5]);
+    assert_eq!(wtdg.incoming_count_from_range(7, 0..3), 2);
+    assert_eq!(wtdg.edge_count_between(0..8, 0..8), 10);
+
+    // a deleted source inside the range: 3 -> 5 and 3 -> 7 count until the deletion is committed
+    let mut wtdg = WTDigraph::from_digraph(Digraph::from_adjacency_list(8, 19, adj));
+    wtdg.delete_vertex(3);
+    assert_eq!(wtdg.incoming_count_from_range(5, 2..5), 1);
+    assert_eq!(wtdg.edge_count_between(2..5, 5..8), 3);
+    wtdg.commit_edits();
+    check(&wtdg);
+    assert_eq!(wtdg.incoming_count_from_range(5, 2..5), 0);
+    assert_eq!(wtdg.incoming_count_from_range(7, 0..8), 1);
+    assert_eq!(wtdg.edge_count_between(2..5, 5..8), 0);
+    assert_eq!(wtdg.edge_count_between(0..5, 5..8), 2);
+}
+#[test]
+fn kth_and_top_k() {
+    let adj = vec![
+        vec![7, 1, 5, 3, 3],
+        vec![0, 2],
+        vec![],
+        vec![6, 1, 2, 4, 5, 0, 7],
+        vec![3],
+        vec![5],
+        vec![0, 1],
+        vec![3],
+    ];
+    let mut wtdg = WTDigraph::from_digraph(Digraph::from_adjacency_list(8, 19, adj));
+    let sorted: Vec<usize> = vec![1, 3, 3, 5, 7];
+    for (k, v) in sorted.iter().enumerate() {
+        assert_eq!(wtdg.kth_outgoing(0, k), Some(*v));
+    }
+    assert_eq!(wtdg.kth_outgoing(0, 5), None);
+    assert_eq!(wtdg.kth_outgoing(2, 0), None);
+    assert_eq!(wtdg.median_outgoing(0), Some(3));
+    assert_eq!(wtdg.median_outgoing(1), Some(0));
+    assert_eq!(wtdg.median_outgoing(3), Some(4));
+    assert_eq!(wtdg.median_outgoing(2), None);
+
+    assert_eq!(wtdg.top_k_in_degree(0..8, 2), vec![(3, 4), (0, 3)]); // 1 has 3 too
+    assert_eq!(
+        wtdg.top_k_in_degree(0..2, 10),
+        vec![(3, 2), (0, 1), (1, 1), (2, 1), (5, 1), (7, 1)]
+    );
+    assert_eq!(wtdg.top_k_in_degree(2..3, 10), vec![]);
+    assert_eq!(wtdg.top_k_in_degree(6..100, 1), vec![(0, 1)]);
+    assert_eq!(wtdg.top_k_in_degree(0..8, 0), vec![]);
+
+    // edges to deleted vertices are left out
+    wtdg.delete_vertex(3);
+    wtdg.commit_edits();
+    assert_eq!(wtdg.median_outgoing(0), Some(5));
+    assert_eq!(wtdg.kth_outgoing(0, 2), Some(7));
+    assert_eq!(wtdg.kth_outgoing(0, 3), None);
+    assert_eq!(wtdg.top_k_in_degree(0..8, 2), vec![(0, 2), (1, 2)]);
+}
+
+// the committed state of a wt-digraph: its sequence, bitmap and deleted vertices
+fn committed_state(wtdg: &WTDigraph) -> (Vec<usize>, Vec<u64>, Vec<usize>) {
+    let sequence = wtdg.wt_adj.iter().collect();
+    let bits = (0..wtdg.starting_indices.len())
+        .map(|i| wtdg.starting_indices.get_unchecked(i))
+        .collect();
+    let mut deleted: Vec<usize> = wtdg.deleted_vertices.keys().copied().collect();
+    deleted.sort_unstable();
+    (sequence, bits, deleted)
+}
+
+#[test]
+fn commit_cost() {
+    let mut dg = Digraph::new();
+    dg.add_vertex(3);
+    for (from, to) in [(0, 1), (0, 2), (1, 2), (2, 0), (3, 1)] {
+        dg.add_edge(from, to);
+    }
+    let mut wtdg = WTDigraph::from_digraph(dg);
+    wtdg.add_edge(1, 3);
+    let cost = wtdg.commit_cost();
+    assert_eq!(
+        cost,
+        CommitCost {
+            vertices: 4,
+            copied_ranges: 2,
+            copied_edges: 4,
+            rebuilt_vertices: 1,
+            rebuilt_edges: 2,
+            built_edges: 6,
+        }
+    );
+    assert!(!cost.prefers_rebuild());
+
+    let new_vertex = wtdg.append_vertex();
+    wtdg.add_edge(new_vertex, 0);
+    wtdg.delete_vertex(0);
+    let cost = wtdg.commit_cost();
+    assert_eq!(
+        cost,
+        CommitCost {
+            vertices: 5,
+            copied_ranges: 1,
+            copied_edges: 2,
+            rebuilt_vertices: 2,
+            rebuilt_edges: 3,
+            built_edges: 5,
+        }
+    );
+    assert!(!cost.prefers_rebuild());
+
+    // every vertex has changed, but deleted vertices aren't collected by either commit
+    wtdg.add_edge(2, 3);
+    wtdg.delete_edge(3, 1);
+    let cost = wtdg.commit_cost();
+    assert_eq!((cost.rebuilt_vertices, cost.copied_ranges), (4, 0));
+    assert!(!cost.prefers_rebuild());
+
+    // nothing to collect after a commit, but the QW-tree is still built from all edges
+    wtdg.commit_edits();
+    let cost = wtdg.commit_cost();
+    assert_eq!(cost.copied_edges, wtdg.wt_adj.len());
+    assert_eq!(cost.built_edges, wtdg.wt_adj.len());
+    assert_eq!((cost.rebuilt_vertices, cost.rebuilt_edges), (0, 0));
+    assert_eq!(cost.copied_ranges, 1);
+
+    // vertex 0 was deleted by the last commit, so its empty range is copied
+    wtdg.add_edge(1, 1);
+    assert_eq!(wtdg.changed_vertices(), vec![1]);
+    let cost = wtdg.commit_cost();
+    assert_eq!((cost.rebuilt_vertices, cost.rebuilt_edges), (1, 3));
+    assert_eq!(cost.copied_ranges, 2);
+    assert!(!cost.prefers_rebuild());
+
+    // the copy is split into as many ranges as the rebuild collects vertices
+    wtdg.add_edge(3, 2);
+    let cost = wtdg.commit_cost();
+    assert_eq!((cost.rebuilt_vertices, cost.copied_ranges), (2, 3));
+    assert!(cost.prefers_rebuild());
+}
+
+#[test]
+fn copying_commit() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(24);
+    let mut dg = Digraph::new();
+    dg.add_vertex(199);
+    for _ in 0..2000 {
+        dg.add_edge(rng.gen_range(0..200), rng.gen_range(0..200));
+    }
+    let mut wtdg = WTDigraph::from_digraph(dg);
+
+    for round in 0..20 {
+        let existing: Vec<usize> = (0..wtdg.wt_adj_len_updated)
+            .filter(|v| wtdg.vertex_exists_updated(*v))
+            .collect();
+        // few edits in most rounds, many in some
+        let edits = if round % 5 == 4 { 500 } else { 10 };
+        for _ in 0..edits {
+            let from = existing[rng.gen_range(0..existing.len())];
+            if !wtdg.vertex_exists_updated(from) {
+                continue;
+            }
+            match rng.gen_range(0..10) {
+                0 => wtdg.delete_vertex(from),
+                1 => {
+                    wtdg.append_vertex();
+                }
+                2..=5 => {
+                    let to = existing[rng.gen_range(0..existing.len())];
+                    if wtdg.vertex_exists_updated(to) && !wtdg.edge_exists_updated(from, to) {
+                        wtdg.add_edge(from, to);
+                    }
+                }
+                _ => {
+                    let outgoing: Vec<usize> = wtdg
+                        .outgoing_iter_updated(from)
+                        .filter(|to| wtdg.vertex_exists_updated(*to))
+                        .collect();
+                    if !outgoing.is_empty() {
+                        wtdg.delete_edge(from, outgoing[rng.gen_range(0..outgoing.len())]);
+                    }
+                }
+            }
+        }
+        // readd a deleted vertex now and then
+        if let Some(&deleted) = wtdg.deleted_vertices.keys().next() {
+            if round % 3 == 0 && !wtdg.deleted_vertices_uncommitted.contains_key(&deleted) {
+                wtdg.add_vertex(deleted);
+            }
+        }
+
+        let mut rebuilt = wtdg.clone();
+        rebuilt.commit_edits_rebuild();
+        wtdg.commit_edits_copying();
+        assert_eq!(committed_state(&wtdg), committed_state(&rebuilt));
+        assert_eq!(wtdg.e_count(), rebuilt.e_count());
+        assert_eq!(wtdg.v_count(), rebuilt.v_count());
+        assert!(!wtdg.has_uncommitted_edits);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::traits::Graph;
+use crate::wt::directed::WTDigraph;
+use crate::Edit;
+use qwt::{AccessUnsigned, RankUnsigned, SelectUnsigned};
+use std::collections::hash_map;
+use std::slice;
+
+// Iterators over the neighbors of a vertex in a wt-digraph.
+// Instead of collecting the neighbors into a new vector, they are read one by one from the QW-tree and the starting_indices bitmap.
+// The iterators are returned by WTDigraph::outgoing_iter, incoming_iter, outgoing_iter_updated and incoming_iter_updated;
+// all other wt-graphs build their iterators on top of these.
+
+// Iterates over the outgoing edges of a vertex at the last commit.
+// Each step is one access on the QW-tree.
+#[derive(Clone, Debug)]
+pub struct OutgoingIter<'a> {
+    dg: &'a WTDigraph,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> OutgoingIter<'a> {
+    // the iterator is empty if the vertex doesn't exist at the last commit
+    pub(crate) fn new(dg: &'a WTDigraph, vertex: usize) -> Self {
+        let (start, end) = if dg.vertex_exists(vertex) {
+            dg.outgoing_range(vertex)
+        } else {
+            (0, 0)
+        };
+        OutgoingIter {
+            dg,
+            pos: start,
+            end,
+        }
+    }
+}
+
+impl Iterator for OutgoingIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.pos >= self.end {
+            return None;
+        }
+        let to = self.dg.wt_adj.get(self.pos).unwrap(); // safe, pos is inside the vertex' range
+        self.pos += 1;
+        Some(to)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for OutgoingIter<'_> {}
+
+// Iterates over the incoming edges of a vertex at the last commit.
+// Each step is one select on the QW-tree and one select0 and rank1 on starting_indices.
+// Note that qwt's select currently uses two small buffers (one entry per level of the tree) internally.
+#[derive(Clone, Debug)]
+pub struct IncomingIter<'a> {
+    dg: &'a WTDigraph,
+    vertex: usize,
+    i: usize,     // the next occurrence of `vertex` in wt_adj; select is 1-based
+    count: usize, // number of occurrences of `vertex` in wt_adj
+}
+
+impl<'a> IncomingIter<'a> {
+    // the iterator is empty if the vertex doesn't exist at the last commit
+    pub(crate) fn new(dg: &'a WTDigraph, vertex: usize) -> Self {
+        let mut count = 0;
+        if dg.vertex_exists(vertex) && dg.e_count() != 0 {
+            // rank is None if `vertex` is bigger than every vertex in wt_adj, i.e. it has no incoming edges
+            count = dg.wt_adj.rank(vertex, dg.wt_adj.len()).unwrap_or(0);
+        }
+        IncomingIter {
+            dg,
+            vertex,
+            i: 1,
+            count,
+        }
+    }
+
+    // returns the next incoming edge and the position of the edge in wt_adj
+    fn next_with_position(&mut self) -> Option<(usize, usize)> {
+        if self.i > self.count {
+            return None;
+        }
+        let index_in_wt = self.dg.wt_adj.select(self.vertex, self.i).unwrap(); // safe, i <= count
+        self.i += 1;
+        let pos_in_bitmap = self.dg.starting_indices.select0(index_in_wt);
+        let from = self.dg.starting_indices.rank1(pos_in_bitmap) - 1;
+        Some((from, index_in_wt))
+    }
+}
+
+impl Iterator for IncomingIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.next_with_position().map(|(from, _)| from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.count + 1 - self.i;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for IncomingIter<'_> {}
+
+// Iterates over the outgoing edges of a vertex including the uncommitted changes.
+// Yields the same edges in the same order as outgoing_edges_updated: first the committed edges which weren't deleted,
+// then the edges added since the last commit.
+#[derive(Clone, Debug)]
+pub struct OutgoingUpdatedIter<'a> {
+    vertex: usize,
+    committed: OutgoingIter<'a>,
+    edits: slice::Iter<'a, Edit<usize>>,
+}
+
+impl<'a> OutgoingUpdatedIter<'a> {
+    pub(crate) fn new(dg: &'a WTDigraph, vertex: usize) -> Self {
+        let edits: &[Edit<usize>] = match dg.adj_uncommitted.get(&vertex) {
+            Some(edits) => edits,
+            None => &[],
+        };
+        OutgoingUpdatedIter {
+            vertex,
+            committed: OutgoingIter::new(dg, vertex),
+            edits: edits.iter(),
+        }
+    }
+}
+
+impl Iterator for OutgoingUpdatedIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.committed.pos < self.committed.end {
+            let pos = self.committed.pos;
+            let to = self.committed.next().unwrap(); // safe, pos < end
+            if !deleted_since_commit(self.committed.dg, self.vertex, to, pos) {
+                return Some(to);
+            }
+        }
+        for change in self.edits.by_ref() {
+            if let Edit::Add(to) = change {
+                return Some(*to);
+            }
+        }
+        None
+    }
+}
+
+// Iterates over the incoming edges of a vertex including the uncommitted changes.
+// Yields the same edges in the same order as incoming_edges_updated: first the committed edges which weren't deleted,
+// then the edges added since the last commit. Like incoming_edges_updated, this has to look at every vertex with uncommitted changes.
+#[derive(Clone, Debug)]
+pub struct IncomingUpdatedIter<'a> {
+    vertex: usize,
+    committed: IncomingIter<'a>,
+    uncommitted: hash_map::Iter<'a, usize, Vec<Edit<usize>>>,
+    current: Option<(usize, slice::Iter<'a, Edit<usize>>)>, // the vertex whose changes are being searched and its remaining changes
+}
+
+impl<'a> IncomingUpdatedIter<'a> {
+    pub(crate) fn new(dg: &'a WTDigraph, vertex: usize) -> Self {
+        IncomingUpdatedIter {
+            vertex,
+            committed: IncomingIter::new(dg, vertex),
+            uncommitted: dg.adj_uncommitted.iter(),
+            current: None,
+        }
+    }
+}
+
+impl Iterator for IncomingUpdatedIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while let Some((from, pos)) = self.committed.next_with_position() {
+            if !deleted_since_commit(self.committed.dg, from, self.vertex, pos) {
+                return Some(from);
+            }
+        }
+        loop {
+            if let Some((from, changes)) = &mut self.current {
+                for change in changes.by_ref() {
+                    if change == &Edit::Add(self.vertex) {
+                        return Some(*from);
+                    }
+                }
+            }
+            let (from, changes) = self.uncommitted.next()?;
+            self.current = Some((*from, changes.iter()));
+        }
+    }
+}
+
+// returns true if the committed edge from `from` to `to` at position `pos` in wt_adj was deleted since the last commit.
+// every Delete(to) in the changes of `from` removes one edge to `to`, starting with the first one,
+// so this only needs to count the edges to `to` in front of `pos`, which are two rank queries.
+fn deleted_since_commit(dg: &WTDigraph, from: usize, to: usize, pos: usize) -> bool {
+    let deletions = match dg.adj_uncommitted.get(&from) {
+        Some(changes) => changes
+            .iter()
+            .filter(|change| **change == Edit::Delete(to))
+            .count(),
+        None => 0,
+    };
+    if deletions == 0 {
+        return false;
+    }
+    let (start, _) = dg.outgoing_range(from);
+    let edges_in_front = dg.wt_adj.rank(to, pos).unwrap() - dg.wt_adj.rank(to, start).unwrap(); // safe, `to` is in wt_adj
+    edges_in_front < deletions
+}
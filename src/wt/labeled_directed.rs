@@ -1,7 +1,22 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::pagerank;
+use crate::algo::search::{self, SearchView};
+use crate::algo::topological;
 use crate::graph::labeled_directed::LabeledDigraph;
 use crate::traits::{Directed, Graph, Labeled, Unlabeled, Unweighted, WTDirected, WTLabeled, WT};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::{TopologicalSort, WTTopologicalSort};
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{TryDirected, TryGraph, TryLabeled, TryUnweighted, TryWTDirected};
 use crate::wt::directed::WTDigraph;
-use crate::Edit;
+use crate::wt::policy::AutoCommit;
+use crate::{Edit, GraphError};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -23,10 +38,12 @@ where
     L: Hash + Clone + Eq,
 {
     pub(crate) dg: WTDigraph,
-    index_label: Vec<L>,
-    index_label_uncommitted: HashMap<usize, Edit<L>>, // this only works with a HashMap
-    label_index: HashMap<L, usize>, // changed from label_index: HashMap<L, Edit<usize>>,
-    label_index_uncommitted: HashMap<L, Edit<usize>>, // changed from label_index_uncommitted: HashMap<L, usize>,
+    pub(crate) index_label: Vec<L>,
+    pub(crate) index_label_uncommitted: HashMap<usize, Edit<L>>, // this only works with a HashMap
+    pub(crate) label_index: HashMap<L, usize>, // changed from label_index: HashMap<L, Edit<usize>>,
+    pub(crate) label_index_uncommitted: HashMap<L, Edit<usize>>, // changed from label_index_uncommitted: HashMap<L, usize>,
+    #[serde(skip)]
+    pub(crate) auto_commit: AutoCommit,
 }
 
 impl<L> LabeledWTDigraph<L>
@@ -40,6 +57,7 @@ where
             label_index: ldg.label_index,
             label_index_uncommitted: HashMap::new(),
             dg: WTDigraph::from_digraph(ldg.dg),
+            auto_commit: AutoCommit::default(),
         };
     }
     pub fn from(sequence: Vec<usize>, starting_indices: RsVec, labels: Vec<L>) -> Self {
@@ -54,8 +72,77 @@ where
             index_label_uncommitted: HashMap::new(),
             label_index: label_index,
             label_index_uncommitted: HashMap::new(),
+            auto_commit: AutoCommit::default(),
         };
     }
+
+    // returns an iterator over the labels of the outgoing edges of the given vertex, see WTDigraph::outgoing_iter.
+    // panics if the vertex doesn't exist
+    pub fn outgoing_iter(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        let vertex_index = self.index(vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg outgoing_iter : Vertex doesn't exist");
+        }
+        self.dg
+            .outgoing_iter(vertex_index.unwrap())
+            .map(|item| self.label(item).unwrap())
+    }
+
+    // returns an iterator over the labels of the incoming edges of the given vertex, see WTDigraph::incoming_iter.
+    // panics if the vertex doesn't exist
+    pub fn incoming_iter(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        let vertex_index = self.index(vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg incoming_iter : Vertex doesn't exist");
+        }
+        self.dg
+            .incoming_iter(vertex_index.unwrap())
+            .map(|item| self.label(item).unwrap())
+    }
+
+    // returns an iterator over the labels of the outgoing edges of the given vertex including the uncommitted changes,
+    // see WTDigraph::outgoing_iter_updated.
+    // panics if the vertex doesn't exist
+    pub fn outgoing_iter_updated(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        let vertex_index = self.index_updated(vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg outgoing_iter_updated : Vertex doesn't exist");
+        }
+        self.dg
+            .outgoing_iter_updated(vertex_index.unwrap())
+            .map(|item| self.label_updated(item).unwrap())
+    }
+
+    // returns an iterator over the labels of the incoming edges of the given vertex including the uncommitted changes,
+    // see WTDigraph::incoming_iter_updated.
+    // panics if the vertex doesn't exist
+    pub fn incoming_iter_updated(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        let vertex_index = self.index_updated(vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg incoming_iter_updated : Vertex doesn't exist");
+        }
+        self.dg
+            .incoming_iter_updated(vertex_index.unwrap())
+            .map(|item| self.label_updated(item).unwrap())
+    }
+
+    // resolves `label` to the index of a vertex existing at the last commit,
+    // telling apart labels which only exist in the uncommitted changes
+    pub(crate) fn committed_index(&self, label: &L) -> Result<usize, GraphError> {
+        match self.index(label) {
+            Some(index) if self.dg.vertex_exists(index) => Ok(index),
+            _ if self.index_updated(label).is_some() => Err(GraphError::UncommittedVertex),
+            _ => Err(GraphError::VertexNotFound),
+        }
+    }
+
+    // resolves `label` to the index of a vertex existing including the uncommitted changes
+    pub(crate) fn updated_index(&self, label: &L) -> Result<usize, GraphError> {
+        match self.index_updated(label) {
+            Some(index) if self.dg.vertex_exists_updated(index) => Ok(index),
+            _ => Err(GraphError::VertexNotFound),
+        }
+    }
 }
 impl<L> Graph<L> for LabeledWTDigraph<L>
 where
@@ -73,6 +160,7 @@ where
             .insert(index, Edit::Add(vertex));
         // need to return v_count_updated -1, but field is private
         // it no longer is -Simon
+        self.commit_if_due();
         return self.dg.wt_adj_len_updated - 1;
     }
 
@@ -85,17 +173,8 @@ where
     }
 
     fn delete_edge(&mut self, from: L, to: L) {
-        let from_index = self.index_updated(&from);
-        let to_index = self.index_updated(&to);
-        if from_index.is_none() {
-            panic!("wtldg edge_exists : from Vertex doesn't exist")
-        }
-        if to_index.is_none() {
-            panic!("wtldg edge_exists : to Vertex doesn't exist")
-        }
-        let from_index = from_index.unwrap();
-        let to_index = to_index.unwrap();
-        self.dg.delete_edge(from_index, to_index);
+        self.delete_edge_uncommitted(from, to);
+        self.commit_if_due();
     }
 
     fn delete_vertex(&mut self, vertex: L) {
@@ -111,6 +190,7 @@ where
             .insert(vertex_index, Edit::Delete(vertex.clone()));
         self.label_index_uncommitted
             .insert(vertex, Edit::Delete(vertex_index));
+        self.commit_if_due();
     }
 
     fn vertex_exists(&self, vertex: L) -> bool {
@@ -124,8 +204,8 @@ where
     }
 
     fn edge_exists(&self, from: L, to: L) -> bool {
-        let from_index = self.index_updated(&from);
-        let to_index = self.index_updated(&to);
+        let from_index = self.index(&from);
+        let to_index = self.index(&to);
         if from_index.is_none() {
             panic!("wtldg edge_exists : from Vertex doesn't exist")
         }
@@ -176,6 +256,7 @@ where
         }
         let vertex_index = vertex_index.unwrap();
         self.dg.delete_outgoing_edges(vertex_index);
+        self.commit_if_due();
     }
 
     fn delete_incoming_edges(&mut self, vertex: L) {
@@ -185,6 +266,23 @@ where
         }
         let vertex_index = vertex_index.unwrap();
         self.dg.delete_incoming_edges(vertex_index);
+        self.commit_if_due();
+    }
+
+    fn out_degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg out_degree : Vertex doesn't exist");
+        }
+        self.dg.out_degree(vertex_index.unwrap())
+    }
+
+    fn in_degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg in_degree : Vertex doesn't exist");
+        }
+        self.dg.in_degree(vertex_index.unwrap())
     }
 }
 impl<L> Labeled<L> for LabeledWTDigraph<L>
@@ -283,6 +381,7 @@ where
         let to_index = to_index.unwrap();
 
         self.dg.add_edge(from_index, to_index);
+        self.commit_if_due();
     }
 }
 impl<L> WT<L> for LabeledWTDigraph<L>
@@ -404,6 +503,22 @@ where
         }
         return incoming_edges_updated_labeled;
     }
+
+    fn out_degree_updated(&self, vertex: L) -> usize {
+        let vertex_index = self.index_updated(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg out_degree_updated : Vertex doesn't exist");
+        }
+        self.dg.out_degree_updated(vertex_index.unwrap())
+    }
+
+    fn in_degree_updated(&self, vertex: L) -> usize {
+        let vertex_index = self.index_updated(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtldg in_degree_updated : Vertex doesn't exist");
+        }
+        self.dg.in_degree_updated(vertex_index.unwrap())
+    }
 }
 
 impl<L> WTLabeled<L> for LabeledWTDigraph<L>
@@ -446,3 +561,303 @@ where
         return self.index(label);
     }
 }
+
+impl<L> TryGraph<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        if self.index_updated(&vertex).is_some() {
+            return Err(GraphError::LabelExists);
+        }
+        Ok(self.add_vertex(vertex))
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.updated_index(&from)?;
+        let to_index = self.updated_index(&to)?;
+        self.dg.try_delete_edge(from_index, to_index)
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.updated_index(&vertex)?;
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let from_index = self.committed_index(&from)?;
+        let to_index = self.committed_index(&to)?;
+        Ok(self.dg.edge_exists(from_index, to_index))
+    }
+}
+
+impl<L> TryDirected<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_outgoing_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.committed_index(&vertex)?;
+        Ok(self.outgoing_edges(vertex))
+    }
+
+    fn try_incoming_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.committed_index(&vertex)?;
+        Ok(self.incoming_edges(vertex))
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        let vertex_index = self.updated_index(&vertex)?;
+        self.dg.try_delete_outgoing_edges(vertex_index)
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        let vertex_index = self.updated_index(&vertex)?;
+        self.dg.try_delete_incoming_edges(vertex_index)
+    }
+}
+
+impl<L> TryLabeled<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.updated_index(&old_label)?;
+        if self.index_updated(&new_label).is_some() {
+            return Err(GraphError::LabelExists);
+        }
+        self.edit_label(old_label, new_label);
+        Ok(())
+    }
+}
+
+impl<L> TryUnweighted<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.updated_index(&from)?;
+        let to_index = self.updated_index(&to)?;
+        self.dg.try_add_edge(from_index, to_index)
+    }
+}
+
+impl<L> TryWTDirected<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_outgoing_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.updated_index(&vertex)?;
+        Ok(self.outgoing_edges_updated(vertex))
+    }
+
+    fn try_incoming_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.updated_index(&vertex)?;
+        Ok(self.incoming_edges_updated(vertex))
+    }
+}
+
+impl<L> GraphSearch<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<Option<ShortestPath<L, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<ShortestPaths<L, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<HashMap<L, ShortestPaths<L, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L> SearchView<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = false;
+
+    // the labels of the existing vertices, by index
+    fn search_vertices(&self) -> Vec<L> {
+        self.dg
+            .search_vertices()
+            .into_iter()
+            .filter_map(|v| self.label(v).cloned())
+            .collect()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.outgoing_edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.incoming_edges(vertex.clone())
+    }
+
+    fn distance(&self, _from: &L, _to: &L) -> usize {
+        1
+    }
+}
+
+impl<L> TopologicalSort<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn topological_order(&self) -> Result<Vec<L>, Vec<L>> {
+        topological::topological_order(self)
+    }
+}
+
+impl<L> WTTopologicalSort<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn topological_order_updated(&self) -> Result<Vec<L>, Vec<L>> {
+        let vertices = (0..self.dg.wt_adj_len_updated)
+            .filter(|&v| self.dg.vertex_exists_updated(v))
+            .filter_map(|v| self.label_updated(v).cloned())
+            .collect();
+        topological::topological_order_updated(self, vertices)
+    }
+}
+
+impl<L> PageRank<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn pagerank(&self, options: &PageRankOptions<L>) -> Result<HashMap<L, f64>, GraphError> {
+        pagerank::wt_pagerank(&self.dg, options, |l| self.index(l), |v| {
+            self.label(v).cloned()
+        })
+    }
+}
+
+impl<L> Centrality<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L> DirectedCores<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn in_core_numbers(&self) -> HashMap<L, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<L, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<L> Neighborhood<L> for LabeledWTDigraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.dg,
+            &u,
+            &v,
+            Direction::Outgoing,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.dg,
+            &u,
+            &v,
+            Direction::Incoming,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.dg, &u, &v, Direction::Outgoing, |l| self.index(l))
+    }
+}
+
+impl<L> LabeledWTDigraph<L>
+where
+    L: Hash + Clone + Eq,
+{
+    // deletes the edge like delete_edge, but doesn't commit. see WTDigraph::delete_edge_uncommitted
+    pub(crate) fn delete_edge_uncommitted(&mut self, from: L, to: L) {
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
+        if from_index.is_none() {
+            panic!("wtldg edge_exists : from Vertex doesn't exist")
+        }
+        if to_index.is_none() {
+            panic!("wtldg edge_exists : to Vertex doesn't exist")
+        }
+        let from_index = from_index.unwrap();
+        let to_index = to_index.unwrap();
+        self.dg.delete_edge(from_index, to_index);
+    }
+
+    // commits the edits if the commit policy calls for it. see WTDigraph::commit_if_due
+    pub(crate) fn commit_if_due(&mut self) {
+        if let Some(event) = self.auto_commit.due(&self.dg) {
+            self.commit_edits();
+            self.auto_commit.notify(&event);
+        }
+    }
+}
+
+impl<L> WTCommitPolicy for LabeledWTDigraph<L>
+where
+    L: Hash + Clone + Eq,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.auto_commit.policy
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.auto_commit.policy = policy;
+        self.commit_if_due();
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.auto_commit.hook = hook;
+    }
+}
@@ -1,6 +1,9 @@
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use crate::traits::*;
+use crate::graph::labeled_directed::LabeledDigraph;
+use crate::wt::labeled_directed::LabeledWTDigraph;
+use crate::GraphError;
 
 
 #[test]
@@ -478,3 +481,25 @@ fn vertex_exists() {
     }
 }
 
+#[test]
+fn try_functions() {
+    let ldg = LabeledDigraph::from_adjacency_list(
+        2,
+        1,
+        vec![vec![1], vec![]],
+        vec!["a".to_string(), "b".to_string()],
+    );
+    let mut wtldg = LabeledWTDigraph::from_labeled_digraph(ldg);
+    let (a, b, c) = ("a".to_string(), "b".to_string(), "c".to_string());
+    assert_eq!(wtldg.try_outgoing_edges(a.clone()), Ok(vec![b.clone()]));
+    assert_eq!(wtldg.try_add_vertex(a.clone()), Err(GraphError::LabelExists));
+    assert_eq!(wtldg.try_edit_label(a.clone(), b.clone()), Err(GraphError::LabelExists));
+    assert_eq!(wtldg.try_edge_exists(c.clone(), a.clone()), Err(GraphError::VertexNotFound));
+
+    assert_eq!(wtldg.try_add_vertex(c.clone()), Ok(2));
+    assert_eq!(wtldg.try_incoming_edges(c.clone()), Err(GraphError::UncommittedVertex));
+    assert_eq!(wtldg.try_add_edge(c.clone(), a.clone()), Ok(()));
+    assert_eq!(wtldg.try_incoming_edges_updated(a.clone()), Ok(vec![c.clone()]));
+    assert_eq!(wtldg.try_delete_vertex(c.clone()), Ok(()));
+    assert_eq!(wtldg.try_delete_vertex(c.clone()), Err(GraphError::VertexNotFound));
+}
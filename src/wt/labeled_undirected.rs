@@ -1,10 +1,26 @@
 use vers_vecs::RsVec;
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
+use crate::algo::triangles;
 use crate::graph::labeled_undirected::LabeledUGraph;
 use crate::traits::{
     Directed, Graph, Labeled, Undirected, Unweighted, WTDirected, WTLabeled, WTUndirected, WT,
 };
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{TryGraph, TryLabeled, TryUndirected, TryUnweighted, TryWTUndirected};
 use crate::wt::labeled_directed::LabeledWTDigraph;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::hash::Hash;
 
 #[cfg(test)]
@@ -22,7 +38,7 @@ pub struct LabeledWTUGraph<L>
 where
     L: Hash + Eq + Clone,
 {
-    ldg: LabeledWTDigraph<L>,
+    pub(crate) ldg: LabeledWTDigraph<L>,
 }
 
 impl<L> LabeledWTUGraph<L>
@@ -39,25 +55,28 @@ where
             ldg: LabeledWTDigraph::from(sequence, starting_indices, labels),
         };
     }
-}
-impl<L> Graph<L> for LabeledWTUGraph<L>
-where
-    L: Hash + Eq + Clone,
-{
-    // this function needs documentation
-    fn add_vertex(&mut self, vertex: L) -> usize {
-        return self.ldg.add_vertex(vertex);
-    }
 
-    fn e_count(&self) -> usize {
-        return self.ldg.e_count();
+    // returns an iterator over the labels of all edges of the given vertex, see WTUGraph::neighbors_iter.
+    // panics if the vertex doesn't exist
+    pub fn neighbors_iter<'a>(&'a self, vertex: &'a L) -> impl Iterator<Item = &'a L> + 'a {
+        self.ldg
+            .incoming_iter(vertex)
+            .chain(self.ldg.outgoing_iter(vertex).filter(move |item| *item != vertex))
     }
 
-    fn v_count(&self) -> usize {
-        return self.ldg.v_count();
+    // returns an iterator over the labels of all edges of the given vertex including the uncommitted changes,
+    // see WTUGraph::neighbors_iter_updated.
+    // panics if the vertex doesn't exist
+    pub fn neighbors_iter_updated<'a>(&'a self, vertex: &'a L) -> impl Iterator<Item = &'a L> + 'a {
+        self.ldg.incoming_iter_updated(vertex).chain(
+            self.ldg
+                .outgoing_iter_updated(vertex)
+                .filter(move |item| *item != vertex),
+        )
     }
 
-    fn delete_edge(&mut self, from: L, to: L) {
+    // deletes the edge like delete_edge, but doesn't commit. see WTDigraph::delete_edge_uncommitted
+    fn delete_edge_uncommitted(&mut self, from: L, to: L) {
         // fixme
         // does check twice if from and to is valid
         let from_index = self.index_updated(&from);
@@ -71,11 +90,33 @@ where
         let from_index = from_index.unwrap();
         let to_index = to_index.unwrap();
         if from_index <= to_index {
-            self.ldg.delete_edge(from, to);
+            self.ldg.delete_edge_uncommitted(from, to);
         } else {
-            self.ldg.delete_edge(to, from);
+            self.ldg.delete_edge_uncommitted(to, from);
         }
     }
+}
+impl<L> Graph<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    // this function needs documentation
+    fn add_vertex(&mut self, vertex: L) -> usize {
+        return self.ldg.add_vertex(vertex);
+    }
+
+    fn e_count(&self) -> usize {
+        return self.ldg.e_count();
+    }
+
+    fn v_count(&self) -> usize {
+        return self.ldg.v_count();
+    }
+
+    fn delete_edge(&mut self, from: L, to: L) {
+        self.delete_edge_uncommitted(from, to);
+        self.ldg.commit_if_due();
+    }
 
     fn delete_vertex(&mut self, vertex: L) {
         self.ldg.delete_vertex(vertex)
@@ -133,8 +174,17 @@ where
 
     fn delete_edges_from(&mut self, vertex: L) {
         for item in self.edges_updated(vertex.clone()) {
-            self.delete_edge(vertex.clone(), item);
+            self.delete_edge_uncommitted(vertex.clone(), item);
+        }
+        self.ldg.commit_if_due();
+    }
+
+    fn degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtlug degree : Vertex doesn't exist");
         }
+        self.ldg.dg.undirected_degree(vertex_index.unwrap())
     }
 }
 impl<L> Labeled<L> for LabeledWTUGraph<L>
@@ -165,8 +215,8 @@ where
     fn add_edge(&mut self, from: L, to: L) {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlug add_edge : from Vertex doesn't exist")
         }
@@ -206,8 +256,8 @@ where
     fn edge_exists_updated(&self, from: L, to: L) -> bool {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlug edge_exists : from Vertex doesn't exist")
         }
@@ -236,7 +286,7 @@ where
     L: Hash + Eq + Clone,
 {
     fn edges_updated(&self, vertex: L) -> Vec<L> {
-        let vertex_index = self.index(&vertex);
+        let vertex_index = self.index_updated(&vertex);
         if vertex_index.is_none() {
             panic!("wtlug edges : Vertex doesn't exist");
         }
@@ -255,6 +305,14 @@ where
             return edges;
         }
     }
+
+    fn degree_updated(&self, vertex: L) -> usize {
+        let vertex_index = self.index_updated(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtlug degree_updated : Vertex doesn't exist");
+        }
+        self.ldg.dg.undirected_degree_updated(vertex_index.unwrap())
+    }
 }
 impl<L> WTLabeled<L> for LabeledWTUGraph<L>
 where
@@ -268,3 +326,245 @@ where
         return self.ldg.index_updated(label);
     }
 }
+
+impl<L> TryGraph<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.ldg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if from_index <= to_index {
+            self.ldg.try_delete_edge(from, to)
+        } else {
+            self.ldg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let from_index = self.ldg.committed_index(&from)?;
+        let to_index = self.ldg.committed_index(&to)?;
+        if from_index <= to_index {
+            self.ldg.try_edge_exists(from, to)
+        } else {
+            self.ldg.try_edge_exists(to, from)
+        }
+    }
+}
+
+impl<L> TryUndirected<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.committed_index(&vertex)?;
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.updated_index(&vertex)?;
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+
+impl<L> TryLabeled<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.ldg.try_edit_label(old_label, new_label)
+    }
+}
+
+impl<L> TryUnweighted<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_add_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if from_index <= to_index {
+            self.ldg.try_add_edge(from, to)
+        } else {
+            self.ldg.try_add_edge(to, from)
+        }
+    }
+}
+
+impl<L> TryWTUndirected<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn try_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.updated_index(&vertex)?;
+        Ok(self.edges_updated(vertex))
+    }
+}
+
+impl<L> GraphSearch<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<Option<ShortestPath<L, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<ShortestPaths<L, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, usize>,
+    ) -> Result<HashMap<L, ShortestPaths<L, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L> SearchView<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    type Distance = usize;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.ldg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn distance(&self, _from: &L, _to: &L) -> usize {
+        1
+    }
+}
+
+impl<L> Triangles<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn triangle_counts(&self) -> TriangleCounts<L> {
+        triangles::wt_triangle_counts(&self.ldg.dg, |v| self.label(v).cloned())
+    }
+}
+
+impl<L> Centrality<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L> Cores<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn core_numbers(&self) -> HashMap<L, usize> {
+        cores::wt_core_numbers(&self.ldg.dg, |v| self.label(v).cloned())
+    }
+
+    fn degeneracy_order(&self) -> Vec<L> {
+        cores::wt_degeneracy_order(&self.ldg.dg, |v| self.label(v).cloned())
+    }
+}
+
+impl<L> Biconnectivity<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<L> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<L> Neighborhood<L> for LabeledWTUGraph<L>
+where
+    L: Hash + Eq + Clone,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.ldg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.ldg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.ldg.dg, &u, &v, Direction::Undirected, |l| {
+            self.index(l)
+        })
+    }
+}
+
+// the policy belongs to the wrapped wt-graph, which commits everything of this graph
+impl<L> WTCommitPolicy for LabeledWTUGraph<L>
+where
+    L: Hash + Clone + Eq,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.ldg.commit_policy()
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.ldg.set_commit_policy(policy);
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.ldg.set_commit_hook(hook);
+    }
+}
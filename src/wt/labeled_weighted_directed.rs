@@ -1,10 +1,23 @@
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
 use crate::graph::labeled_weighted_directed::LabeledWeightedDigraph;
 use crate::traits::{
     Directed, Graph, Labeled, Unweighted, WTDirected, WTLabeled, WTWeighted, Weighted, WT,
 };
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::{TopologicalSort, WTTopologicalSort};
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{TryDirected, TryGraph, TryLabeled, TryWTDirected, TryWTWeighted, TryWeighted};
 use crate::wt::labeled_directed::LabeledWTDigraph;
-use crate::Edit;
-use num::Num;
+use crate::wt::policy::AutoCommit;
+use crate::{Edit, GraphError};
+use num::{Num, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::Hash;
@@ -22,9 +35,11 @@ where
     L: Hash + Clone + Eq,
     W: Num,
 {
-    ldg: LabeledWTDigraph<L>,
-    weights_uncommitted: HashMap<(usize, usize), Edit<W>>,
-    weights: HashMap<(usize, usize), W>,
+    pub(crate) ldg: LabeledWTDigraph<L>,
+    pub(crate) weights_uncommitted: HashMap<(usize, usize), Edit<W>>,
+    pub(crate) weights: HashMap<(usize, usize), W>,
+    #[serde(skip)]
+    pub(crate) auto_commit: AutoCommit,
 }
 impl<L, W> LabeledWeightedWTDigraph<L, W>
 where
@@ -36,6 +51,7 @@ where
             ldg: LabeledWTDigraph::from_labeled_digraph(lwdg.ldg),
             weights_uncommitted: HashMap::new(),
             weights: lwdg.weights,
+            auto_commit: AutoCommit::default(),
         };
     }
 
@@ -49,8 +65,45 @@ where
             ldg: LabeledWTDigraph::from(sequence, starting_indices, labels),
             weights_uncommitted: HashMap::new(),
             weights,
+            auto_commit: AutoCommit::default(),
         };
     }
+
+    // see LabeledWTDigraph::outgoing_iter
+    pub fn outgoing_iter(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        self.ldg.outgoing_iter(vertex)
+    }
+
+    // see LabeledWTDigraph::incoming_iter
+    pub fn incoming_iter(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        self.ldg.incoming_iter(vertex)
+    }
+
+    // see LabeledWTDigraph::outgoing_iter_updated
+    pub fn outgoing_iter_updated(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        self.ldg.outgoing_iter_updated(vertex)
+    }
+
+    // see LabeledWTDigraph::incoming_iter_updated
+    pub fn incoming_iter_updated(&self, vertex: &L) -> impl Iterator<Item = &L> + '_ {
+        self.ldg.incoming_iter_updated(vertex)
+    }
+
+    // returns the weight of the edge at the last commit, or None if no weight is stored for it.
+    // unlike weight, this doesn't check that the edge exists
+    pub(crate) fn committed_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.weights.get(&(from, to))
+    }
+
+    // returns the weight of the edge including the uncommitted changes, or None if no weight is stored for it.
+    // unlike weight_updated, this doesn't check that the edge exists
+    pub(crate) fn updated_weight(&self, from: usize, to: usize) -> Option<&W> {
+        match self.weights_uncommitted.get(&(from, to)) {
+            Some(Edit::Add(weight)) => Some(weight),
+            Some(Edit::Delete(_)) => None,
+            None => self.weights.get(&(from, to)),
+        }
+    }
 }
 
 impl<L, W> Graph<L> for LabeledWeightedWTDigraph<L, W>
@@ -59,7 +112,9 @@ where
     W: Clone + Num,
 {
     fn add_vertex(&mut self, vertex: L) -> usize {
-        self.ldg.add_vertex(vertex)
+        let index = self.ldg.add_vertex(vertex);
+        self.commit_if_due();
+        index
     }
 
     fn e_count(&self) -> usize {
@@ -71,35 +126,27 @@ where
     }
 
     fn delete_edge(&mut self, from: L, to: L) {
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
-        if from_index.is_none() {
-            panic!("lwdg delete_edge : from Vertex doesn't exist");
-        }
-        if to_index.is_none() {
-            panic!("lwdg delete_edge : to Vertex doesn't exist");
-        }
-        let from_index = from_index.unwrap();
-        let to_index = to_index.unwrap();
-
-        self.ldg.delete_edge(from.clone(), to.clone());
-        let weight = self.weight(from, to); // checkme fixme seems ugly this way
-        self.weights_uncommitted
-            .insert((from_index, to_index), Edit::Delete(weight));
+        self.delete_edge_uncommitted(from, to);
+        self.commit_if_due();
     }
 
     fn delete_vertex(&mut self, vertex: L) {
-        let vertex_index = self.index(&vertex);
+        let vertex_index = self.index_updated(&vertex);
         if vertex_index.is_none() {
             panic!("lwdg delete_vertex : Vertex doesn't exist");
         }
-        if !self.vertex_exists(vertex.clone()) {
+        if !self.vertex_exists_updated(vertex.clone()) {
             // todo
             panic!("lwdg delete_vertex : Vertex doesn't exist");
         }
-        self.delete_incoming_edges(vertex.clone());
-        self.delete_outgoing_edges(vertex.clone());
+        for from in self.incoming_edges_updated(vertex.clone()) {
+            self.delete_edge_uncommitted(from, vertex.clone());
+        }
+        for to in self.outgoing_edges_updated(vertex.clone()) {
+            self.delete_edge_uncommitted(vertex.clone(), to);
+        }
         self.ldg.delete_vertex(vertex);
+        self.commit_if_due();
     }
 
     fn vertex_exists(&self, vertex: L) -> bool {
@@ -125,14 +172,24 @@ where
 
     fn delete_outgoing_edges(&mut self, vertex: L) {
         for to in self.outgoing_edges_updated(vertex.clone()) {
-            self.delete_edge(vertex.clone(), to);
+            self.delete_edge_uncommitted(vertex.clone(), to);
         }
+        self.commit_if_due();
     }
 
     fn delete_incoming_edges(&mut self, vertex: L) {
         for from in self.incoming_edges_updated(vertex.clone()) {
-            self.delete_edge(from, vertex.clone());
+            self.delete_edge_uncommitted(from, vertex.clone());
         }
+        self.commit_if_due();
+    }
+
+    fn out_degree(&self, vertex: L) -> usize {
+        self.ldg.out_degree(vertex)
+    }
+
+    fn in_degree(&self, vertex: L) -> usize {
+        self.ldg.in_degree(vertex)
     }
 }
 impl<L, W> Labeled<L> for LabeledWeightedWTDigraph<L, W>
@@ -162,8 +219,8 @@ where
     W: Clone + Num,
 {
     fn add_edge(&mut self, from: L, to: L, weight: W) {
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("lwdg add_edge : from Vertex doesn't exist");
         }
@@ -176,11 +233,12 @@ where
         self.ldg.add_edge(from, to);
         self.weights_uncommitted
             .insert((from_index, to_index), Edit::Add(weight));
+        self.commit_if_due();
     }
 
     fn edit_weight(&mut self, from: L, to: L, weight: W) {
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("lwdg edit_weight : from Vertex doesn't exist");
         }
@@ -196,7 +254,7 @@ where
             .insert((from_index, to_index), Edit::Add(weight));
     }
 
-    fn weight(&mut self, from: L, to: L) -> W {
+    fn weight(&self, from: L, to: L) -> W {
         let from_index = self.index(&from);
         let to_index = self.index(&to);
         if from_index.is_none() {
@@ -207,6 +265,9 @@ where
         }
         let from_index = from_index.unwrap();
         let to_index = to_index.unwrap();
+        if !self.ldg.dg.edge_exists(from_index, to_index) {
+            panic!("lwdg get_weight : Edge doesn't exist");
+        }
 
         return self.weights.get(&(from_index, to_index)).unwrap().clone();
     }
@@ -260,7 +321,7 @@ where
     L: Hash + Clone + Eq,
     W: Clone + Num,
 {
-    fn weight_updated(&mut self, from: L, to: L) -> W {
+    fn weight_updated(&self, from: L, to: L) -> W {
         let from_index = self.index_updated(&from);
         let to_index = self.index_updated(&to);
         if from_index.is_none() {
@@ -297,6 +358,12 @@ where
                     }
                 }
             }
+        } else if self.weights.contains_key(&(from_index, to_index)) {
+            // weight wasn't edited since the last commit
+            self.weights
+                .get(&(from_index, to_index))
+                .unwrap()
+                .to_owned()
         } else {
             panic!("lwdg get_weights_updated : Something went wrong,weight is missing")
         }
@@ -314,6 +381,14 @@ where
     fn incoming_edges_updated(&self, vertex: L) -> Vec<L> {
         self.ldg.incoming_edges_updated(vertex)
     }
+
+    fn out_degree_updated(&self, vertex: L) -> usize {
+        self.ldg.out_degree_updated(vertex)
+    }
+
+    fn in_degree_updated(&self, vertex: L) -> usize {
+        self.ldg.in_degree_updated(vertex)
+    }
 }
 
 impl<L, W> WTLabeled<L> for LabeledWeightedWTDigraph<L, W>
@@ -329,3 +404,355 @@ where
         self.ldg.index_updated(label)
     }
 }
+
+impl<L, W> TryGraph<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.ldg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if !self.ldg.dg.edge_exists_updated(from_index, to_index) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.updated_index(&vertex)?;
+        self.delete_vertex(vertex);
+        Ok(())
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        self.ldg.try_edge_exists(from, to)
+    }
+}
+
+impl<L, W> TryDirected<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_outgoing_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_outgoing_edges(vertex)
+    }
+
+    fn try_incoming_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_incoming_edges(vertex)
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.updated_index(&vertex)?;
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.ldg.updated_index(&vertex)?;
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+
+impl<L, W> TryLabeled<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Num,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.ldg.try_edit_label(old_label, new_label)
+    }
+}
+
+impl<L, W> TryWeighted<L, W> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_add_edge(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if self.ldg.dg.edge_exists_updated(from_index, to_index) {
+            return Err(GraphError::EdgeExists);
+        }
+        self.add_edge(from, to, weight);
+        Ok(())
+    }
+
+    fn try_edit_weight(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if !self.ldg.dg.edge_exists_updated(from_index, to_index) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.edit_weight(from, to, weight);
+        Ok(())
+    }
+
+    fn try_weight(&self, from: L, to: L) -> Result<W, GraphError> {
+        let from_index = self.ldg.committed_index(&from)?;
+        let to_index = self.ldg.committed_index(&to)?;
+        if !self.ldg.dg.edge_exists(from_index, to_index) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.weights
+            .get(&(from_index, to_index))
+            .cloned()
+            .ok_or(GraphError::WeightNotFound)
+    }
+}
+
+impl<L, W> TryWTDirected<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Num,
+{
+    fn try_outgoing_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_outgoing_edges_updated(vertex)
+    }
+
+    fn try_incoming_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.ldg.try_incoming_edges_updated(vertex)
+    }
+}
+
+impl<L, W> TryWTWeighted<L, W> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_weight_updated(&self, from: L, to: L) -> Result<W, GraphError> {
+        let from_index = self.ldg.updated_index(&from)?;
+        let to_index = self.ldg.updated_index(&to)?;
+        if !self.ldg.dg.edge_exists_updated(from_index, to_index) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        match self.weights_uncommitted.get(&(from_index, to_index)) {
+            Some(Edit::Add(weight)) => Ok(weight.clone()),
+            Some(Edit::Delete(_)) => Err(GraphError::WeightNotFound),
+            None => self
+                .weights
+                .get(&(from_index, to_index))
+                .cloned()
+                .ok_or(GraphError::WeightNotFound),
+        }
+    }
+}
+
+impl<L, W> GraphSearch<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<Option<ShortestPath<L, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<ShortestPaths<L, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<HashMap<L, ShortestPaths<L, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L, W> SearchView<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.ldg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.outgoing_edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.incoming_edges(vertex.clone())
+    }
+
+    fn distance(&self, from: &L, to: &L) -> W {
+        self.weight(from.clone(), to.clone())
+    }
+}
+
+impl<L, W> TopologicalSort<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order(&self) -> Result<Vec<L>, Vec<L>> {
+        self.ldg.topological_order()
+    }
+}
+
+impl<L, W> WTTopologicalSort<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order_updated(&self) -> Result<Vec<L>, Vec<L>> {
+        self.ldg.topological_order_updated()
+    }
+}
+
+impl<L, W> PageRank<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn pagerank(&self, options: &PageRankOptions<L>) -> Result<HashMap<L, f64>, GraphError> {
+        self.ldg.pagerank(options)
+    }
+}
+
+impl<L, W> Centrality<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L, W> DirectedCores<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn in_core_numbers(&self) -> HashMap<L, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<L, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<L, W> Neighborhood<L> for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.ldg.dg,
+            &u,
+            &v,
+            Direction::Outgoing,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.ldg.dg,
+            &u,
+            &v,
+            Direction::Incoming,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.ldg.dg, &u, &v, Direction::Outgoing, |l| self.index(l))
+    }
+}
+
+impl<L, W> LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    // deletes the edge and its weight like delete_edge, but doesn't commit. see WTDigraph::delete_edge_uncommitted
+    pub(crate) fn delete_edge_uncommitted(&mut self, from: L, to: L) {
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
+        if from_index.is_none() {
+            panic!("lwdg delete_edge : from Vertex doesn't exist");
+        }
+        if to_index.is_none() {
+            panic!("lwdg delete_edge : to Vertex doesn't exist");
+        }
+        let from_index = from_index.unwrap();
+        let to_index = to_index.unwrap();
+
+        // needs to be read before the edge is gone. an edge without a weight has none to delete
+        let weight = self.try_weight_updated(from.clone(), to.clone()).ok();
+        self.ldg.delete_edge(from, to);
+        if let Some(weight) = weight {
+            self.weights_uncommitted
+                .insert((from_index, to_index), Edit::Delete(weight));
+        }
+    }
+
+    // commits the edits if the commit policy calls for it. see WTDigraph::commit_if_due
+    pub(crate) fn commit_if_due(&mut self) {
+        if let Some(event) = self.auto_commit.due(&self.ldg.dg) {
+            self.commit_edits();
+            self.auto_commit.notify(&event);
+        }
+    }
+}
+
+impl<L, W> WTCommitPolicy for LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.auto_commit.policy
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.auto_commit.policy = policy;
+        self.commit_if_due();
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.auto_commit.hook = hook;
+    }
+}
@@ -1,6 +1,9 @@
 use rand::prelude::SliceRandom;
 use rand::thread_rng;
 use crate::{prelude::WTLabeled, traits::{Graph, Labeled, WT}};
+use crate::traits::{TryGraph, TryWeighted};
+use crate::wt::labeled_weighted_directed::LabeledWeightedWTDigraph;
+use crate::GraphError;
 
 #[test]
 fn add_vertex() {
@@ -88,4 +91,33 @@ fn add_vertex() {
     }    
 
 }
-    
\ No newline at end of file
+    
+#[test]
+fn delete_edge_without_weight() {
+    // the edges a -> b and b -> a, only the second one has a weight
+    let mut bits = vers_vecs::BitVec::new();
+    for bit in [true, false, true, false] {
+        bits.append(bit);
+    }
+    let labels = vec!["a".to_string(), "b".to_string()];
+    let weights = std::collections::HashMap::from([((1, 0), 1)]);
+    let mut lwwdg = LabeledWeightedWTDigraph::from(
+        vec![1, 0],
+        vers_vecs::RsVec::from_bit_vec(bits),
+        labels,
+        weights,
+    );
+    let (a, b) = ("a".to_string(), "b".to_string());
+    assert_eq!(
+        lwwdg.try_weight(a.clone(), b.clone()),
+        Err(GraphError::WeightNotFound)
+    );
+    assert_eq!(lwwdg.try_delete_edge(a.clone(), b.clone()), Ok(()));
+    assert_eq!(
+        lwwdg.try_delete_edge(a.clone(), b.clone()),
+        Err(GraphError::EdgeNotFound)
+    );
+    assert_eq!(lwwdg.try_delete_edge(b.clone(), a.clone()), Ok(()));
+    lwwdg.commit_edits();
+    assert_eq!(lwwdg.e_count(), 0);
+}
@@ -1,12 +1,29 @@
-use num::Num;
+use num::{Num, ToPrimitive};
 use vers_vecs::RsVec;
 
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
+use crate::algo::spanning;
+use crate::algo::triangles;
 use crate::graph::labeled_weighted_undirected::LabeledWeightedUGraph;
 use crate::traits::{
     Directed, Graph, Labeled, Undirected, WTDirected, WTLabeled, WTUndirected, WTWeighted,
     Weighted, WT,
 };
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{SpanningForest, SpanningTree, SpanningTreeAlgorithm};
+use crate::traits::{TryGraph, TryLabeled, TryUndirected, TryWTUndirected, TryWTWeighted, TryWeighted};
 use crate::wt::labeled_weighted_directed::LabeledWeightedWTDigraph;
+use crate::GraphError;
 use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
@@ -24,7 +41,7 @@ where
     L: Hash + Clone + Eq,
     W: Num,
 {
-    lwdg: LabeledWeightedWTDigraph<L, W>,
+    pub(crate) lwdg: LabeledWeightedWTDigraph<L, W>,
 }
 impl<L, W> LabeledWeightedWTUGraph<L, W>
 where
@@ -46,26 +63,31 @@ where
             lwdg: LabeledWeightedWTDigraph::from(sequence, starting_indices, labels, weights),
         }
     }
+
+    // see LabeledWTUGraph::neighbors_iter
+    pub fn neighbors_iter<'a>(&'a self, vertex: &'a L) -> impl Iterator<Item = &'a L> + 'a {
+        self.lwdg
+            .incoming_iter(vertex)
+            .chain(self.lwdg.outgoing_iter(vertex).filter(move |item| *item != vertex))
+    }
+
+    // see LabeledWTUGraph::neighbors_iter_updated
+    pub fn neighbors_iter_updated<'a>(&'a self, vertex: &'a L) -> impl Iterator<Item = &'a L> + 'a {
+        self.lwdg.incoming_iter_updated(vertex).chain(
+            self.lwdg
+                .outgoing_iter_updated(vertex)
+                .filter(move |item| *item != vertex),
+        )
+    }
 }
 
-impl<L, W> Graph<L> for LabeledWeightedWTUGraph<L, W>
+impl<L, W> LabeledWeightedWTUGraph<L, W>
 where
     L: Hash + Clone + Eq,
     W: Clone + Num,
 {
-    fn add_vertex(&mut self, vertex: L) -> usize {
-        return self.lwdg.add_vertex(vertex);
-    }
-
-    fn e_count(&self) -> usize {
-        return self.lwdg.e_count();
-    }
-
-    fn v_count(&self) -> usize {
-        return self.lwdg.v_count();
-    }
-
-    fn delete_edge(&mut self, from: L, to: L) {
+    // deletes the edge like delete_edge, but doesn't commit. see WTDigraph::delete_edge_uncommitted
+    fn delete_edge_uncommitted(&mut self, from: L, to: L) {
         // fixme
         // does check twice if from and to is valid
         let from_index = self.index_updated(&from);
@@ -79,11 +101,34 @@ where
         let from_index = from_index.unwrap();
         let to_index = to_index.unwrap();
         if from_index <= to_index {
-            self.lwdg.delete_edge(from, to);
+            self.lwdg.delete_edge_uncommitted(from, to);
         } else {
-            self.lwdg.delete_edge(to, from);
+            self.lwdg.delete_edge_uncommitted(to, from);
         }
     }
+}
+
+impl<L, W> Graph<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn add_vertex(&mut self, vertex: L) -> usize {
+        return self.lwdg.add_vertex(vertex);
+    }
+
+    fn e_count(&self) -> usize {
+        return self.lwdg.e_count();
+    }
+
+    fn v_count(&self) -> usize {
+        return self.lwdg.v_count();
+    }
+
+    fn delete_edge(&mut self, from: L, to: L) {
+        self.delete_edge_uncommitted(from, to);
+        self.lwdg.commit_if_due();
+    }
 
     fn delete_vertex(&mut self, vertex: L) {
         self.lwdg.delete_vertex(vertex);
@@ -142,8 +187,17 @@ where
 
     fn delete_edges_from(&mut self, vertex: L) {
         for item in self.edges_updated(vertex.clone()) {
-            self.delete_edge(vertex.clone(), item);
+            self.delete_edge_uncommitted(vertex.clone(), item);
         }
+        self.lwdg.commit_if_due();
+    }
+
+    fn degree(&self, vertex: L) -> usize {
+        let vertex_index = self.index(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtlwug degree : Vertex doesn't exist");
+        }
+        self.lwdg.ldg.dg.undirected_degree(vertex_index.unwrap())
     }
 }
 impl<L, W> Labeled<L> for LabeledWeightedWTUGraph<L, W>
@@ -175,8 +229,8 @@ where
     fn add_edge(&mut self, from: L, to: L, weight: W) {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlwug add_edge : from Vertex doesn't exist")
         }
@@ -196,8 +250,8 @@ where
     fn edit_weight(&mut self, from: L, to: L, weight: W) {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlwug edit_weight : from Vertex doesn't exist")
         }
@@ -214,7 +268,7 @@ where
         }
     }
 
-    fn weight(&mut self, from: L, to: L) -> W {
+    fn weight(&self, from: L, to: L) -> W {
         // fixme
         // does check twice if from and to is valid
         let from_index = self.index(&from);
@@ -259,8 +313,8 @@ where
     fn edge_exists_updated(&self, from: L, to: L) -> bool {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlwug edge_exists_updated : from Vertex doesn't exist")
         }
@@ -290,11 +344,11 @@ where
     L: Hash + Clone + Eq,
     W: Clone + Num,
 {
-    fn weight_updated(&mut self, from: L, to: L) -> W {
+    fn weight_updated(&self, from: L, to: L) -> W {
         // fixme
         // does check twice if from and to is valid
-        let from_index = self.index(&from);
-        let to_index = self.index(&to);
+        let from_index = self.index_updated(&from);
+        let to_index = self.index_updated(&to);
         if from_index.is_none() {
             panic!("wtlwug get_weight_updated : from Vertex doesn't exist")
         }
@@ -317,7 +371,7 @@ where
     W: Clone + Num,
 {
     fn edges_updated(&self, vertex: L) -> Vec<L> {
-        let vertex_index = self.index(&vertex);
+        let vertex_index = self.index_updated(&vertex);
         if vertex_index.is_none() {
             panic!("wtlwug edges_updated : Vertex doesn't exist");
         }
@@ -336,6 +390,14 @@ where
             return edges;
         }
     }
+
+    fn degree_updated(&self, vertex: L) -> usize {
+        let vertex_index = self.index_updated(&vertex);
+        if vertex_index.is_none() {
+            panic!("wtlwug degree_updated : Vertex doesn't exist");
+        }
+        self.lwdg.ldg.dg.undirected_degree_updated(vertex_index.unwrap())
+    }
 }
 
 impl<L, W> WTLabeled<L> for LabeledWeightedWTUGraph<L, W>
@@ -351,3 +413,302 @@ where
         return self.lwdg.index_updated(label);
     }
 }
+
+impl<L, W> LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Num,
+{
+    // orders `from` and `to` by their index including the uncommitted changes, the way edges are stored in lwdg
+    fn ordered_updated(&self, from: L, to: L) -> Result<(L, L), GraphError> {
+        let from_index = self.lwdg.ldg.updated_index(&from)?;
+        let to_index = self.lwdg.ldg.updated_index(&to)?;
+        if from_index <= to_index {
+            Ok((from, to))
+        } else {
+            Ok((to, from))
+        }
+    }
+
+    // orders `from` and `to` by their index at the last commit, the way edges are stored in lwdg
+    fn ordered_committed(&self, from: L, to: L) -> Result<(L, L), GraphError> {
+        let from_index = self.lwdg.ldg.committed_index(&from)?;
+        let to_index = self.lwdg.ldg.committed_index(&to)?;
+        if from_index <= to_index {
+            Ok((from, to))
+        } else {
+            Ok((to, from))
+        }
+    }
+}
+
+impl<L, W> TryGraph<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_add_vertex(&mut self, vertex: L) -> Result<usize, GraphError> {
+        self.lwdg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: L, to: L) -> Result<(), GraphError> {
+        let (from, to) = self.ordered_updated(from, to)?;
+        self.lwdg.try_delete_edge(from, to)
+    }
+
+    fn try_delete_vertex(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.lwdg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: L, to: L) -> Result<bool, GraphError> {
+        let (from, to) = self.ordered_committed(from, to)?;
+        self.lwdg.try_edge_exists(from, to)
+    }
+}
+
+impl<L, W> TryUndirected<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_edges(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.lwdg.ldg.committed_index(&vertex)?;
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: L) -> Result<(), GraphError> {
+        self.lwdg.ldg.updated_index(&vertex)?;
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+
+impl<L, W> TryLabeled<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Num,
+{
+    fn try_edit_label(&mut self, old_label: L, new_label: L) -> Result<(), GraphError> {
+        self.lwdg.try_edit_label(old_label, new_label)
+    }
+}
+
+impl<L, W> TryWeighted<L, W> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_add_edge(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let (from, to) = self.ordered_updated(from, to)?;
+        self.lwdg.try_add_edge(from, to, weight)
+    }
+
+    fn try_edit_weight(&mut self, from: L, to: L, weight: W) -> Result<(), GraphError> {
+        let (from, to) = self.ordered_updated(from, to)?;
+        self.lwdg.try_edit_weight(from, to, weight)
+    }
+
+    fn try_weight(&self, from: L, to: L) -> Result<W, GraphError> {
+        let (from, to) = self.ordered_committed(from, to)?;
+        self.lwdg.try_weight(from, to)
+    }
+}
+
+impl<L, W> TryWTUndirected<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_edges_updated(&self, vertex: L) -> Result<Vec<L>, GraphError> {
+        self.lwdg.ldg.updated_index(&vertex)?;
+        Ok(self.edges_updated(vertex))
+    }
+}
+
+impl<L, W> TryWTWeighted<L, W> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn try_weight_updated(&self, from: L, to: L) -> Result<W, GraphError> {
+        let (from, to) = self.ordered_updated(from, to)?;
+        self.lwdg.try_weight_updated(from, to)
+    }
+}
+
+impl<L, W> GraphSearch<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: L,
+        to: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<Option<ShortestPath<L, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: L,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<ShortestPaths<L, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<L, W>,
+    ) -> Result<HashMap<L, ShortestPaths<L, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<L>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: L, to: L) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<L, W> SearchView<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<L> {
+        self.lwdg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &L) -> bool {
+        self.vertex_exists(vertex.clone())
+    }
+
+    fn successors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn predecessors(&self, vertex: &L) -> Vec<L> {
+        self.edges(vertex.clone())
+    }
+
+    fn distance(&self, from: &L, to: &L) -> W {
+        self.weight(from.clone(), to.clone())
+    }
+}
+
+impl<L, W> SpanningTree<L, W> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn minimum_spanning_forest(&self, mode: SpanningTreeAlgorithm) -> SpanningForest<L, W> {
+        spanning::minimum_spanning_forest(self, mode)
+    }
+}
+
+impl<L, W> Triangles<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num,
+{
+    fn triangle_counts(&self) -> TriangleCounts<L> {
+        triangles::wt_triangle_counts(&self.lwdg.ldg.dg, |v| self.label(v).cloned())
+    }
+}
+
+impl<L, W> Centrality<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<L, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<L, W> Cores<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num,
+{
+    fn core_numbers(&self) -> HashMap<L, usize> {
+        cores::wt_core_numbers(&self.lwdg.ldg.dg, |v| self.label(v).cloned())
+    }
+
+    fn degeneracy_order(&self) -> Vec<L> {
+        cores::wt_degeneracy_order(&self.lwdg.ldg.dg, |v| self.label(v).cloned())
+    }
+}
+
+impl<L, W> Biconnectivity<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<L> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<L, W> Neighborhood<L> for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Eq + Clone,
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.lwdg.ldg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn common_in_neighbors(&self, u: L, v: L) -> Result<Vec<L>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.lwdg.ldg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |l| self.index(l),
+            |v| self.label(v).cloned(),
+        )
+    }
+
+    fn jaccard(&self, u: L, v: L) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.lwdg.ldg.dg, &u, &v, Direction::Undirected, |l| {
+            self.index(l)
+        })
+    }
+}
+
+// the policy belongs to the wrapped wt-graph, which commits everything of this graph
+impl<L, W> WTCommitPolicy for LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq,
+    W: Clone + Num,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.lwdg.commit_policy()
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.lwdg.set_commit_policy(policy);
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.lwdg.set_commit_hook(hook);
+    }
+}
@@ -0,0 +1,762 @@
+//! Read-only wt-graphs answering queries straight from a memory-mapped file.
+//!
+//! Loading a graph with `load` deserializes the QW-tree and the bitmap into memory, which needs as much memory as the
+//! file is large. For graphs which only have to be queried, `save_mapped` writes the committed graph in a layout
+//! that can be used in place: `MappedWTDigraph::open` and `MappedWTUGraph::open` map the file into memory and
+//! read the adjacency from it on demand, so only the pages which are queried are loaded (and can be evicted again)
+//! by the operating system.
+//!
+//! The file starts with a 16 byte header like the files written by `save`, but with the magic bytes
+//! `MAPPED_MAGIC` and the version `MAPPED_VERSION`. It is followed by little endian u64 words:
+//!
+//! | Words                     | Content                                                                    |
+//! |---------------------------|----------------------------------------------------------------------------|
+//! | 6                         | `n` (the last index + 1), the number of edges, `s`, `t`, `d` and `b`       |
+//! | 9 per 512 bits of n+1+s   | the outgoing bitmap: a 1 for every vertex followed by a 0 for every outgoing edge, and a final 1 |
+//! | s · b / 64                | the targets of the outgoing edges, in the same order as in the QW-tree     |
+//! | 9 per 512 bits of n+1+t   | the incoming bitmap, like the outgoing bitmap for the incoming edges       |
+//! | t · b / 64                | the sources of the incoming edges, ascending for each vertex               |
+//! | d                         | the deleted vertices, ascending                                            |
+//!
+//! `s` and `t` are the number of outgoing and incoming edges, `b` is the number of bits of the biggest index.
+//! The bitmaps are stored in blocks of 9 words: the number of 1s before the block, followed by 512 bits.
+//! The targets and sources are packed into consecutive words, the last word of each section is padded with 0s.
+//!
+//! So a graph takes about `2 * b + 2.25` bits per edge and 2.25 bits per vertex, about twice as much as a file
+//! written by `save`, which only holds the outgoing edges in the QW-tree. The out- and in-degree of a vertex are
+//! answered with two selects on a bitmap, and `edge_exists` with a binary search over the incoming edges of the target.
+//! `save_mapped` streams each section straight from the QW-tree and the bitmap of the graph, without building it in
+//! memory. The targets and sources are plain packed arrays, not a succinct structure: mapping the QW-tree itself would
+//! save half of the file, but needs the internal layout of qwt, and the incoming edges would cost a select each.
+//!
+//! `open` and `from_mmap` only check the header and that the counts fit the length of the file, which takes constant
+//! time, so opening a big file doesn't read it. A damaged file can then make queries panic or return wrong edges,
+//! though they never read outside the file. `open_checked` and `from_mmap_checked` additionally read the whole file
+//! once to check that the counts of the bitmaps are right, so the offsets of the vertices are ascending and in bounds,
+//! and that all indices are smaller than `n`, so a damaged file can't make the queries fail.
+//!
+//! The mapped graphs implement the read functions of `Graph`, `Directed` and `Undirected`.
+//! All functions which would change the graph panic. The file must not be changed while it is mapped.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::prelude::*;
+//! use wt_graphs::wt::mapped::MappedWTDigraph;
+//!
+//! let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(2, 1, vec![vec![1], vec![]]));
+//! let path = std::env::temp_dir().join("wt_graphs_mapped_example.wtm");
+//! wtd.save_mapped(std::fs::File::create(&path).unwrap()).unwrap();
+//!
+//! let mapped = MappedWTDigraph::open(&path).unwrap();
+//! assert!(mapped.edge_exists(0, 1));
+//! assert_eq!(mapped.incoming_edges(1), vec![0]);
+//! # std::fs::remove_file(&path).unwrap();
+//! ```
+
+use crate::traits::{Directed, Graph, Undirected};
+use crate::wt::directed::WTDigraph;
+use crate::wt::persist::{GraphKind, PersistError};
+use crate::wt::undirected::WTUGraph;
+use memmap2::Mmap;
+use qwt::{AccessUnsigned, RankUnsigned, SelectUnsigned};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+#[cfg(test)]
+mod test;
+
+/// The magic bytes every file written by `save_mapped` starts with.
+pub const MAPPED_MAGIC: &[u8; 8] = b"WTMAPPED";
+
+/// The version of the layout written by `save_mapped`. `open` only maps files of this version.
+pub const MAPPED_VERSION: u32 = 2;
+
+const HEADER_LEN: usize = 16;
+const WORD: usize = 8;
+const COUNTS: usize = 6; // the number of words before the first section
+const BLOCK_BITS: usize = 512; // the bits of a block of a bitmap
+const BLOCK_WORDS: usize = 1 + BLOCK_BITS / 64; // the number of 1s before the block and its bits
+
+impl WTDigraph {
+    /// Saves the graph at the last commit in the layout read by `MappedWTDigraph`.
+    /// Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save_mapped<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save_mapped(writer, GraphKind::WTDigraph, self)
+    }
+}
+
+impl WTUGraph {
+    /// Saves the graph at the last commit in the layout read by `MappedWTUGraph`.
+    /// Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save_mapped<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save_mapped(writer, GraphKind::WTUGraph, &self.wtd)
+    }
+}
+
+// the position of a bitmap in the file and its number of bits
+#[derive(Clone, Copy, Debug, Default)]
+struct Bitmap {
+    start: usize, // the word index of the first block
+    len: usize,
+}
+
+impl Bitmap {
+    fn words(len: usize) -> Option<usize> {
+        len.div_ceil(BLOCK_BITS).checked_mul(BLOCK_WORDS)
+    }
+}
+
+// A read-only wt-digraph, which answers all queries from a memory-mapped file written by WTDigraph::save_mapped.
+// Nothing but the positions of the sections is kept in memory.
+// The file holds the adjacency of both directions as packed arrays, which is about twice the size of the file
+// written by save and not succinct: each edge takes two indices of b bits, instead of about b bits in the QW-tree.
+#[derive(Debug)]
+pub struct MappedWTDigraph {
+    map: Mmap,
+    index_space: usize, // the index of the last vertex + 1, like wt_adj_len in WTDigraph
+    e_count: usize,
+    bits: usize, // the bits of each target and source
+    out_bitmap: Bitmap,
+    out_targets: usize, // the word index of the section in the file
+    in_bitmap: Bitmap,
+    in_sources: usize,
+    deleted: usize,
+    deleted_len: usize,
+}
+
+impl MappedWTDigraph {
+    /// Maps the file at `path`, which has to be written by `WTDigraph::save_mapped`.
+    /// Only checks the header and the length of the file; queries on a damaged file may panic or return wrong edges.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, PersistError> {
+        Self::from_mmap(map(path)?)
+    }
+
+    /// Maps the file at `path` like `open`, and reads the whole file once to check it,
+    /// so that queries can't fail on a damaged file. Takes time linear in the size of the file.
+    pub fn open_checked<P: AsRef<Path>>(path: P) -> Result<Self, PersistError> {
+        Self::from_mmap_checked(map(path)?)
+    }
+
+    /// Uses a file which was mapped by the caller, e.g. with other options than `open` uses.
+    /// Checks as much as `open`.
+    pub fn from_mmap(map: Mmap) -> Result<Self, PersistError> {
+        from_mmap(map, GraphKind::WTDigraph)
+    }
+
+    /// Uses a file which was mapped by the caller, and checks it like `open_checked`.
+    pub fn from_mmap_checked(map: Mmap) -> Result<Self, PersistError> {
+        let graph = from_mmap(map, GraphKind::WTDigraph)?;
+        graph.validate()?;
+        Ok(graph)
+    }
+
+    /// Returns an iterator over the outgoing edges of the given vertex, without allocating a vector.
+    /// Panics if the vertex doesn't exist.
+    pub fn outgoing_iter(&self, vertex: usize) -> impl ExactSizeIterator<Item = usize> + '_ {
+        self.check(vertex, "outgoing_iter");
+        let (start, end) = self.range(self.out_bitmap, vertex);
+        (start..end).map(move |i| self.packed(self.out_targets, i))
+    }
+
+    /// Returns an iterator over the incoming edges of the given vertex, without allocating a vector.
+    /// Panics if the vertex doesn't exist.
+    pub fn incoming_iter(&self, vertex: usize) -> impl ExactSizeIterator<Item = usize> + '_ {
+        self.check(vertex, "incoming_iter");
+        let (start, end) = self.range(self.in_bitmap, vertex);
+        (start..end).map(move |i| self.packed(self.in_sources, i))
+    }
+
+    fn check(&self, vertex: usize, function: &str) {
+        if !self.vertex_exists(vertex) {
+            panic!("{}: Vertex {} doesn't exist.", function, vertex);
+        }
+    }
+
+    // the word at the given index, counted from the end of the header
+    fn word(&self, index: usize) -> u64 {
+        let start = HEADER_LEN + index * WORD;
+        let mut bytes = [0u8; WORD];
+        bytes.copy_from_slice(&self.map[start..start + WORD]);
+        u64::from_le_bytes(bytes)
+    }
+
+    // the i-th entry of the packed section starting at the given word
+    fn packed(&self, section: usize, i: usize) -> usize {
+        let bit = i * self.bits;
+        let (word, offset) = (section + bit / 64, bit % 64);
+        let mut value = self.word(word) >> offset;
+        if offset + self.bits > 64 {
+            value |= self.word(word + 1) << (64 - offset);
+        }
+        (value & mask(self.bits)) as usize
+    }
+
+    // the position of the k-th 1 (starting at 0) in the bitmap, which has to contain more than k 1s.
+    // a binary search over the number of 1s before each block, followed by a scan of the block
+    fn select1(&self, bitmap: Bitmap, k: usize) -> usize {
+        let blocks = bitmap.len.div_ceil(BLOCK_BITS);
+        let ones_before = |block: usize| self.word(bitmap.start + block * BLOCK_WORDS) as usize;
+        let (mut low, mut high) = (0, blocks);
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if ones_before(mid) <= k {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let mut rest = k - ones_before(low);
+        for i in 0..BLOCK_WORDS - 1 {
+            let mut word = self.word(bitmap.start + low * BLOCK_WORDS + 1 + i);
+            let ones = word.count_ones() as usize;
+            if rest < ones {
+                for _ in 0..rest {
+                    word &= word - 1; // clears the lowest 1
+                }
+                return low * BLOCK_BITS + i * 64 + word.trailing_zeros() as usize;
+            }
+            rest -= ones;
+        }
+        panic!("a bitmap of the mapped file is damaged")
+    }
+
+    // the range of the vertex' edges in the targets or sources: the number of 0s before its 1 and before the next 1
+    fn range(&self, bitmap: Bitmap, vertex: usize) -> (usize, usize) {
+        (
+            self.select1(bitmap, vertex) - vertex,
+            self.select1(bitmap, vertex + 1) - (vertex + 1),
+        )
+    }
+
+    // the number of edges from `from` to `to`, by two binary searches over the ascending incoming edges of `to`
+    fn edge_count(&self, from: usize, to: usize) -> usize {
+        let (start, end) = self.range(self.in_bitmap, to);
+        let source = |i| self.packed(self.in_sources, i);
+        let first = partition_point(start, end, |i| source(i) < from);
+        let last = partition_point(first, end, |i| source(i) <= from);
+        last - first
+    }
+
+    // binary search over the ascending deleted vertices
+    fn is_deleted(&self, vertex: usize) -> bool {
+        let deleted = |i| self.word(self.deleted + i) as usize;
+        let i = partition_point(0, self.deleted_len, |i| deleted(i) < vertex);
+        i < self.deleted_len && deleted(i) == vertex
+    }
+}
+
+impl Graph<usize> for MappedWTDigraph {
+    fn add_vertex(&mut self, _vertex: usize) -> usize {
+        panic!("MappedWTDigraph is read-only.");
+    }
+
+    // returns the number of edges in the graph at the time it was saved
+    fn e_count(&self) -> usize {
+        self.e_count
+    }
+
+    // returns the number of vertices in the graph at the time it was saved
+    fn v_count(&self) -> usize {
+        self.index_space - self.deleted_len
+    }
+
+    fn delete_edge(&mut self, _from: usize, _to: usize) {
+        panic!("MappedWTDigraph is read-only.");
+    }
+
+    fn delete_vertex(&mut self, _vertex: usize) {
+        panic!("MappedWTDigraph is read-only.");
+    }
+
+    fn vertex_exists(&self, vertex: usize) -> bool {
+        vertex < self.index_space && !self.is_deleted(vertex)
+    }
+
+    fn edge_exists(&self, from: usize, to: usize) -> bool {
+        if !(self.vertex_exists(from) && self.vertex_exists(to)) {
+            return false;
+        }
+        self.edge_count(from, to) > 0
+    }
+}
+
+impl Directed<usize> for MappedWTDigraph {
+    fn outgoing_edges(&self, vertex: usize) -> Vec<usize> {
+        self.check(vertex, "outgoing_edges");
+        self.outgoing_iter(vertex).collect()
+    }
+
+    fn incoming_edges(&self, vertex: usize) -> Vec<usize> {
+        self.check(vertex, "incoming_edges");
+        self.incoming_iter(vertex).collect()
+    }
+
+    fn delete_outgoing_edges(&mut self, _vertex: usize) {
+        panic!("MappedWTDigraph is read-only.");
+    }
+
+    fn delete_incoming_edges(&mut self, _vertex: usize) {
+        panic!("MappedWTDigraph is read-only.");
+    }
+
+    // two selects on the outgoing bitmap
+    fn out_degree(&self, vertex: usize) -> usize {
+        self.check(vertex, "out_degree");
+        let (start, end) = self.range(self.out_bitmap, vertex);
+        end - start
+    }
+
+    // two selects on the incoming bitmap
+    fn in_degree(&self, vertex: usize) -> usize {
+        self.check(vertex, "in_degree");
+        let (start, end) = self.range(self.in_bitmap, vertex);
+        end - start
+    }
+}
+// A read-only wt-ugraph, which answers all queries from a memory-mapped file written by WTUGraph::save_mapped.
+// Like WTUGraph, it holds a digraph with the edges from the smaller to the bigger index.
+#[derive(Debug)]
+pub struct MappedWTUGraph {
+    mdg: MappedWTDigraph,
+}
+
+impl MappedWTUGraph {
+    /// Maps the file at `path`, which has to be written by `WTUGraph::save_mapped`.
+    /// Only checks the header and the length of the file; queries on a damaged file may panic or return wrong edges.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, PersistError> {
+        Self::from_mmap(map(path)?)
+    }
+
+    /// Maps the file at `path` like `open`, and reads the whole file once to check it,
+    /// so that queries can't fail on a damaged file. Takes time linear in the size of the file.
+    pub fn open_checked<P: AsRef<Path>>(path: P) -> Result<Self, PersistError> {
+        Self::from_mmap_checked(map(path)?)
+    }
+
+    /// Uses a file which was mapped by the caller, e.g. with other options than `open` uses.
+    /// Checks as much as `open`.
+    pub fn from_mmap(map: Mmap) -> Result<Self, PersistError> {
+        Ok(MappedWTUGraph {
+            mdg: from_mmap(map, GraphKind::WTUGraph)?,
+        })
+    }
+
+    /// Uses a file which was mapped by the caller, and checks it like `open_checked`.
+    pub fn from_mmap_checked(map: Mmap) -> Result<Self, PersistError> {
+        let mdg = from_mmap(map, GraphKind::WTUGraph)?;
+        mdg.validate()?;
+        Ok(MappedWTUGraph { mdg })
+    }
+
+    /// Returns an iterator over all edges of the given vertex, without allocating a vector.
+    /// Yields the same vertices in the same order as `WTUGraph::neighbors_iter`.
+    /// Panics if the vertex doesn't exist.
+    pub fn neighbors_iter(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.mdg.incoming_iter(vertex).chain(
+            self.mdg
+                .outgoing_iter(vertex)
+                .filter(move |&item| item != vertex),
+        )
+    }
+}
+
+impl Graph<usize> for MappedWTUGraph {
+    fn add_vertex(&mut self, _vertex: usize) -> usize {
+        panic!("MappedWTUGraph is read-only.");
+    }
+
+    fn e_count(&self) -> usize {
+        self.mdg.e_count()
+    }
+
+    fn v_count(&self) -> usize {
+        self.mdg.v_count()
+    }
+
+    fn delete_edge(&mut self, _from: usize, _to: usize) {
+        panic!("MappedWTUGraph is read-only.");
+    }
+
+    fn delete_vertex(&mut self, _vertex: usize) {
+        panic!("MappedWTUGraph is read-only.");
+    }
+
+    fn vertex_exists(&self, vertex: usize) -> bool {
+        self.mdg.vertex_exists(vertex)
+    }
+
+    fn edge_exists(&self, from: usize, to: usize) -> bool {
+        if from <= to {
+            self.mdg.edge_exists(from, to)
+        } else {
+            self.mdg.edge_exists(to, from)
+        }
+    }
+}
+
+impl Undirected<usize> for MappedWTUGraph {
+    fn edges(&self, vertex: usize) -> Vec<usize> {
+        self.neighbors_iter(vertex).collect()
+    }
+
+    // a loop is both an incoming and an outgoing edge, but counts once
+    fn degree(&self, vertex: usize) -> usize {
+        self.mdg.in_degree(vertex) + self.mdg.out_degree(vertex)
+            - self.mdg.edge_count(vertex, vertex)
+    }
+
+    fn delete_edges_from(&mut self, _vertex: usize) {
+        panic!("MappedWTUGraph is read-only.");
+    }
+}
+
+// writes the header and the sections of the committed digraph in one pass over each section.
+// the sources of the incoming edges of a vertex are found with select on the QW-tree, so they are ascending.
+fn save_mapped<Wr: Write>(writer: Wr, kind: GraphKind, dg: &WTDigraph) -> Result<(), PersistError> {
+    if dg.has_uncommitted_edits {
+        return Err(PersistError::UncommittedEdits);
+    }
+    let mut writer = BufWriter::new(writer);
+    let n = dg.wt_adj_len;
+    let out_degree = |v: usize| {
+        let (start, end) = dg.outgoing_range(v);
+        end - start
+    };
+    // rank is None if `v` is bigger than every vertex in wt_adj, i.e. it has no incoming edges.
+    // an empty QW-tree can't answer rank queries
+    let in_degree = |v: usize| {
+        if dg.wt_adj.is_empty() {
+            return 0;
+        }
+        dg.wt_adj.rank(v, dg.wt_adj.len()).unwrap_or(0)
+    };
+    let s: usize = (0..n).map(out_degree).sum();
+    let t: usize = (0..n).map(in_degree).sum();
+    let mut deleted: Vec<usize> = dg.deleted_vertices.keys().copied().collect();
+    deleted.sort_unstable();
+    let bits = (usize::BITS - n.saturating_sub(1).leading_zeros()).max(1) as usize;
+
+    let mut header = [0u8; HEADER_LEN];
+    header[0..8].copy_from_slice(MAPPED_MAGIC);
+    header[8..12].copy_from_slice(&MAPPED_VERSION.to_le_bytes());
+    header[12] = kind as u8;
+    writer.write_all(&header)?;
+    for count in [n, dg.e_count, s, t, deleted.len(), bits] {
+        writer.write_all(&(count as u64).to_le_bytes())?;
+    }
+
+    let mut bitmap = BitWriter::new(&mut writer);
+    for v in 0..n {
+        bitmap.push(true)?;
+        for _ in 0..out_degree(v) {
+            bitmap.push(false)?;
+        }
+    }
+    bitmap.push(true)?;
+    bitmap.finish()?;
+
+    let mut targets = PackedWriter::new(&mut writer, bits);
+    for v in 0..n {
+        let (start, end) = dg.outgoing_range(v);
+        for i in start..end {
+            targets.push(dg.wt_adj.get(i).unwrap())?; // safe, i < wt_adj.len()
+        }
+    }
+    targets.finish()?;
+
+    let mut bitmap = BitWriter::new(&mut writer);
+    for v in 0..n {
+        bitmap.push(true)?;
+        for _ in 0..in_degree(v) {
+            bitmap.push(false)?;
+        }
+    }
+    bitmap.push(true)?;
+    bitmap.finish()?;
+
+    // the source of the i-th occurrence of `v` in wt_adj is the number of 1s before its 0 in starting_indices - 1
+    let mut sources = PackedWriter::new(&mut writer, bits);
+    for v in 0..n {
+        for i in 1..=in_degree(v) {
+            let index_in_wt = dg.wt_adj.select(v, i).unwrap(); // safe, i <= rank
+            let pos_in_bitmap = dg.starting_indices.select0(index_in_wt);
+            sources.push(dg.starting_indices.rank1(pos_in_bitmap) - 1)?;
+        }
+    }
+    sources.finish()?;
+
+    for vertex in deleted {
+        writer.write_all(&(vertex as u64).to_le_bytes())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// writes a bitmap in blocks of the number of 1s before the block and BLOCK_BITS bits
+struct BitWriter<'a, Wr: Write> {
+    writer: &'a mut Wr,
+    block: [u64; BLOCK_WORDS - 1],
+    len: usize, // the bits in the current block
+    ones: u64,  // the 1s before the current block
+    ones_in_block: u64,
+}
+
+impl<'a, Wr: Write> BitWriter<'a, Wr> {
+    fn new(writer: &'a mut Wr) -> Self {
+        BitWriter {
+            writer,
+            block: [0; BLOCK_WORDS - 1],
+            len: 0,
+            ones: 0,
+            ones_in_block: 0,
+        }
+    }
+
+    fn push(&mut self, bit: bool) -> std::io::Result<()> {
+        if bit {
+            self.block[self.len / 64] |= 1 << (self.len % 64);
+            self.ones_in_block += 1;
+        }
+        self.len += 1;
+        if self.len == BLOCK_BITS {
+            self.write_block()?;
+        }
+        Ok(())
+    }
+
+    fn write_block(&mut self) -> std::io::Result<()> {
+        self.writer.write_all(&self.ones.to_le_bytes())?;
+        for word in self.block {
+            self.writer.write_all(&word.to_le_bytes())?;
+        }
+        self.ones += self.ones_in_block;
+        self.ones_in_block = 0;
+        self.block = [0; BLOCK_WORDS - 1];
+        self.len = 0;
+        Ok(())
+    }
+
+    // writes the last block, padded with 0s
+    fn finish(mut self) -> std::io::Result<()> {
+        if self.len > 0 {
+            self.write_block()?;
+        }
+        Ok(())
+    }
+}
+
+// writes numbers of `bits` bits each into consecutive words
+struct PackedWriter<'a, Wr: Write> {
+    writer: &'a mut Wr,
+    bits: usize,
+    word: u64,
+    len: usize, // the bits in the current word
+}
+
+impl<'a, Wr: Write> PackedWriter<'a, Wr> {
+    fn new(writer: &'a mut Wr, bits: usize) -> Self {
+        PackedWriter {
+            writer,
+            bits,
+            word: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, value: usize) -> std::io::Result<()> {
+        let value = value as u64;
+        self.word |= value << self.len;
+        self.len += self.bits;
+        if self.len >= 64 {
+            self.writer.write_all(&self.word.to_le_bytes())?;
+            self.len -= 64;
+            // the bits of the value which didn't fit into the word; checked_shr is None for a shift by 64
+            self.word = value
+                .checked_shr((self.bits - self.len) as u32)
+                .unwrap_or(0);
+        }
+        Ok(())
+    }
+
+    // writes the last word, padded with 0s
+    fn finish(self) -> std::io::Result<()> {
+        if self.len > 0 {
+            self.writer.write_all(&self.word.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+// the words of a packed section of `len` numbers of `bits` bits each
+fn packed_words(len: usize, bits: usize) -> Option<usize> {
+    Some(len.checked_mul(bits)?.div_ceil(64))
+}
+
+fn mask(bits: usize) -> u64 {
+    u64::MAX >> (64 - bits)
+}
+
+// the first index in start..end for which `pred` is false, `pred` has to be true for a prefix of the range
+fn partition_point(mut start: usize, mut end: usize, pred: impl Fn(usize) -> bool) -> usize {
+    while start < end {
+        let mid = start + (end - start) / 2;
+        if pred(mid) {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+    start
+}
+
+fn corrupt(message: &str) -> PersistError {
+    PersistError::Corrupt(message.to_string())
+}
+
+fn map<P: AsRef<Path>>(path: P) -> Result<Mmap, PersistError> {
+    let file = File::open(path)?;
+    // the file is only read; like every memory-mapped file it must not be changed while it is mapped
+    Ok(unsafe { Mmap::map(&file)? })
+}
+
+// checks the header, the length of the file and the sections, and computes the positions of the sections.
+// takes constant time, the content of the sections is checked by MappedWTDigraph::validate
+fn from_mmap(map: Mmap, kind: GraphKind) -> Result<MappedWTDigraph, PersistError> {
+    if map.len() < HEADER_LEN || &map[0..8] != MAPPED_MAGIC {
+        return Err(PersistError::NotAGraph);
+    }
+    let version = u32::from_le_bytes([map[8], map[9], map[10], map[11]]);
+    if version != MAPPED_VERSION {
+        return Err(PersistError::UnsupportedVersion(version));
+    }
+    if GraphKind::from_byte(map[12]) != Some(kind) {
+        return Err(PersistError::WrongKind {
+            expected: kind,
+            found: map[12],
+        });
+    }
+    let words = (map.len() - HEADER_LEN) / WORD;
+    if words < COUNTS {
+        return Err(corrupt("the file is truncated"));
+    }
+
+    let mut graph = MappedWTDigraph {
+        map,
+        index_space: 0,
+        e_count: 0,
+        bits: 0,
+        out_bitmap: Bitmap::default(),
+        out_targets: 0,
+        in_bitmap: Bitmap::default(),
+        in_sources: 0,
+        deleted: 0,
+        deleted_len: 0,
+    };
+    let [n, e_count, s, t, d, bits] = [0, 1, 2, 3, 4, 5].map(|i| graph.word(i) as usize);
+    if !(1..=64).contains(&bits) || (n > 1 && (n - 1) >> (bits - 1) >> 1 != 0) {
+        return Err(corrupt(
+            "the number of bits doesn't fit the number of vertices",
+        ));
+    }
+    // checked, so that a damaged file can't overflow the positions
+    let out_bits = n.checked_add(1).and_then(|ones| ones.checked_add(s));
+    let in_bits = n.checked_add(1).and_then(|ones| ones.checked_add(t));
+    let sections = [
+        out_bits.and_then(Bitmap::words),
+        packed_words(s, bits),
+        in_bits.and_then(Bitmap::words),
+        packed_words(t, bits),
+        Some(d),
+    ];
+    let mut starts = [0; 5];
+    let mut end = Some(COUNTS);
+    for (start, len) in starts.iter_mut().zip(sections) {
+        *start = end.unwrap_or(0);
+        end = end.zip(len).and_then(|(end, len)| end.checked_add(len));
+    }
+    if end != Some(words) || HEADER_LEN + words * WORD != graph.map.len() {
+        return Err(corrupt("the length of the file doesn't match its sections"));
+    }
+    graph.index_space = n;
+    graph.e_count = e_count;
+    graph.bits = bits;
+    graph.out_bitmap = Bitmap {
+        start: starts[0],
+        len: out_bits.unwrap(), // safe, checked with the sections
+    };
+    graph.out_targets = starts[1];
+    graph.in_bitmap = Bitmap {
+        start: starts[2],
+        len: in_bits.unwrap(),
+    };
+    graph.in_sources = starts[3];
+    graph.deleted = starts[4];
+    graph.deleted_len = d;
+
+    if e_count != s || s != t {
+        return Err(corrupt("the number of edges doesn't match the adjacency"));
+    }
+    Ok(graph)
+}
+
+impl MappedWTDigraph {
+    // reads the sections once to check that queries can't fail
+    fn validate(&self) -> Result<(), PersistError> {
+        let n = self.index_space;
+        // with the right number of 1s before each block and n + 1 1s, the offsets of the vertices are ascending and in bounds
+        for bitmap in [self.out_bitmap, self.in_bitmap] {
+            if self.count_ones(bitmap)? != n + 1
+                || !self.bit(bitmap, 0)
+                || !self.bit(bitmap, bitmap.len - 1)
+            {
+                return Err(corrupt("a bitmap doesn't match the number of vertices"));
+            }
+        }
+        let mut previous = None;
+        for i in 0..self.deleted_len {
+            let vertex = self.word(self.deleted + i) as usize;
+            if vertex >= n || previous.is_some_and(|previous| previous >= vertex) {
+                return Err(corrupt("the deleted vertices aren't ascending indices"));
+            }
+            previous = Some(vertex);
+        }
+        for section in [self.out_targets, self.in_sources] {
+            if (0..self.e_count).any(|i| self.packed(section, i) >= n) {
+                return Err(corrupt("an edge points to a vertex out of bounds"));
+            }
+        }
+        Ok(())
+    }
+
+    fn bit(&self, bitmap: Bitmap, i: usize) -> bool {
+        let word =
+            self.word(bitmap.start + (i / BLOCK_BITS) * BLOCK_WORDS + 1 + (i % BLOCK_BITS) / 64);
+        word >> (i % 64) & 1 == 1
+    }
+
+    // checks the number of 1s before each block and that the padding is 0, and returns the number of 1s
+    fn count_ones(&self, bitmap: Bitmap) -> Result<usize, PersistError> {
+        let mut ones = 0;
+        for block in 0..bitmap.len.div_ceil(BLOCK_BITS) {
+            let start = bitmap.start + block * BLOCK_WORDS;
+            if self.word(start) as usize != ones {
+                return Err(corrupt("a bitmap has a wrong count of 1s"));
+            }
+            for i in 0..BLOCK_WORDS - 1 {
+                let mut word = self.word(start + 1 + i);
+                let first = block * BLOCK_BITS + i * 64; // the position of the first bit of the word
+                if first + 64 > bitmap.len {
+                    let used = bitmap.len.saturating_sub(first);
+                    if used < 64 && word >> used != 0 {
+                        return Err(corrupt("a bitmap has bits after its end"));
+                    }
+                    word &= if used == 0 { 0 } else { mask(used) };
+                }
+                ones += word.count_ones() as usize;
+            }
+        }
+        Ok(ones)
+    }
+}
@@ -0,0 +1,255 @@
+use crate::graph::{directed::Digraph, undirected::UGraph};
+use crate::traits::{Directed, Graph, Undirected, Unweighted, WT};
+use crate::wt::mapped::*;
+use crate::wt::persist::{GraphKind, PersistError};
+use crate::wt::{directed::WTDigraph, undirected::WTUGraph};
+use std::fs;
+use std::path::PathBuf;
+
+// a file in the temp directory, which is removed again at the end of the test
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str) -> Self {
+        TempFile(std::env::temp_dir().join(format!(
+            "wt_graphs_mapped_{}_{}",
+            std::process::id(),
+            name
+        )))
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn wtd() -> WTDigraph {
+    // with a loop, parallel edges and a vertex without edges
+    let adj = vec![vec![1, 2, 1], vec![2], vec![0, 2, 4], vec![], vec![0]];
+    WTDigraph::from_digraph(Digraph::from_adjacency_list(5, 7, adj))
+}
+
+#[test]
+fn mapped_digraph() {
+    let mut wtd = wtd();
+    wtd.delete_vertex(3);
+    wtd.add_edge(4, 1);
+    wtd.commit_edits();
+    let file = TempFile::new("digraph");
+    wtd.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+
+    let mapped = MappedWTDigraph::open(&file.0).unwrap();
+    assert_eq!(mapped.v_count(), wtd.v_count());
+    assert_eq!(mapped.e_count(), wtd.e_count());
+    for v in 0..6 {
+        assert_eq!(mapped.vertex_exists(v), wtd.vertex_exists(v));
+        if !wtd.vertex_exists(v) {
+            continue;
+        }
+        assert_eq!(mapped.outgoing_edges(v), wtd.outgoing_edges(v));
+        assert_eq!(mapped.incoming_edges(v), wtd.incoming_edges(v));
+        assert_eq!(mapped.out_degree(v), wtd.out_degree(v));
+        assert_eq!(mapped.in_degree(v), wtd.in_degree(v));
+        assert_eq!(mapped.outgoing_iter(v).len(), wtd.out_degree(v));
+        for w in 0..6 {
+            assert_eq!(mapped.edge_exists(v, w), wtd.edge_exists(v, w));
+        }
+    }
+    assert_eq!(mapped.incoming_edges(1), vec![0, 0, 4]);
+    assert!(!mapped.edge_exists(3, 0));
+}
+
+// a graph whose bitmaps span several blocks, with parallel edges, loops and deleted vertices
+fn big_wtd() -> WTDigraph {
+    let n = 300;
+    let mut adj = vec![vec![]; n];
+    let mut x: usize = 7;
+    for _ in 0..1500 {
+        x = (x * 1103515245 + 12345) % (1 << 31);
+        adj[x % n].push((x >> 12) % n);
+    }
+    let e = adj.iter().map(Vec::len).sum();
+    let mut wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(n, e, adj));
+    for v in [0, 17, 150, 299] {
+        wtd.delete_vertex(v);
+    }
+    wtd.commit_edits();
+    wtd
+}
+
+#[test]
+fn mapped_big_digraph() {
+    let wtd = big_wtd();
+    let file = TempFile::new("big");
+    wtd.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+
+    // far below the 16 bytes per edge and per vertex of a dump of the offsets and edges
+    let len = fs::metadata(&file.0).unwrap().len() as usize;
+    assert!(len < (wtd.e_count() + 300) * 4);
+
+    let mapped = MappedWTDigraph::open(&file.0).unwrap();
+    assert_eq!(mapped.v_count(), wtd.v_count());
+    assert_eq!(mapped.e_count(), wtd.e_count());
+    for v in 0..300 {
+        assert_eq!(mapped.vertex_exists(v), wtd.vertex_exists(v));
+        if !wtd.vertex_exists(v) {
+            continue;
+        }
+        assert_eq!(mapped.outgoing_edges(v), wtd.outgoing_edges(v));
+        assert_eq!(mapped.incoming_edges(v), wtd.incoming_edges(v));
+        assert_eq!(mapped.in_degree(v), wtd.in_degree(v));
+        for w in (0..300).step_by(7) {
+            assert_eq!(mapped.edge_exists(v, w), wtd.edge_exists(v, w));
+        }
+    }
+}
+
+#[test]
+fn mapped_ugraph() {
+    let ug = UGraph::from_adjacency_list(4, 4, vec![vec![1, 0], vec![2], vec![3], vec![]]);
+    let wtu = WTUGraph::from_ugraph(ug);
+    let file = TempFile::new("ugraph");
+    wtu.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+
+    let mapped = MappedWTUGraph::open(&file.0).unwrap();
+    assert_eq!(mapped.e_count(), wtu.e_count());
+    for v in 0..4 {
+        assert_eq!(mapped.edges(v), wtu.edges(v));
+        assert_eq!(mapped.degree(v), wtu.degree(v));
+        for w in 0..4 {
+            assert_eq!(mapped.edge_exists(v, w), wtu.edge_exists(v, w));
+        }
+    }
+    assert_eq!(mapped.degree(0), 2);
+    assert!(mapped.edge_exists(3, 2));
+}
+
+#[test]
+fn empty_graph() {
+    let wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(2, 0, vec![vec![], vec![]]));
+    let file = TempFile::new("empty");
+    wtd.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+    let mapped = MappedWTDigraph::open(&file.0).unwrap();
+    assert_eq!(mapped.v_count(), 2);
+    assert_eq!(mapped.outgoing_edges(1), Vec::<usize>::new());
+    assert!(!mapped.edge_exists(0, 1));
+}
+
+#[test]
+fn open_errors() {
+    let mut wtd = wtd();
+    let file = TempFile::new("errors");
+
+    // a file written by save isn't a mapped graph
+    wtd.save(fs::File::create(&file.0).unwrap()).unwrap();
+    assert!(matches!(
+        MappedWTDigraph::open(&file.0),
+        Err(PersistError::NotAGraph)
+    ));
+
+    wtd.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+    assert!(matches!(
+        MappedWTUGraph::open(&file.0),
+        Err(PersistError::WrongKind {
+            expected: GraphKind::WTUGraph,
+            found: 1
+        })
+    ));
+
+    let mut bytes = fs::read(&file.0).unwrap();
+    bytes.truncate(bytes.len() - 8);
+    fs::write(&file.0, &bytes).unwrap();
+    assert!(matches!(
+        MappedWTDigraph::open(&file.0),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the outgoing bitmap starts after the header, the counts and the number of 1s before its first block
+    wtd.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+    let mut bytes = fs::read(&file.0).unwrap();
+    bytes[16 + 7 * 8] |= 0b10;
+    fs::write(&file.0, &bytes).unwrap();
+    assert!(matches!(
+        MappedWTDigraph::open_checked(&file.0),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the number of bits doesn't fit the vertices
+    let mut bytes = fs::read(&file.0).unwrap();
+    bytes[16 + 7 * 8] &= !0b10;
+    bytes[16 + 5 * 8] = 65;
+    fs::write(&file.0, &bytes).unwrap();
+    assert!(matches!(
+        MappedWTDigraph::open(&file.0),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    wtd.add_edge(3, 3);
+    assert!(matches!(
+        wtd.save_mapped(Vec::new()),
+        Err(PersistError::UncommittedEdits)
+    ));
+    assert!(matches!(
+        MappedWTDigraph::open(&TempFile::new("missing").0),
+        Err(PersistError::Io(_))
+    ));
+}
+
+#[test]
+fn wrong_block_count() {
+    let file = TempFile::new("block_count");
+    big_wtd()
+        .save_mapped(fs::File::create(&file.0).unwrap())
+        .unwrap();
+    MappedWTDigraph::open_checked(&file.0).unwrap();
+
+    // the number of 1s before the second block of the outgoing bitmap
+    let mut bytes = fs::read(&file.0).unwrap();
+    bytes[16 + 15 * 8] ^= 1;
+    fs::write(&file.0, &bytes).unwrap();
+    assert!(matches!(
+        MappedWTDigraph::open_checked(&file.0),
+        Err(PersistError::Corrupt(_))
+    ));
+    // open only checks the header and the length of the file
+    assert!(MappedWTDigraph::open(&file.0).is_ok());
+}
+
+#[test]
+fn checked_ugraph() {
+    let wtu = WTUGraph::from_ugraph(UGraph::from_adjacency_list(
+        3,
+        2,
+        vec![vec![1, 2], vec![], vec![]],
+    ));
+    let file = TempFile::new("checked_ugraph");
+    wtu.save_mapped(fs::File::create(&file.0).unwrap()).unwrap();
+    let mapped = MappedWTUGraph::open_checked(&file.0).unwrap();
+    assert_eq!(
+        mapped.edges(0),
+        MappedWTUGraph::open(&file.0).unwrap().edges(0)
+    );
+
+    // the last source of the incoming edges points to vertex 3, out of bounds
+    let mut bytes = fs::read(&file.0).unwrap();
+    let len = bytes.len();
+    bytes[len - 8] |= 0b11 << 2;
+    fs::write(&file.0, &bytes).unwrap();
+    assert!(matches!(
+        MappedWTUGraph::open_checked(&file.0),
+        Err(PersistError::Corrupt(_))
+    ));
+}
+
+#[test]
+#[should_panic]
+fn read_only() {
+    let file = TempFile::new("read_only");
+    wtd()
+        .save_mapped(fs::File::create(&file.0).unwrap())
+        .unwrap();
+    let mut mapped = MappedWTDigraph::open(&file.0).unwrap();
+    mapped.delete_edge(0, 1);
+}
@@ -0,0 +1,773 @@
+//! Saving and loading wt-graphs in a versioned binary format.
+//!
+//! Every wt-graph offers `save`, `save_with_edits` and `load`. The file starts with a 16 byte header:
+//!
+//! | Bytes  | Content                                                             |
+//! |--------|---------------------------------------------------------------------|
+//! | 0..8   | the magic bytes `WTGRAPHS`                                          |
+//! | 8..12  | the format version as little endian u32, currently `FORMAT_VERSION` |
+//! | 12     | the type of the graph, see `GraphKind`                              |
+//! | 13     | flags; bit 0 is set if the file contains uncommitted edits          |
+//! | 14..16 | reserved, zero                                                      |
+//!
+//! The header is followed by the sections of the graph, each encoded with bincode (fixed size little endian integers):
+//! the number of vertices and edges, the versions of qwt and vers-vecs, the QW-tree of the adjacency sequence,
+//! the `starting_indices` bitmap, the deleted vertices, then the labels (labeled graphs) and the weights (weighted graphs).
+//! The QW-tree and the bitmap are stored with their rank and select support, so loading a graph doesn't rebuild the wavelet tree.
+//! Files saved with `save_with_edits` end with the uncommitted edits.
+//!
+//! The QW-tree and the bitmap are written in the serde layouts of qwt and vers-vecs, which aren't part of their
+//! stable API. So both crates are pinned to exact versions, and `load` refuses files written with other versions
+//! with `PersistError::UnsupportedDependencies`. Updating either crate needs a new `FORMAT_VERSION`.
+//! `load` checks that the sections fit together, e.g. that every deleted vertex, label and weight belongs to an index
+//! of the graph, but it doesn't read the whole QW-tree.
+//!
+//! `save` refuses to save a graph with uncommitted edits, because they would be lost silently.
+//! Either call `commit_edits` first or use `save_with_edits` to keep them.
+//!
+//! # Example
+//!
+//! ```rust
+//! use wt_graphs::prelude::*;
+//!
+//! let mut wtd = WTDigraph::from_digraph(Digraph::from_adjacency_list(2, 1, vec![vec![1], vec![]]));
+//! let mut file = Vec::new();
+//! wtd.save(&mut file).unwrap();
+//!
+//! let loaded = WTDigraph::load(file.as_slice()).unwrap();
+//! assert!(loaded.edge_exists(0, 1));
+//!
+//! wtd.add_edge(1, 0);
+//! assert!(wtd.save(Vec::new()).is_err()); // the new edge isn't committed
+//! wtd.save_with_edits(Vec::new()).unwrap();
+//! ```
+
+use crate::wt::policy::AutoCommit;
+use crate::wt::{
+    directed::WTDigraph, labeled_directed::LabeledWTDigraph, labeled_undirected::LabeledWTUGraph,
+    labeled_weighted_directed::LabeledWeightedWTDigraph,
+    labeled_weighted_undirected::LabeledWeightedWTUGraph, undirected::WTUGraph,
+    weighted_directed::WeightedWTDigraph, weighted_undirected::WeightedWTUGraph,
+};
+use crate::Edit;
+use num::Num;
+use qwt::QWT256;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::io::{self, Read, Write};
+use vers_vecs::RsVec;
+
+#[cfg(test)]
+mod test;
+
+/// The magic bytes every saved graph starts with.
+pub const MAGIC: &[u8; 8] = b"WTGRAPHS";
+
+/// The version of the format written by `save`. `load` only reads files of this version.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// The versions of the crates whose serde layouts are saved, as recorded in every file.
+/// They have to match the exact versions in Cargo.toml.
+pub const DEPENDENCIES: &str = "qwt 0.1.32, vers-vecs 1.3.1";
+
+const FLAG_EDITS: u8 = 1;
+
+/// The type of graph stored in a file, byte 12 of the header.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum GraphKind {
+    WTDigraph = 1,
+    WTUGraph = 2,
+    WeightedWTDigraph = 3,
+    WeightedWTUGraph = 4,
+    LabeledWTDigraph = 5,
+    LabeledWTUGraph = 6,
+    LabeledWeightedWTDigraph = 7,
+    LabeledWeightedWTUGraph = 8,
+}
+
+impl GraphKind {
+    pub(crate) fn from_byte(byte: u8) -> Option<Self> {
+        let kind = match byte {
+            1 => GraphKind::WTDigraph,
+            2 => GraphKind::WTUGraph,
+            3 => GraphKind::WeightedWTDigraph,
+            4 => GraphKind::WeightedWTUGraph,
+            5 => GraphKind::LabeledWTDigraph,
+            6 => GraphKind::LabeledWTUGraph,
+            7 => GraphKind::LabeledWeightedWTDigraph,
+            8 => GraphKind::LabeledWeightedWTUGraph,
+            _ => return None,
+        };
+        Some(kind)
+    }
+}
+
+/// Error returned by `save`, `save_with_edits` and `load`.
+#[derive(Debug)]
+pub enum PersistError {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// `save` was called on a graph with uncommitted edits.
+    UncommittedEdits,
+    /// The input doesn't start with `MAGIC`.
+    NotAGraph,
+    /// The input was written in another version of the format.
+    UnsupportedVersion(u32),
+    /// The input holds another type of graph.
+    WrongKind { expected: GraphKind, found: u8 },
+    /// The input was written with other versions of qwt or vers-vecs, given as recorded in the input.
+    UnsupportedDependencies(String),
+    /// The content of the input is damaged.
+    Corrupt(String),
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PersistError::Io(e) => write!(f, "i/o error: {}", e),
+            PersistError::UncommittedEdits => write!(
+                f,
+                "graph has uncommitted edits, commit them or use save_with_edits"
+            ),
+            PersistError::NotAGraph => write!(f, "input is not a saved graph"),
+            PersistError::UnsupportedVersion(version) => {
+                write!(f, "unsupported format version {}", version)
+            }
+            PersistError::WrongKind { expected, found } => write!(
+                f,
+                "expected a {:?}, but the input holds graph type {}",
+                expected, found
+            ),
+            PersistError::UnsupportedDependencies(found) => write!(
+                f,
+                "input was written with {}, but this build uses {}",
+                found, DEPENDENCIES
+            ),
+            PersistError::Corrupt(message) => write!(f, "input is corrupt: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for PersistError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PersistError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for PersistError {
+    fn from(e: io::Error) -> Self {
+        PersistError::Io(e)
+    }
+}
+
+impl From<bincode::Error> for PersistError {
+    fn from(e: bincode::Error) -> Self {
+        match *e {
+            bincode::ErrorKind::Io(e) => PersistError::Io(e),
+            e => PersistError::Corrupt(e.to_string()),
+        }
+    }
+}
+
+impl WTDigraph {
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::WTDigraph,
+            self.has_uncommitted_edits,
+            false,
+            |w, edits| put_digraph(w, self, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(writer, GraphKind::WTDigraph, false, true, |w, edits| {
+            put_digraph(w, self, edits)
+        })
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        load(reader, GraphKind::WTDigraph, take_digraph)
+    }
+}
+
+impl WTUGraph {
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let wtd = &self.wtd;
+        save(
+            writer,
+            GraphKind::WTUGraph,
+            wtd.has_uncommitted_edits,
+            false,
+            |w, edits| put_digraph(w, wtd, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(writer, GraphKind::WTUGraph, false, true, |w, edits| {
+            put_digraph(w, &self.wtd, edits)
+        })
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        let wtd = load(reader, GraphKind::WTUGraph, take_digraph)?;
+        Ok(WTUGraph { wtd })
+    }
+}
+
+impl<W> WeightedWTDigraph<W>
+where
+    W: Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let edits = self.dg.has_uncommitted_edits || !self.weights_uncommitted.is_empty();
+        save(
+            writer,
+            GraphKind::WeightedWTDigraph,
+            edits,
+            false,
+            |w, edits| put_weighted(w, self, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::WeightedWTDigraph,
+            false,
+            true,
+            |w, edits| put_weighted(w, self, edits),
+        )
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        load(reader, GraphKind::WeightedWTDigraph, take_weighted)
+    }
+}
+
+impl<W> WeightedWTUGraph<W>
+where
+    W: Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let wdg = &self.wdg;
+        let edits = wdg.dg.has_uncommitted_edits || !wdg.weights_uncommitted.is_empty();
+        save(
+            writer,
+            GraphKind::WeightedWTUGraph,
+            edits,
+            false,
+            |w, edits| put_weighted(w, wdg, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::WeightedWTUGraph,
+            false,
+            true,
+            |w, edits| put_weighted(w, &self.wdg, edits),
+        )
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        let wdg = load(reader, GraphKind::WeightedWTUGraph, take_weighted)?;
+        Ok(WeightedWTUGraph { wdg })
+    }
+}
+
+impl<L> LabeledWTDigraph<L>
+where
+    L: Hash + Clone + Eq + Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::LabeledWTDigraph,
+            labeled_edits(self),
+            false,
+            |w, edits| put_labeled(w, self, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::LabeledWTDigraph,
+            false,
+            true,
+            |w, edits| put_labeled(w, self, edits),
+        )
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        load(reader, GraphKind::LabeledWTDigraph, take_labeled)
+    }
+}
+
+impl<L> LabeledWTUGraph<L>
+where
+    L: Hash + Clone + Eq + Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let ldg = &self.ldg;
+        save(
+            writer,
+            GraphKind::LabeledWTUGraph,
+            labeled_edits(ldg),
+            false,
+            |w, edits| put_labeled(w, ldg, edits),
+        )
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        save(
+            writer,
+            GraphKind::LabeledWTUGraph,
+            false,
+            true,
+            |w, edits| put_labeled(w, &self.ldg, edits),
+        )
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        let ldg = load(reader, GraphKind::LabeledWTUGraph, take_labeled)?;
+        Ok(LabeledWTUGraph { ldg })
+    }
+}
+
+impl<L, W> LabeledWeightedWTDigraph<L, W>
+where
+    L: Hash + Clone + Eq + Serialize + DeserializeOwned,
+    W: Num + Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let edits = labeled_edits(&self.ldg) || !self.weights_uncommitted.is_empty();
+        let kind = GraphKind::LabeledWeightedWTDigraph;
+        save(writer, kind, edits, false, |w, edits| {
+            put_labeled_weighted(w, self, edits)
+        })
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let kind = GraphKind::LabeledWeightedWTDigraph;
+        save(writer, kind, false, true, |w, edits| {
+            put_labeled_weighted(w, self, edits)
+        })
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        load(
+            reader,
+            GraphKind::LabeledWeightedWTDigraph,
+            take_labeled_weighted,
+        )
+    }
+}
+
+impl<L, W> LabeledWeightedWTUGraph<L, W>
+where
+    L: Hash + Clone + Eq + Serialize + DeserializeOwned,
+    W: Num + Serialize + DeserializeOwned,
+{
+    /// Saves the graph at the last commit. Fails with `PersistError::UncommittedEdits` if the graph has uncommitted edits.
+    pub fn save<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let lwdg = &self.lwdg;
+        let edits = labeled_edits(&lwdg.ldg) || !lwdg.weights_uncommitted.is_empty();
+        let kind = GraphKind::LabeledWeightedWTUGraph;
+        save(writer, kind, edits, false, |w, edits| {
+            put_labeled_weighted(w, lwdg, edits)
+        })
+    }
+
+    /// Saves the graph together with its uncommitted edits.
+    pub fn save_with_edits<Wr: Write>(&self, writer: Wr) -> Result<(), PersistError> {
+        let kind = GraphKind::LabeledWeightedWTUGraph;
+        save(writer, kind, false, true, |w, edits| {
+            put_labeled_weighted(w, &self.lwdg, edits)
+        })
+    }
+
+    /// Loads a graph saved with `save` or `save_with_edits`.
+    pub fn load<R: Read>(reader: R) -> Result<Self, PersistError> {
+        let lwdg = load(
+            reader,
+            GraphKind::LabeledWeightedWTUGraph,
+            take_labeled_weighted,
+        )?;
+        Ok(LabeledWeightedWTUGraph { lwdg })
+    }
+}
+
+// writes the header and then the sections written by `put`, which gets told whether to include the uncommitted edits.
+// refuses if `has_edits` is true, i.e. if the graph has uncommitted edits which would be lost
+fn save<Wr, F>(
+    mut writer: Wr,
+    kind: GraphKind,
+    has_edits: bool,
+    with_edits: bool,
+    put: F,
+) -> Result<(), PersistError>
+where
+    Wr: Write,
+    F: FnOnce(&mut Wr, bool) -> Result<(), PersistError>,
+{
+    if has_edits {
+        return Err(PersistError::UncommittedEdits);
+    }
+    let mut header = [0u8; 16];
+    header[0..8].copy_from_slice(MAGIC);
+    header[8..12].copy_from_slice(&FORMAT_VERSION.to_le_bytes());
+    header[12] = kind as u8;
+    header[13] = if with_edits { FLAG_EDITS } else { 0 };
+    writer.write_all(&header)?;
+    put(&mut writer, with_edits)?;
+    writer.flush()?;
+    Ok(())
+}
+
+// checks the header and reads the sections with `take`, which gets told whether the file contains uncommitted edits
+fn load<R, T, F>(mut reader: R, kind: GraphKind, take: F) -> Result<T, PersistError>
+where
+    R: Read,
+    F: FnOnce(&mut R, bool) -> Result<T, PersistError>,
+{
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => PersistError::NotAGraph,
+        _ => PersistError::Io(e),
+    })?;
+    if &header[0..8] != MAGIC {
+        return Err(PersistError::NotAGraph);
+    }
+    let version = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+    if version != FORMAT_VERSION {
+        return Err(PersistError::UnsupportedVersion(version));
+    }
+    if GraphKind::from_byte(header[12]) != Some(kind) {
+        return Err(PersistError::WrongKind {
+            expected: kind,
+            found: header[12],
+        });
+    }
+    take(&mut reader, header[13] & FLAG_EDITS != 0)
+}
+
+fn put<Wr: Write, T: Serialize + ?Sized>(writer: &mut Wr, value: &T) -> Result<(), PersistError> {
+    Ok(bincode::serialize_into(writer, value)?)
+}
+
+fn take<R: Read, T: DeserializeOwned>(reader: &mut R) -> Result<T, PersistError> {
+    Ok(bincode::deserialize_from(reader)?)
+}
+
+fn corrupt(message: &str) -> PersistError {
+    PersistError::Corrupt(message.to_string())
+}
+
+// the number of indices a vertex of the graph can have, with or without the uncommitted edits
+fn index_space(dg: &WTDigraph) -> usize {
+    dg.wt_adj_len.max(dg.wt_adj_len_updated)
+}
+
+// the entries of a map sorted by their keys, so that saving the same graph always gives the same bytes
+fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+// like sorted, for maps whose keys can't be ordered, e.g. labels: the entries are sorted by their encoding
+fn sorted_by_encoding<K, V>(map: &HashMap<K, V>) -> Result<Vec<(&K, &V)>, PersistError>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    let mut entries = Vec::with_capacity(map.len());
+    for entry in map.iter() {
+        entries.push((bincode::serialize(&entry)?, entry));
+    }
+    entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+    Ok(entries.into_iter().map(|(_, entry)| entry).collect())
+}
+
+fn put_digraph<Wr: Write>(
+    writer: &mut Wr,
+    dg: &WTDigraph,
+    with_edits: bool,
+) -> Result<(), PersistError> {
+    put(writer, &dg.wt_adj_len)?;
+    put(writer, &dg.e_count)?;
+    put(writer, DEPENDENCIES)?;
+    put(writer, &dg.wt_adj)?;
+    put(writer, &dg.starting_indices)?;
+    let mut deleted_vertices: Vec<usize> = dg.deleted_vertices.keys().copied().collect();
+    deleted_vertices.sort_unstable();
+    put(writer, &deleted_vertices)?;
+    if with_edits {
+        put(writer, &dg.wt_adj_len_updated)?;
+        put(writer, &dg.e_count_updated)?;
+        put(writer, &sorted(&dg.deleted_vertices_uncommitted))?;
+        put(writer, &sorted(&dg.adj_uncommitted))?;
+        put(writer, &dg.has_uncommitted_edits)?;
+    }
+    Ok(())
+}
+
+fn take_digraph<R: Read>(reader: &mut R, with_edits: bool) -> Result<WTDigraph, PersistError> {
+    let wt_adj_len: usize = take(reader)?;
+    let e_count: usize = take(reader)?;
+    let dependencies: String = take(reader)?;
+    if dependencies != DEPENDENCIES {
+        return Err(PersistError::UnsupportedDependencies(dependencies));
+    }
+    let wt_adj: QWT256<usize> = take(reader)?;
+    let starting_indices: RsVec = take(reader)?;
+    // every vertex is a 1 in starting_indices, every edge a 0
+    let ones = starting_indices.rank1(starting_indices.len());
+    if ones != wt_adj_len || starting_indices.len() - ones != wt_adj.len() {
+        return Err(PersistError::Corrupt(
+            "starting_indices doesn't match the adjacency sequence".to_string(),
+        ));
+    }
+    if e_count != wt_adj.len() {
+        return Err(PersistError::Corrupt(
+            "the number of edges doesn't match starting_indices".to_string(),
+        ));
+    }
+    let deleted_vertices: Vec<usize> = take(reader)?;
+    if deleted_vertices.iter().any(|&v| v >= wt_adj_len) {
+        return Err(corrupt("a deleted vertex is out of bounds"));
+    }
+    let mut dg = WTDigraph {
+        wt_adj_len,
+        e_count,
+        wt_adj_len_updated: wt_adj_len,
+        e_count_updated: e_count,
+        wt_adj,
+        starting_indices,
+        deleted_vertices: deleted_vertices.into_iter().map(|v| (v, true)).collect(),
+        deleted_vertices_uncommitted: HashMap::new(),
+        adj_uncommitted: HashMap::new(),
+        has_uncommitted_edits: false,
+        pending_edits: 0,
+        auto_commit: AutoCommit::default(),
+    };
+    if with_edits {
+        dg.wt_adj_len_updated = take(reader)?;
+        dg.e_count_updated = take(reader)?;
+        let deleted_vertices_uncommitted: Vec<(usize, bool)> = take(reader)?;
+        if deleted_vertices_uncommitted
+            .iter()
+            .any(|&(v, _)| v >= index_space(&dg))
+        {
+            return Err(corrupt("a deleted vertex is out of bounds"));
+        }
+        dg.deleted_vertices_uncommitted = deleted_vertices_uncommitted.into_iter().collect();
+        let adj_uncommitted: Vec<(usize, Vec<Edit<usize>>)> = take(reader)?;
+        if adj_uncommitted.iter().any(|(v, _)| *v >= index_space(&dg)) {
+            return Err(corrupt("an edited vertex is out of bounds"));
+        }
+        dg.adj_uncommitted = adj_uncommitted.into_iter().collect();
+        dg.has_uncommitted_edits = take(reader)?;
+        // the number of edits isn't saved, every saved change counts as one
+        dg.pending_edits = dg.deleted_vertices_uncommitted.len()
+            + dg.adj_uncommitted.values().map(Vec::len).sum::<usize>();
+    }
+    Ok(dg)
+}
+
+fn put_weighted<Wr, W>(
+    writer: &mut Wr,
+    wdg: &WeightedWTDigraph<W>,
+    with_edits: bool,
+) -> Result<(), PersistError>
+where
+    Wr: Write,
+    W: Serialize,
+{
+    put_digraph(writer, &wdg.dg, with_edits)?;
+    put(writer, &sorted(&wdg.weights))?;
+    if with_edits {
+        put(writer, &sorted(&wdg.weights_uncommitted))?;
+    }
+    Ok(())
+}
+
+fn take_weighted<R, W>(
+    reader: &mut R,
+    with_edits: bool,
+) -> Result<WeightedWTDigraph<W>, PersistError>
+where
+    R: Read,
+    W: DeserializeOwned,
+{
+    let dg = take_digraph(reader, with_edits)?;
+    let weights: Vec<((usize, usize), W)> = take(reader)?;
+    check_weights(&weights, dg.wt_adj_len)?;
+    let mut weights_uncommitted = HashMap::new();
+    if with_edits {
+        let edits: Vec<((usize, usize), Edit<W>)> = take(reader)?;
+        check_weights(&edits, index_space(&dg))?;
+        weights_uncommitted = edits.into_iter().collect();
+    }
+    Ok(WeightedWTDigraph {
+        dg,
+        weights_uncommitted,
+        weights: weights.into_iter().collect(),
+        auto_commit: AutoCommit::default(),
+    })
+}
+
+// fails if a weight belongs to an edge from or to an index which isn't smaller than `len`
+fn check_weights<T>(weights: &[((usize, usize), T)], len: usize) -> Result<(), PersistError> {
+    if weights
+        .iter()
+        .any(|((from, to), _)| *from >= len || *to >= len)
+    {
+        return Err(corrupt("a weight belongs to an edge out of bounds"));
+    }
+    Ok(())
+}
+
+// true if the labeled graph has uncommitted edits, which may only change its labels
+fn labeled_edits<L>(ldg: &LabeledWTDigraph<L>) -> bool
+where
+    L: Hash + Clone + Eq,
+{
+    ldg.dg.has_uncommitted_edits
+        || !ldg.index_label_uncommitted.is_empty()
+        || !ldg.label_index_uncommitted.is_empty()
+}
+
+fn put_labeled<Wr, L>(
+    writer: &mut Wr,
+    ldg: &LabeledWTDigraph<L>,
+    with_edits: bool,
+) -> Result<(), PersistError>
+where
+    Wr: Write,
+    L: Hash + Clone + Eq + Serialize,
+{
+    put_digraph(writer, &ldg.dg, with_edits)?;
+    put(writer, &ldg.index_label)?;
+    // label_index is saved as well, since it doesn't contain the labels of deleted vertices
+    let mut label_index: Vec<(&L, &usize)> = ldg.label_index.iter().collect();
+    label_index.sort_unstable_by_key(|(_, index)| **index);
+    put(writer, &label_index)?;
+    if with_edits {
+        put(writer, &sorted(&ldg.index_label_uncommitted))?;
+        put(writer, &sorted_by_encoding(&ldg.label_index_uncommitted)?)?;
+    }
+    Ok(())
+}
+
+fn take_labeled<R, L>(reader: &mut R, with_edits: bool) -> Result<LabeledWTDigraph<L>, PersistError>
+where
+    R: Read,
+    L: Hash + Clone + Eq + DeserializeOwned,
+{
+    let dg = take_digraph(reader, with_edits)?;
+    let index_label: Vec<L> = take(reader)?;
+    let label_index: Vec<(L, usize)> = take(reader)?;
+    if label_index
+        .iter()
+        .any(|(_, index)| *index >= dg.wt_adj_len || *index >= index_label.len())
+    {
+        return Err(corrupt("a label belongs to a vertex out of bounds"));
+    }
+    let mut ldg = LabeledWTDigraph {
+        dg,
+        index_label,
+        index_label_uncommitted: HashMap::new(),
+        label_index: label_index.into_iter().collect(),
+        label_index_uncommitted: HashMap::new(),
+        auto_commit: AutoCommit::default(),
+    };
+    if with_edits {
+        let index_label_uncommitted: Vec<(usize, Edit<L>)> = take(reader)?;
+        if index_label_uncommitted
+            .iter()
+            .any(|(index, _)| *index >= index_space(&ldg.dg))
+        {
+            return Err(corrupt("a label belongs to a vertex out of bounds"));
+        }
+        ldg.index_label_uncommitted = index_label_uncommitted.into_iter().collect();
+        let label_index_uncommitted: Vec<(L, Edit<usize>)> = take(reader)?;
+        ldg.label_index_uncommitted = label_index_uncommitted.into_iter().collect();
+    }
+    Ok(ldg)
+}
+
+fn put_labeled_weighted<Wr, L, W>(
+    writer: &mut Wr,
+    lwdg: &LabeledWeightedWTDigraph<L, W>,
+    with_edits: bool,
+) -> Result<(), PersistError>
+where
+    Wr: Write,
+    L: Hash + Clone + Eq + Serialize,
+    W: Num + Serialize,
+{
+    put_labeled(writer, &lwdg.ldg, with_edits)?;
+    put(writer, &sorted(&lwdg.weights))?;
+    if with_edits {
+        put(writer, &sorted(&lwdg.weights_uncommitted))?;
+    }
+    Ok(())
+}
+
+fn take_labeled_weighted<R, L, W>(
+    reader: &mut R,
+    with_edits: bool,
+) -> Result<LabeledWeightedWTDigraph<L, W>, PersistError>
+where
+    R: Read,
+    L: Hash + Clone + Eq + DeserializeOwned,
+    W: Num + DeserializeOwned,
+{
+    let ldg = take_labeled(reader, with_edits)?;
+    let weights: Vec<((usize, usize), W)> = take(reader)?;
+    check_weights(&weights, ldg.dg.wt_adj_len)?;
+    let mut weights_uncommitted = HashMap::new();
+    if with_edits {
+        let edits: Vec<((usize, usize), Edit<W>)> = take(reader)?;
+        check_weights(&edits, index_space(&ldg.dg))?;
+        weights_uncommitted = edits.into_iter().collect();
+    }
+    Ok(LabeledWeightedWTDigraph {
+        ldg,
+        weights_uncommitted,
+        weights: weights.into_iter().collect(),
+        auto_commit: AutoCommit::default(),
+    })
+}
@@ -0,0 +1,312 @@
+use crate::graph::{
+    directed::Digraph, labeled_weighted_undirected::LabeledWeightedUGraph,
+    weighted_directed::WeightedDigraph,
+};
+use crate::io::{
+    read_labeled_wt_digraph, write_labeled_weighted_wt_ugraph_updated, write_labeled_wt_digraph,
+    write_labeled_wt_digraph_updated, write_weighted_wt_digraph_updated, write_wt_digraph,
+    write_wt_digraph_updated,
+};
+use crate::traits::{
+    Directed, Graph, Labeled, Unlabeled, Unweighted, WTDirected, WTWeighted, Weighted, WT,
+};
+use crate::wt::persist::*;
+use crate::wt::{
+    directed::WTDigraph, labeled_directed::LabeledWTDigraph,
+    labeled_weighted_undirected::LabeledWeightedWTUGraph, undirected::WTUGraph,
+    weighted_directed::WeightedWTDigraph,
+};
+
+fn wtd() -> WTDigraph {
+    let adj = vec![vec![1, 2], vec![2], vec![0, 0], vec![]];
+    WTDigraph::from_digraph(Digraph::from_adjacency_list(4, 5, adj))
+}
+
+fn text(write: impl FnOnce(&mut Vec<u8>) -> std::io::Result<()>) -> String {
+    let mut buffer = Vec::new();
+    write(&mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn save_load() {
+    let mut wtd = wtd();
+    wtd.delete_vertex(3);
+    wtd.commit_edits();
+    let mut file = Vec::new();
+    wtd.save(&mut file).unwrap();
+    assert_eq!(&file[0..8], MAGIC);
+    assert_eq!(file[12], GraphKind::WTDigraph as u8);
+    assert_eq!(file[13], 0);
+
+    let loaded = WTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.v_count(), wtd.v_count());
+    assert_eq!(loaded.e_count(), 5);
+    assert!(!loaded.vertex_exists(3));
+    assert_eq!(loaded.incoming_edges(0), vec![2, 2]);
+    assert_eq!(
+        text(|w| write_wt_digraph(w, &loaded)),
+        text(|w| write_wt_digraph(w, &wtd))
+    );
+
+    // saving the same graph gives the same bytes
+    let mut again = Vec::new();
+    loaded.save(&mut again).unwrap();
+    assert_eq!(again, file);
+
+    let wtu = WTUGraph::from(
+        vec![1, 1],
+        vers_vecs::RsVec::from_bit_vec(vec_bits(&[1, 0, 0, 1])),
+    );
+    let mut file = Vec::new();
+    wtu.save(&mut file).unwrap();
+    let loaded = WTUGraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.e_count(), 2);
+    assert!(loaded.edge_exists(1, 0));
+}
+
+// builds a bitvector from 0s and 1s
+fn vec_bits(bits: &[u64]) -> vers_vecs::BitVec {
+    let mut bv = vers_vecs::BitVec::new();
+    for bit in bits {
+        bv.append(*bit == 1);
+    }
+    bv
+}
+
+#[test]
+fn save_load_weighted_and_labeled() {
+    let wdg = WeightedDigraph::from_adjacency_list(2, 1, vec![vec![(1, 0.5)], vec![]]);
+    let mut wtwd: WeightedWTDigraph<f64> = WeightedWTDigraph::from_weighted_digraph(wdg);
+    let mut file = Vec::new();
+    wtwd.save(&mut file).unwrap();
+    let loaded: WeightedWTDigraph<f64> = WeightedWTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.weight(0, 1), 0.5);
+
+    // editing a weight is an uncommitted edit
+    wtwd.edit_weight(0, 1, 1.5);
+    assert!(matches!(
+        wtwd.save(Vec::new()),
+        Err(PersistError::UncommittedEdits)
+    ));
+
+    let ldg = read_labeled_wt_digraph::<_, String>("3\n2\na b\nb c\n".as_bytes()).unwrap();
+    let mut file = Vec::new();
+    ldg.save(&mut file).unwrap();
+    let loaded: LabeledWTDigraph<String> = LabeledWTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.label(2), Some(&"c".to_string()));
+    assert_eq!(loaded.index(&"b".to_string()), Some(1));
+    assert_eq!(
+        text(|w| write_labeled_wt_digraph(w, &loaded)),
+        text(|w| write_labeled_wt_digraph(w, &ldg))
+    );
+
+    let lwug: LabeledWeightedUGraph<String, i64> =
+        crate::io::read_labeled_weighted_ugraph("3\n2\nx y -1\nz x 7\n".as_bytes()).unwrap();
+    let lwwtu = LabeledWeightedWTUGraph::from_labeled_weighted_ugraph(lwug);
+    let mut file = Vec::new();
+    lwwtu.save(&mut file).unwrap();
+    let loaded: LabeledWeightedWTUGraph<String, i64> =
+        LabeledWeightedWTUGraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.weight("x".to_string(), "z".to_string()), 7);
+    assert_eq!(
+        text(|w| write_labeled_weighted_wt_ugraph_updated(w, &loaded)),
+        text(|w| write_labeled_weighted_wt_ugraph_updated(w, &lwwtu))
+    );
+}
+
+#[test]
+fn save_with_edits() {
+    let mut wtd = wtd();
+    wtd.add_edge(3, 1);
+    wtd.delete_edge(0, 2);
+    let append = wtd.append_vertex();
+    assert!(matches!(
+        wtd.save(Vec::new()),
+        Err(PersistError::UncommittedEdits)
+    ));
+
+    let mut file = Vec::new();
+    wtd.save_with_edits(&mut file).unwrap();
+    assert_eq!(file[13], 1);
+    let mut loaded = WTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.outgoing_edges_updated(3), vec![1]);
+    assert!(loaded.vertex_exists_updated(append));
+    assert!(!loaded.vertex_exists(append));
+    assert_eq!(
+        text(|w| write_wt_digraph_updated(w, &loaded)),
+        text(|w| write_wt_digraph_updated(w, &wtd))
+    );
+    // the edits can still be committed after loading
+    loaded.commit_edits();
+    wtd.commit_edits();
+    assert_eq!(
+        text(|w| write_wt_digraph(w, &loaded)),
+        text(|w| write_wt_digraph(w, &wtd))
+    );
+
+    let wdg = WeightedDigraph::from_adjacency_list(2, 1, vec![vec![(1, 2)], vec![]]);
+    let mut wtwd: WeightedWTDigraph<u8> = WeightedWTDigraph::from_weighted_digraph(wdg);
+    wtwd.add_edge(1, 0, 3);
+    let mut file = Vec::new();
+    wtwd.save_with_edits(&mut file).unwrap();
+    let loaded: WeightedWTDigraph<u8> = WeightedWTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(loaded.weight_updated(1, 0), 3);
+    assert_eq!(
+        text(|w| write_weighted_wt_digraph_updated(w, &loaded)),
+        text(|w| write_weighted_wt_digraph_updated(w, &wtwd))
+    );
+
+    let mut ldg = read_labeled_wt_digraph::<_, String>("2\n1\na b\n".as_bytes()).unwrap();
+    ldg.add_vertex("c".to_string());
+    ldg.add_edge("c".to_string(), "a".to_string());
+    let mut file = Vec::new();
+    ldg.save_with_edits(&mut file).unwrap();
+    let loaded: LabeledWTDigraph<String> = LabeledWTDigraph::load(file.as_slice()).unwrap();
+    assert_eq!(
+        text(|w| write_labeled_wt_digraph_updated(w, &loaded)),
+        "3\n2\na\nb\nc\na\tb\nc\ta\n"
+    );
+}
+
+#[test]
+fn load_errors() {
+    let mut file = Vec::new();
+    wtd().save(&mut file).unwrap();
+
+    assert!(matches!(
+        WTDigraph::load(&b"not a graph"[..]),
+        Err(PersistError::NotAGraph)
+    ));
+    assert!(matches!(
+        WTUGraph::load(file.as_slice()),
+        Err(PersistError::WrongKind {
+            expected: GraphKind::WTUGraph,
+            found: 1
+        })
+    ));
+
+    let mut newer = file.clone();
+    newer[8] = FORMAT_VERSION as u8 + 1;
+    assert!(matches!(
+        WTDigraph::load(newer.as_slice()),
+        Err(PersistError::UnsupportedVersion(version)) if version == FORMAT_VERSION + 1
+    ));
+
+    // a truncated file
+    assert!(matches!(
+        WTDigraph::load(&file[..file.len() - 3]),
+        Err(PersistError::Io(_))
+    ));
+
+    // the number of vertices doesn't match the bitmap
+    let mut damaged = file.clone();
+    damaged[16] = 9;
+    assert!(matches!(
+        WTDigraph::load(damaged.as_slice()),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the number of edges doesn't match the bitmap, it follows the number of vertices
+    let mut damaged = file.clone();
+    damaged[24] = 4;
+    assert!(matches!(
+        WTDigraph::load(damaged.as_slice()),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the versions of the dependencies follow the number of edges, after the length of the string
+    let mut other = file.clone();
+    assert_eq!(&other[40..40 + DEPENDENCIES.len()], DEPENDENCIES.as_bytes());
+    other[40 + "qwt 0.1.3".len()] = b'1';
+    assert!(matches!(
+        WTDigraph::load(other.as_slice()),
+        Err(PersistError::UnsupportedDependencies(found)) if found == "qwt 0.1.31, vers-vecs 1.3.1"
+    ));
+}
+
+#[test]
+fn load_out_of_bounds() {
+    // the deleted vertices are the last section
+    let mut wtd = wtd();
+    wtd.delete_vertex(3);
+    wtd.commit_edits();
+    let mut file = Vec::new();
+    wtd.save(&mut file).unwrap();
+    let len = file.len();
+    file[len - 8] = 4;
+    assert!(matches!(
+        WTDigraph::load(file.as_slice()),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the weights are the last section, the last one ends with its edge and the f64
+    let wdg = WeightedDigraph::from_adjacency_list(2, 1, vec![vec![(1, 0.5)], vec![]]);
+    let wtwd: WeightedWTDigraph<f64> = WeightedWTDigraph::from_weighted_digraph(wdg);
+    let mut file = Vec::new();
+    wtwd.save(&mut file).unwrap();
+    let len = file.len();
+    file[len - 16] = 2;
+    assert!(matches!(
+        WeightedWTDigraph::<f64>::load(file.as_slice()),
+        Err(PersistError::Corrupt(_))
+    ));
+
+    // the label index is the last section, sorted by index
+    let ldg = read_labeled_wt_digraph::<_, String>("3\n2\na b\nb c\n".as_bytes()).unwrap();
+    let mut file = Vec::new();
+    ldg.save(&mut file).unwrap();
+    let len = file.len();
+    file[len - 8] = 3;
+    assert!(matches!(
+        LabeledWTDigraph::<String>::load(file.as_slice()),
+        Err(PersistError::Corrupt(_))
+    ));
+}
+
+#[test]
+fn dependencies_are_pinned() {
+    // the recorded versions have to be the ones in Cargo.toml
+    let manifest = include_str!("../../../Cargo.toml");
+    for dependency in DEPENDENCIES.split(", ") {
+        let (name, version) = dependency.split_once(' ').unwrap();
+        let line = manifest
+            .lines()
+            .find(|line| line.starts_with(&format!("{} = ", name)))
+            .unwrap();
+        assert!(line.contains(&format!("\"={}\"", version)), "{}", line);
+    }
+}
+
+#[test]
+fn save_is_deterministic() {
+    // every graph gets its own hash maps, which iterate in a different order
+    let edited = || {
+        let mut ldg = read_labeled_wt_digraph::<_, String>("3\n2\na b\nb c\n".as_bytes()).unwrap();
+        for label in ["d", "e", "f", "g", "h"] {
+            ldg.add_vertex(label.to_string());
+        }
+        ldg.edit_label("a".to_string(), "x".to_string());
+        ldg.edit_label("b".to_string(), "y".to_string());
+        ldg.delete_vertex("c".to_string());
+        let mut file = Vec::new();
+        ldg.save_with_edits(&mut file).unwrap();
+        file
+    };
+    let file = edited();
+    for _ in 0..10 {
+        assert_eq!(edited(), file);
+    }
+    let loaded: LabeledWTDigraph<String> = LabeledWTDigraph::load(file.as_slice()).unwrap();
+    let mut saved = Vec::new();
+    loaded.save_with_edits(&mut saved).unwrap();
+    assert_eq!(saved, file);
+
+    // e_count still matches the bitmap after a deleted vertex with edges has been committed
+    let mut wtd = wtd();
+    wtd.delete_vertex(2);
+    wtd.commit_edits();
+    let mut file = Vec::new();
+    wtd.save(&mut file).unwrap();
+    assert_eq!(WTDigraph::load(file.as_slice()).unwrap().e_count(), 3);
+}
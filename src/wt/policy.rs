@@ -0,0 +1,57 @@
+// The state behind traits::WTCommitPolicy.
+// The wt-digraph and every wt-graph with uncommitted changes of its own (weights, labels) holds one and commits itself,
+// the wt-graphs inside of it keep CommitPolicy::Manual. Undirected wt-graphs forward the policy to the wt-graph they wrap.
+
+use crate::traits::{CommitEvent, CommitHook, CommitPolicy};
+use crate::wt::directed::WTDigraph;
+use std::fmt;
+
+#[cfg(test)]
+mod test;
+
+#[derive(Clone, Default)]
+pub(crate) struct AutoCommit {
+    pub(crate) policy: CommitPolicy,
+    pub(crate) hook: Option<CommitHook>,
+}
+
+impl AutoCommit {
+    // returns the event of the commit if the pending edits of `dg` call for one
+    pub(crate) fn due(&self, dg: &WTDigraph) -> Option<CommitEvent> {
+        let pending_edits = dg.pending_edits();
+        if pending_edits == 0 {
+            return None;
+        }
+        let read_cost = dg.updated_read_cost();
+        let due = match self.policy {
+            CommitPolicy::Manual => false,
+            CommitPolicy::AfterEdits(edits) => pending_edits >= edits,
+            CommitPolicy::EditFraction(fraction) => {
+                pending_edits as f64 >= fraction * dg.e_count as f64
+            }
+            CommitPolicy::ReadCost(budget) => read_cost > budget,
+        };
+        due.then_some(CommitEvent {
+            policy: self.policy,
+            pending_edits,
+            read_cost,
+        })
+    }
+
+    // calls the hook, after the commit
+    pub(crate) fn notify(&self, event: &CommitEvent) {
+        if let Some(hook) = &self.hook {
+            hook(event);
+        }
+    }
+}
+
+// the hook can't be printed
+impl fmt::Debug for AutoCommit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AutoCommit")
+            .field("policy", &self.policy)
+            .field("hook", &self.hook.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,264 @@
+use crate::io::*;
+use crate::traits::*;
+use crate::wt::directed::WTDigraph;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+const TEXT: &str = "4\n3\n0 1\n1 2\n2 3\n";
+const WEIGHTED: &str = "4\n3\n0 1 1\n1 2 1\n2 3 1\n";
+
+// a hook which records the events
+fn recorder() -> (CommitHook, Arc<Mutex<Vec<CommitEvent>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    let hook: CommitHook = Arc::new(move |event: &CommitEvent| {
+        recorded.lock().unwrap().push(*event);
+    });
+    (hook, events)
+}
+
+#[test]
+fn manual() {
+    let mut wtd: WTDigraph = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    assert_eq!(wtd.commit_policy(), CommitPolicy::Manual);
+    for to in 0..4 {
+        wtd.add_edge(3, to);
+    }
+    assert_eq!(wtd.pending_edits(), 4);
+    assert!(!wtd.edge_exists(3, 0));
+}
+
+#[test]
+fn after_edits() {
+    let mut wtd: WTDigraph = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    let (hook, events) = recorder();
+    wtd.set_commit_hook(Some(hook));
+    wtd.set_commit_policy(CommitPolicy::AfterEdits(3));
+    wtd.add_edge(3, 0);
+    wtd.delete_edge(0, 1);
+    assert!(!wtd.edge_exists(3, 0));
+    assert!(events.lock().unwrap().is_empty());
+
+    wtd.append_vertex();
+    assert!(wtd.edge_exists(3, 0));
+    assert!(!wtd.edge_exists(0, 1));
+    assert_eq!(wtd.v_count(), 5);
+    assert_eq!(wtd.pending_edits(), 0);
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![CommitEvent {
+            policy: CommitPolicy::AfterEdits(3),
+            pending_edits: 3,
+            read_cost: 6, // vertices 0, 3 and the new vertex 4 have changes, plus 3 edits
+        }]
+    );
+
+    // commit_edits doesn't call the hook
+    wtd.add_edge(4, 0);
+    wtd.commit_edits();
+    assert_eq!(events.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn edit_fraction() {
+    let mut wtd: WTDigraph = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    wtd.set_commit_policy(CommitPolicy::EditFraction(0.5));
+    wtd.add_edge(3, 0);
+    assert!(!wtd.edge_exists(3, 0));
+    wtd.add_edge(3, 1); // 2 edits, 3 edges at the last commit
+    assert!(wtd.edge_exists(3, 1));
+
+    // the next commit needs 3 edits, since there are 5 edges now
+    wtd.add_edge(0, 0);
+    wtd.add_edge(0, 2);
+    assert!(!wtd.edge_exists(0, 0));
+    wtd.add_edge(0, 3);
+    assert!(wtd.edge_exists(0, 0));
+}
+
+#[test]
+fn read_cost() {
+    let mut wtd: WTDigraph = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    let (hook, events) = recorder();
+    wtd.set_commit_hook(Some(hook));
+    wtd.set_commit_policy(CommitPolicy::ReadCost(4));
+    wtd.add_edge(3, 0);
+    wtd.add_edge(3, 1);
+    wtd.add_edge(3, 2);
+    assert_eq!(wtd.updated_read_cost(), 4);
+    assert!(!wtd.edge_exists(3, 0));
+    wtd.add_edge(0, 0);
+    assert!(wtd.edge_exists(3, 0));
+    assert_eq!(events.lock().unwrap()[0].read_cost, 6);
+    assert_eq!(wtd.updated_read_cost(), 0);
+}
+
+#[test]
+fn set_policy_commits() {
+    let mut wtd: WTDigraph = read_wt_digraph(TEXT.as_bytes()).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    wtd.set_commit_hook(Some(Arc::new(move |_: &CommitEvent| {
+        counter.fetch_add(1, Ordering::Relaxed);
+    })));
+    wtd.add_edge(3, 0);
+    wtd.add_edge(3, 1);
+    wtd.set_commit_policy(CommitPolicy::AfterEdits(2));
+    assert!(wtd.edge_exists(3, 1));
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    // nothing to commit
+    wtd.set_commit_policy(CommitPolicy::AfterEdits(0));
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    // a clone keeps the hook
+    let mut clone = wtd.clone();
+    clone.add_edge(0, 0);
+    assert!(clone.edge_exists(0, 0));
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+}
+
+// a hook which counts the calls
+fn counter() -> (CommitHook, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counted = Arc::clone(&calls);
+    let hook: CommitHook = Arc::new(move |_: &CommitEvent| {
+        counted.fetch_add(1, Ordering::Relaxed);
+    });
+    (hook, calls)
+}
+
+#[test]
+fn one_commit_per_call() {
+    // 0 -> 1 -> 2 -> 3 plus 3 -> 0, 3 -> 1 and 3 -> 2, so 3 has three outgoing edges
+    const STAR: &str = "4\n6\n0 1\n1 2\n2 3\n3 0\n3 1\n3 2\n";
+    let mut wtd: WTDigraph = read_wt_digraph(STAR.as_bytes()).unwrap();
+    let (hook, calls) = counter();
+    wtd.set_commit_hook(Some(hook));
+    wtd.set_commit_policy(CommitPolicy::AfterEdits(1));
+    wtd.delete_outgoing_edges(3);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(wtd.e_count(), 3);
+    wtd.delete_incoming_edges(2);
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+    // adding an existing vertex deletes its incoming edges and commits once
+    wtd.add_edge(0, 3);
+    wtd.add_edge(1, 3);
+    assert_eq!(calls.load(Ordering::Relaxed), 4);
+    assert_eq!(wtd.in_degree(3), 3);
+    wtd.add_vertex(3);
+    assert_eq!(calls.load(Ordering::Relaxed), 5);
+    assert_eq!(wtd.in_degree(3), 0);
+    assert_eq!(wtd.pending_edits(), 0);
+
+    let mut wtu = read_wt_ugraph(STAR.as_bytes()).unwrap();
+    let (hook, calls) = counter();
+    wtu.set_commit_hook(Some(hook));
+    wtu.set_commit_policy(CommitPolicy::AfterEdits(1));
+    wtu.delete_edges_from(3);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(wtu.degree(3), 0);
+
+    const WEIGHTED_STAR: &str = "4\n6\n0 1 1\n1 2 1\n2 3 1\n3 0 1\n3 1 1\n3 2 1\n";
+    let mut wdg = read_weighted_wt_digraph::<_, i32>(WEIGHTED_STAR.as_bytes()).unwrap();
+    let (hook, calls) = counter();
+    wdg.set_commit_hook(Some(hook));
+    wdg.set_commit_policy(CommitPolicy::AfterEdits(1));
+    wdg.delete_incoming_edges(2);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert!(!wdg.edge_exists(1, 2));
+
+    let mut lwdg = read_labeled_weighted_wt_digraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap();
+    let (hook, calls) = counter();
+    lwdg.set_commit_hook(Some(hook));
+    lwdg.set_commit_policy(CommitPolicy::AfterEdits(1));
+    lwdg.delete_vertex("1".to_string());
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(lwdg.e_count(), 1);
+}
+
+#[test]
+fn weights_and_labels() {
+    // the weights are committed with the edges
+    let mut wdg = read_weighted_wt_digraph::<_, i32>(WEIGHTED.as_bytes()).unwrap();
+    wdg.set_commit_policy(CommitPolicy::AfterEdits(2));
+    wdg.add_edge(3, 0, 7);
+    wdg.add_edge(3, 1, 8);
+    assert_eq!(wdg.weight(3, 0), 7);
+    assert_eq!(wdg.weight(3, 1), 8);
+    // deleting the edges commits once at the end, and leaves nothing to save
+    wdg.delete_outgoing_edges(3);
+    assert!(!wdg.edge_exists(3, 0));
+    assert!(wdg.save(Vec::new()).is_ok());
+
+    let mut ldg = read_labeled_wt_digraph::<_, String>(TEXT.as_bytes()).unwrap();
+    ldg.set_commit_policy(CommitPolicy::AfterEdits(2));
+    ldg.add_vertex("4".to_string());
+    ldg.add_edge("4".to_string(), "0".to_string());
+    assert!(ldg.edge_exists("4".to_string(), "0".to_string()));
+    assert_eq!(ldg.label(4), Some(&"4".to_string()));
+}
+
+#[test]
+fn all_types() {
+    fn check<T: Clone, G: Graph<T> + WTCommitPolicy>(
+        mut graph: G,
+        add: impl Fn(&mut G),
+        from: T,
+        to: T,
+    ) {
+        graph.set_commit_policy(CommitPolicy::AfterEdits(1));
+        assert_eq!(graph.commit_policy(), CommitPolicy::AfterEdits(1));
+        add(&mut graph);
+        assert!(graph.edge_exists(from, to));
+    }
+    let l = |v: usize| v.to_string();
+    check(
+        read_wt_digraph(TEXT.as_bytes()).unwrap(),
+        |g| g.add_edge(3, 0),
+        3,
+        0,
+    );
+    check(
+        read_wt_ugraph(TEXT.as_bytes()).unwrap(),
+        |g| g.add_edge(3, 0),
+        0,
+        3,
+    );
+    check(
+        read_weighted_wt_digraph::<_, i32>(WEIGHTED.as_bytes()).unwrap(),
+        |g| g.add_edge(3, 0, 1),
+        3,
+        0,
+    );
+    check(
+        read_weighted_wt_ugraph::<_, i32>(WEIGHTED.as_bytes()).unwrap(),
+        |g| g.add_edge(3, 0, 1),
+        0,
+        3,
+    );
+    check(
+        read_labeled_wt_digraph::<_, String>(TEXT.as_bytes()).unwrap(),
+        |g| g.add_edge(l(3), l(0)),
+        l(3),
+        l(0),
+    );
+    check(
+        read_labeled_wt_ugraph::<_, String>(TEXT.as_bytes()).unwrap(),
+        |g| g.add_edge(l(3), l(0)),
+        l(0),
+        l(3),
+    );
+    check(
+        read_labeled_weighted_wt_digraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap(),
+        |g| g.add_edge(l(3), l(0), 1),
+        l(3),
+        l(0),
+    );
+    check(
+        read_labeled_weighted_wt_ugraph::<_, String, i32>(WEIGHTED.as_bytes()).unwrap(),
+        |g| g.add_edge(l(3), l(0), 1),
+        l(0),
+        l(3),
+    );
+}
@@ -1,9 +1,25 @@
+use std::collections::HashMap;
+use std::ops::Range;
 use vers_vecs::RsVec;
 
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
+use crate::algo::triangles;
 use crate::graph::undirected::UGraph;
-use crate::traits::{Directed, WTDirected};
 use crate::traits::{Graph, Undirected, Unlabeled, Unweighted, WTUndirected, WT};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{TryGraph, TryUndirected, TryUnweighted, TryWTUndirected};
 use crate::wt::directed::WTDigraph;
+use crate::GraphError;
 use serde::{Deserialize, Serialize}; // needed because of WTDigraph
 
 
@@ -37,6 +53,55 @@ impl WTUGraph {
             wtd: WTDigraph::from(sequence, starting_indices),
         };
     }
+
+    // returns an iterator over all edges of the given vertex, without allocating a vector like edges does.
+    // yields the incoming edges in wtd, followed by the outgoing edges in wtd; a loop on the vertex is only yielded once.
+    // panics if the vertex doesn't exist
+    pub fn neighbors_iter(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.wtd.incoming_iter(vertex).chain(
+            self.wtd
+                .outgoing_iter(vertex)
+                .filter(move |&item| item != vertex),
+        )
+    }
+
+    // returns an iterator over all edges of the given vertex, which exist and weren't deleted, or were created since last commit.
+    // panics if the vertex doesn't exist
+    pub fn neighbors_iter_updated(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.wtd.incoming_iter_updated(vertex).chain(
+            self.wtd
+                .outgoing_iter_updated(vertex)
+                .filter(move |&item| item != vertex),
+        )
+    }
+
+    // returns the k-th smallest (counting from 0) neighbor of the given vertex at last commit,
+    // see WTDigraph::kth_outgoing. all edges of a vertex in an undirected graph are outgoing; a loop counts once.
+    // a scan of the neighbors, O(degree * log n).
+    // panics if the vertex doesn't exist
+    pub fn kth_outgoing(&self, vertex: usize, k: usize) -> Option<usize> {
+        self.wtd.kth_existing(self.neighbors_iter(vertex), |_| Some(k))
+    }
+
+    // returns the median of the neighbors of the given vertex at last commit, see WTDigraph::median_outgoing.
+    // a scan of the neighbors, O(degree * log n).
+    // panics if the vertex doesn't exist
+    pub fn median_outgoing(&self, vertex: usize) -> Option<usize> {
+        self.wtd.kth_existing(self.neighbors_iter(vertex), |len| {
+            len.checked_sub(1).map(|last| last / 2)
+        })
+    }
+
+    // returns the k vertices with the most edges at last commit to vertices in `range`, with the number of these edges,
+    // see WTDigraph::top_k_in_degree. a loop counts once.
+    // a scan of the neighbors of every vertex in `range`, O(b * log n) for b edges of these vertices.
+    pub fn top_k_in_degree(&self, range: Range<usize>, k: usize) -> Vec<(usize, usize)> {
+        let end = range.end.min(self.wtd.wt_adj_len);
+        let edges = (range.start..end)
+            .filter(|&v| self.vertex_exists(v))
+            .flat_map(|v| self.neighbors_iter(v));
+        self.wtd.top_k_existing(edges, k)
+    }
 }
 
 impl Graph<usize> for WTUGraph {
@@ -91,32 +156,28 @@ impl Graph<usize> for WTUGraph {
 }
 impl Undirected<usize> for WTUGraph {
     // returns all edges of the given vertex in a vector, by computing it's incoming and outgoing edges in wtd.
-    // should probably be changed to return an iterator instead
-    // todo ! catch non-existing vertice as input
+    // use neighbors_iter to avoid the allocation
     fn edges(&self, vertex: usize) -> Vec<usize> {
-        // returns all edges connected to vertex
-        let mut edges: Vec<usize>;
-        edges = self.wtd.incoming_edges(vertex); // all incoming edges of vertex
-        if self.edge_exists(vertex, vertex) {
-            for item in self.wtd.outgoing_edges(vertex) {
-                if item != vertex {
-                    edges.push(item);
-                }
-            }
-            return edges;
-        } else {
-            edges.append(&mut self.wtd.outgoing_edges(vertex)); // + outgoing edges of vertex
-            return edges;
-        }
+        return self.neighbors_iter(vertex).collect();
+    }
+
+    // returns the number of edges of the given vertex from its in- and out-degree in wtd
+    fn degree(&self, vertex: usize) -> usize {
+        self.wtd.undirected_degree(vertex)
     }
 
     // delete all edges of the given vertex
     // should return a Result
     fn delete_edges_from(&mut self, vertex: usize) {
-        // deletes all edges connected to vertex
+        // deletes all edges connected to vertex, and commits at most once at the end
         for item in self.edges_updated(vertex) {
-            self.delete_edge(vertex, item);
+            if vertex <= item {
+                self.wtd.delete_edge_uncommitted(vertex, item);
+            } else {
+                self.wtd.delete_edge_uncommitted(item, vertex);
+            }
         }
+        self.wtd.commit_if_due();
     }
 }
 impl Unlabeled<usize> for WTUGraph {
@@ -185,20 +246,201 @@ impl WT<usize> for WTUGraph {
 
 impl WTUndirected<usize> for WTUGraph {
     // return all edges of the given vertex in a vector, which exist and weren't deleted, or were created since since last commit.
-    // should probably be changed to return an iterator instead
+    // use neighbors_iter_updated to avoid the allocation
     fn edges_updated(&self, vertex: usize) -> Vec<usize> {
-        let mut edges: Vec<usize>;
-        edges = self.wtd.incoming_edges_updated(vertex); // all incoming edges of vertex
-        if self.edge_exists_updated(vertex, vertex) {
-            for item in self.wtd.outgoing_edges_updated(vertex) {
-                if item != vertex {
-                    edges.push(item);
-                }
-            }
-            return edges;
+        return self.neighbors_iter_updated(vertex).collect();
+    }
+
+    fn degree_updated(&self, vertex: usize) -> usize {
+        self.wtd.undirected_degree_updated(vertex)
+    }
+}
+
+impl TryGraph<usize> for WTUGraph {
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        self.wtd.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.wtd.try_delete_edge(from, to)
         } else {
-            edges.append(&mut self.wtd.outgoing_edges_updated(vertex)); // + outgoing edges of vertex
-            return edges;
+            self.wtd.try_delete_edge(to, from)
         }
     }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.wtd.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        if from <= to {
+            self.wtd.try_edge_exists(from, to)
+        } else {
+            self.wtd.try_edge_exists(to, from)
+        }
+    }
+}
+
+impl TryUndirected<usize> for WTUGraph {
+    fn try_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.wtd.check_committed(vertex)?;
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.wtd.check_updated(vertex)?;
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+
+impl TryUnweighted<usize> for WTUGraph {
+    fn try_add_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.wtd.try_add_edge(from, to)
+        } else {
+            self.wtd.try_add_edge(to, from)
+        }
+    }
+}
+
+impl TryWTUndirected<usize> for WTUGraph {
+    fn try_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.wtd.check_updated(vertex)?;
+        Ok(self.edges_updated(vertex))
+    }
+}
+
+impl GraphSearch<usize> for WTUGraph {
+    type Distance = usize;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<Option<ShortestPath<usize, usize>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<ShortestPaths<usize, usize>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, usize>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, usize>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl SearchView<usize> for WTUGraph {
+    type Distance = usize;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = false;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.wtd.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn distance(&self, _from: &usize, _to: &usize) -> usize {
+        1
+    }
+}
+
+impl Triangles<usize> for WTUGraph {
+    fn triangle_counts(&self) -> TriangleCounts<usize> {
+        triangles::wt_triangle_counts(&self.wtd, Some)
+    }
+}
+
+impl Centrality<usize> for WTUGraph {
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl Cores<usize> for WTUGraph {
+    fn core_numbers(&self) -> HashMap<usize, usize> {
+        cores::wt_core_numbers(&self.wtd, Some)
+    }
+
+    fn degeneracy_order(&self) -> Vec<usize> {
+        cores::wt_degeneracy_order(&self.wtd, Some)
+    }
+}
+
+impl Biconnectivity<usize> for WTUGraph {
+    fn biconnected_components(&self) -> BiconnectedComponents<usize> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl Neighborhood<usize> for WTUGraph {
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.wtd,
+            &u,
+            &v,
+            Direction::Undirected,
+            |&v| Some(v),
+            Some,
+        )
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.wtd,
+            &u,
+            &v,
+            Direction::Undirected,
+            |&v| Some(v),
+            Some,
+        )
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.wtd, &u, &v, Direction::Undirected, |&v| Some(v))
+    }
+}
+
+// the policy belongs to the wrapped wt-graph, which commits everything of this graph
+impl WTCommitPolicy for WTUGraph {
+    fn commit_policy(&self) -> CommitPolicy {
+        self.wtd.commit_policy()
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.wtd.set_commit_policy(policy);
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.wtd.set_commit_hook(hook);
+    }
 }
@@ -82,3 +82,75 @@ fn add_vertex() {
     
 }
 
+
+#[test]
+fn neighbors_iter() {
+    use crate::graph::undirected::UGraph;
+    use crate::wt::undirected::WTUGraph;
+
+    let mut ug = UGraph::new();
+    ug.add_vertex(3);
+    ug.add_edge(0, 1);
+    ug.add_edge(1, 1);
+    ug.add_edge(2, 1);
+    let mut wtug = WTUGraph::from_ugraph(ug);
+    assert_eq!(wtug.neighbors_iter(1).collect::<Vec<usize>>(), vec![0, 1, 2]);
+    assert_eq!(wtug.neighbors_iter(1).collect::<Vec<usize>>(), wtug.edges(1));
+
+    wtug.delete_edge(1, 1);
+    wtug.add_edge(0, 2);
+    assert_eq!(wtug.neighbors_iter_updated(1).collect::<Vec<usize>>(), vec![0, 2]);
+    assert_eq!(wtug.neighbors_iter_updated(2).collect::<Vec<usize>>(), vec![1, 0]);
+}
+
+#[test]
+fn degree() {
+    use crate::graph::undirected::UGraph;
+    use crate::wt::undirected::WTUGraph;
+
+    let mut ug = UGraph::new();
+    ug.add_vertex(3);
+    ug.add_edge(0, 1);
+    ug.add_edge(1, 1);
+    ug.add_edge(2, 1);
+    let mut wtug = WTUGraph::from_ugraph(ug);
+    assert_eq!(wtug.degree(1), 3);
+    assert_eq!(wtug.degree(3), 0);
+
+    wtug.delete_edge(1, 1);
+    wtug.add_edge(3, 3);
+    wtug.add_edge(0, 2);
+    for v in 0..4 {
+        assert_eq!(wtug.degree_updated(v), wtug.edges_updated(v).len());
+    }
+    assert_eq!(wtug.degree_updated(3), 1);
+}
+
+#[test]
+fn kth_and_top_k() {
+    use crate::graph::undirected::UGraph;
+    use crate::wt::undirected::WTUGraph;
+
+    // 0 - 1, 0 - 2, 0 - 3, 1 - 2, 1 - 1, 3 - 4
+    let adj = vec![vec![1, 2, 3], vec![2, 1], vec![], vec![4], vec![]];
+    let mut wtug = WTUGraph::from_ugraph(UGraph::from_adjacency_list(5, 6, adj));
+    assert_eq!(wtug.kth_outgoing(1, 0), Some(0));
+    assert_eq!(wtug.kth_outgoing(1, 1), Some(1));
+    assert_eq!(wtug.kth_outgoing(1, 2), Some(2));
+    assert_eq!(wtug.kth_outgoing(1, 3), None);
+    assert_eq!(wtug.median_outgoing(0), Some(2));
+    assert_eq!(wtug.median_outgoing(3), Some(0));
+    assert_eq!(wtug.median_outgoing(4), Some(3));
+
+    // the neighbors of 0 and 1, with the loop at 1 once
+    assert_eq!(wtug.top_k_in_degree(0..2, 3), vec![(1, 2), (2, 2), (0, 1)]);
+    assert_eq!(
+        wtug.top_k_in_degree(3..10, 10),
+        vec![(0, 1), (3, 1), (4, 1)]
+    );
+
+    wtug.delete_vertex(2);
+    wtug.commit_edits();
+    assert_eq!(wtug.median_outgoing(0), Some(1));
+    assert_eq!(wtug.top_k_in_degree(0..3, 10), vec![(1, 2), (0, 1), (3, 1)]);
+}
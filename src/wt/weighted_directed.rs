@@ -1,11 +1,28 @@
+use num::{Num, ToPrimitive};
 use std::collections::HashMap;
 
 use vers_vecs::RsVec;
 
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::flow;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
 use crate::graph::weighted_directed::WeightedDigraph;
 use crate::traits::{Directed, Graph, Unlabeled, Unweighted, WTDirected, WTWeighted, Weighted, WT};
+use crate::traits::{Flow, MaxFlow};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{PageRank, PageRankOptions};
+use crate::traits::{TopologicalSort, WTTopologicalSort};
+use crate::traits::DirectedCores;
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{TryDirected, TryGraph, TryWTDirected, TryWTWeighted, TryWeighted};
 use crate::wt::directed::WTDigraph;
-use crate::Edit;
+use crate::wt::iter::{IncomingIter, IncomingUpdatedIter, OutgoingIter, OutgoingUpdatedIter};
+use crate::wt::policy::AutoCommit;
+use crate::{Edit, GraphError};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -15,9 +32,11 @@ mod test;
 // The greatest possible of number of edges or of vertices is usize vertices, vertex-indices are also usize-data-type. Weights can have any type.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WeightedWTDigraph<W> {
-    dg: WTDigraph,
-    weights_uncommitted: HashMap<(usize, usize), Edit<W>>,
-    weights: HashMap<(usize, usize), W>,
+    pub(crate) dg: WTDigraph,
+    pub(crate) weights_uncommitted: HashMap<(usize, usize), Edit<W>>,
+    pub(crate) weights: HashMap<(usize, usize), W>,
+    #[serde(skip)]
+    pub(crate) auto_commit: AutoCommit,
 }
 
 impl<W> WeightedWTDigraph<W> {
@@ -26,6 +45,7 @@ impl<W> WeightedWTDigraph<W> {
             dg: WTDigraph::from_digraph(wdg.dg),
             weights_uncommitted: HashMap::new(),
             weights: wdg.weights,
+            auto_commit: AutoCommit::default(),
         };
     }
     pub fn from(
@@ -37,15 +57,54 @@ impl<W> WeightedWTDigraph<W> {
             dg: WTDigraph::from(sequence, starting_indices),
             weights_uncommitted: HashMap::new(),
             weights,
+            auto_commit: AutoCommit::default(),
         };
     }
+
+    // see WTDigraph::outgoing_iter
+    pub fn outgoing_iter(&self, vertex: usize) -> OutgoingIter<'_> {
+        self.dg.outgoing_iter(vertex)
+    }
+
+    // see WTDigraph::incoming_iter
+    pub fn incoming_iter(&self, vertex: usize) -> IncomingIter<'_> {
+        self.dg.incoming_iter(vertex)
+    }
+
+    // see WTDigraph::outgoing_iter_updated
+    pub fn outgoing_iter_updated(&self, vertex: usize) -> OutgoingUpdatedIter<'_> {
+        self.dg.outgoing_iter_updated(vertex)
+    }
+
+    // see WTDigraph::incoming_iter_updated
+    pub fn incoming_iter_updated(&self, vertex: usize) -> IncomingUpdatedIter<'_> {
+        self.dg.incoming_iter_updated(vertex)
+    }
+
+    // returns the weight of the edge at the last commit, or None if no weight is stored for it.
+    // unlike weight, this doesn't check that the edge exists
+    pub(crate) fn committed_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.weights.get(&(from, to))
+    }
+
+    // returns the weight of the edge including the uncommitted changes, or None if no weight is stored for it.
+    // unlike weight_updated, this doesn't check that the edge exists
+    pub(crate) fn updated_weight(&self, from: usize, to: usize) -> Option<&W> {
+        match self.weights_uncommitted.get(&(from, to)) {
+            Some(Edit::Add(weight)) => Some(weight),
+            Some(Edit::Delete(_)) => None,
+            None => self.weights.get(&(from, to)),
+        }
+    }
 }
 impl<W> Graph<usize> for WeightedWTDigraph<W>
 where
     W: Clone,
 {
     fn add_vertex(&mut self, vertex: usize) -> usize {
-        self.dg.add_vertex(vertex)
+        let index = self.dg.add_vertex(vertex);
+        self.commit_if_due();
+        index
     }
 
     fn e_count(&self) -> usize {
@@ -57,14 +116,13 @@ where
     }
 
     fn delete_edge(&mut self, from: usize, to: usize) {
-        self.dg.delete_edge(from, to);
-        let old_weight = self.weight_updated(from, to); // checkme
-        self.weights_uncommitted
-            .insert((from, to), Edit::Delete(old_weight));
+        self.delete_edge_uncommitted(from, to);
+        self.commit_if_due();
     }
 
     fn delete_vertex(&mut self, vertex: usize) {
         self.dg.delete_vertex(vertex);
+        self.commit_if_due();
     }
 
     fn vertex_exists(&self, vertex: usize) -> bool {
@@ -72,11 +130,7 @@ where
     }
 
     fn edge_exists(&self, from: usize, to: usize) -> bool {
-        if from <= to {
-            self.dg.edge_exists(from, to)
-        } else {
-            self.dg.edge_exists(to, from)
-        }
+        self.dg.edge_exists(from, to)
     }
 }
 
@@ -101,10 +155,10 @@ where
         let outgoing: Vec<usize> = self.outgoing_edges_updated(vertex);
 
         for to in outgoing {
-            self.delete_edge(vertex, to); // this function call updates e_count_updated
+            self.delete_edge_uncommitted(vertex, to); // this function call updates e_count_updated
         }
-        // need to call self.delete_edge to update weights
-        self.dg.has_uncommitted_edits = true;
+        // need to call self.delete_edge_uncommitted to update weights
+        self.commit_if_due();
     }
 
     fn delete_incoming_edges(&mut self, vertex: usize) {
@@ -116,16 +170,29 @@ where
         let incoming: Vec<usize> = self.incoming_edges_updated(vertex); // empty list if there are no incoming edges
 
         for from in incoming {
-            self.delete_edge(from, vertex); // this function call updates e_count_updated
+            self.delete_edge_uncommitted(from, vertex); // this function call updates e_count_updated
         }
-        // need to call self.delete_edge to update weights
-        self.dg.has_uncommitted_edits = true;
+        // need to call self.delete_edge_uncommitted to update weights
+        self.commit_if_due();
+    }
+
+    fn out_degree(&self, vertex: usize) -> usize {
+        self.dg.out_degree(vertex)
+    }
+
+    fn in_degree(&self, vertex: usize) -> usize {
+        self.dg.in_degree(vertex)
     }
 }
 
-impl<W> Unlabeled<usize> for WeightedWTDigraph<W> {
+impl<W> Unlabeled<usize> for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
     fn append_vertex(&mut self) -> usize {
-        self.dg.append_vertex()
+        let index = self.dg.append_vertex();
+        self.commit_if_due();
+        index
     }
 
     fn shrink(&mut self) -> Vec<Option<usize>> {
@@ -140,6 +207,7 @@ where
         self.dg.add_edge(from, to);
         self.weights_uncommitted
             .insert((from, to), Edit::Add(weight));
+        self.commit_if_due();
     }
 
     fn edit_weight(&mut self, from: usize, to: usize, weight: W) {
@@ -149,7 +217,7 @@ where
         }
     }
 
-    fn weight(&mut self, from: usize, to: usize) -> W {
+    fn weight(&self, from: usize, to: usize) -> W {
         if self.edge_exists(from, to) {
             if self.weights.contains_key(&(from, to)) {
                 let weight = self.weights.get(&(from, to)).unwrap().clone();
@@ -214,12 +282,20 @@ impl<W> WTDirected<usize> for WeightedWTDigraph<W> {
     fn incoming_edges_updated(&self, vertex: usize) -> Vec<usize> {
         self.dg.incoming_edges_updated(vertex)
     }
+
+    fn out_degree_updated(&self, vertex: usize) -> usize {
+        self.dg.out_degree_updated(vertex)
+    }
+
+    fn in_degree_updated(&self, vertex: usize) -> usize {
+        self.dg.in_degree_updated(vertex)
+    }
 }
 impl<W> WTWeighted<usize, W> for WeightedWTDigraph<W>
 where
     W: Clone,
 {
-    fn weight_updated(&mut self, from: usize, to: usize) -> W {
+    fn weight_updated(&self, from: usize, to: usize) -> W {
         if !self.vertex_exists_updated(from) {
             panic!("wdg get_weight_updated : from Vertex doesn't exist");
         }
@@ -244,3 +320,308 @@ where
         }
     }
 }
+
+impl<W> TryGraph<usize> for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        self.dg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        self.dg.check_updated(from)?;
+        self.dg.check_updated(to)?;
+        if !self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.delete_edge(from, to);
+        Ok(())
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.dg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        self.dg.try_edge_exists(from, to)
+    }
+}
+
+impl<W> TryDirected<usize> for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    fn try_outgoing_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_outgoing_edges(vertex)
+    }
+
+    fn try_incoming_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_incoming_edges(vertex)
+    }
+
+    fn try_delete_outgoing_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.dg.check_updated(vertex)?;
+        self.delete_outgoing_edges(vertex);
+        Ok(())
+    }
+
+    fn try_delete_incoming_edges(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.dg.check_updated(vertex)?;
+        self.delete_incoming_edges(vertex);
+        Ok(())
+    }
+}
+
+impl<W> TryWeighted<usize, W> for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    fn try_add_edge(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        self.dg.check_updated(from)?;
+        self.dg.check_updated(to)?;
+        if self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeExists);
+        }
+        self.add_edge(from, to, weight);
+        Ok(())
+    }
+
+    // unlike edit_weight, this doesn't silently ignore missing edges
+    fn try_edit_weight(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        self.dg.check_updated(from)?;
+        self.dg.check_updated(to)?;
+        if !self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.edit_weight(from, to, weight);
+        Ok(())
+    }
+
+    fn try_weight(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        if !self.dg.try_edge_exists(from, to)? {
+            return Err(GraphError::EdgeNotFound);
+        }
+        self.weights
+            .get(&(from, to))
+            .cloned()
+            .ok_or(GraphError::WeightNotFound)
+    }
+}
+
+impl<W> TryWTDirected<usize> for WeightedWTDigraph<W> {
+    fn try_outgoing_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_outgoing_edges_updated(vertex)
+    }
+
+    fn try_incoming_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.dg.try_incoming_edges_updated(vertex)
+    }
+}
+
+impl<W> TryWTWeighted<usize, W> for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    fn try_weight_updated(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        self.dg.check_updated(from)?;
+        self.dg.check_updated(to)?;
+        if !self.edge_exists_updated(from, to) {
+            return Err(GraphError::EdgeNotFound);
+        }
+        match self.weights_uncommitted.get(&(from, to)) {
+            Some(Edit::Add(weight)) => Ok(weight.clone()),
+            Some(Edit::Delete(_)) => Err(GraphError::WeightNotFound),
+            None => self
+                .weights
+                .get(&(from, to))
+                .cloned()
+                .ok_or(GraphError::WeightNotFound),
+        }
+    }
+}
+
+impl<W> GraphSearch<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<Option<ShortestPath<usize, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<ShortestPaths<usize, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<W> SearchView<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = true;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.dg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.outgoing_edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.incoming_edges(*vertex)
+    }
+
+    fn distance(&self, from: &usize, to: &usize) -> W {
+        self.weight(*from, *to)
+    }
+}
+
+impl<W> TopologicalSort<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order(&self) -> Result<Vec<usize>, Vec<usize>> {
+        self.dg.topological_order()
+    }
+}
+
+impl<W> WTTopologicalSort<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn topological_order_updated(&self) -> Result<Vec<usize>, Vec<usize>> {
+        self.dg.topological_order_updated()
+    }
+}
+
+impl<W> MaxFlow<usize, W> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn max_flow(&self, source: usize, sink: usize) -> Result<Flow<usize, W>, GraphError> {
+        flow::max_flow(self, source, sink)
+    }
+}
+
+impl<W> PageRank<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn pagerank(&self, options: &PageRankOptions<usize>) -> Result<HashMap<usize, f64>, GraphError> {
+        self.dg.pagerank(options)
+    }
+}
+
+impl<W> Centrality<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<W> DirectedCores<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn in_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::in_core_numbers(self)
+    }
+
+    fn out_core_numbers(&self) -> HashMap<usize, usize> {
+        cores::out_core_numbers(self)
+    }
+}
+
+impl<W> Neighborhood<usize> for WeightedWTDigraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(&self.dg, &u, &v, Direction::Outgoing, |&v| Some(v), Some)
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(&self.dg, &u, &v, Direction::Incoming, |&v| Some(v), Some)
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.dg, &u, &v, Direction::Outgoing, |&v| Some(v))
+    }
+}
+
+impl<W> WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    // deletes the edge and its weight like delete_edge, but doesn't commit. see WTDigraph::delete_edge_uncommitted
+    pub(crate) fn delete_edge_uncommitted(&mut self, from: usize, to: usize) {
+        // needs to be read before the edge is gone. an edge without a weight has none to delete
+        let old_weight = self.try_weight_updated(from, to).ok();
+        self.dg.delete_edge(from, to);
+        if let Some(old_weight) = old_weight {
+            self.weights_uncommitted
+                .insert((from, to), Edit::Delete(old_weight));
+        }
+    }
+
+    // commits the edits if the commit policy calls for it. see WTDigraph::commit_if_due
+    pub(crate) fn commit_if_due(&mut self) {
+        if let Some(event) = self.auto_commit.due(&self.dg) {
+            self.commit_edits();
+            self.auto_commit.notify(&event);
+        }
+    }
+}
+
+impl<W> WTCommitPolicy for WeightedWTDigraph<W>
+where
+    W: Clone,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.auto_commit.policy
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.auto_commit.policy = policy;
+        self.commit_if_due();
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.auto_commit.hook = hook;
+    }
+}
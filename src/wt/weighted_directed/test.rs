@@ -1,6 +1,9 @@
 use rand::prelude::SliceRandom;
 use rand::thread_rng;
-use crate::traits::{Graph,WT};
+use crate::graph::weighted_directed::WeightedDigraph;
+use crate::traits::{Graph, TryGraph, TryWTWeighted, TryWeighted, Weighted, WT};
+use crate::wt::weighted_directed::WeightedWTDigraph;
+use crate::GraphError;
 
 #[test]
 fn add_vertex() {
@@ -84,4 +87,44 @@ fn add_vertex() {
     }    
 
 }
-    
\ No newline at end of file
+    
+
+#[test]
+fn try_functions() {
+    let wdg = WeightedDigraph::from_adjacency_list(2, 1, vec![vec![(1, 2.5)], vec![]]);
+    let mut wtwdg = WeightedWTDigraph::from_weighted_digraph(wdg);
+    assert_eq!(wtwdg.try_weight(0, 1), Ok(2.5));
+    assert_eq!(wtwdg.try_weight(1, 0), Err(GraphError::EdgeNotFound));
+    assert_eq!(wtwdg.try_edit_weight(1, 0, 1.0), Err(GraphError::EdgeNotFound));
+    assert_eq!(wtwdg.try_add_edge(0, 1, 1.0), Err(GraphError::EdgeExists));
+    assert_eq!(wtwdg.try_add_edge(1, 0, 1.0), Ok(()));
+    assert_eq!(wtwdg.try_weight_updated(1, 0), Ok(1.0));
+    assert_eq!(wtwdg.try_delete_edge(0, 1), Ok(()));
+    assert_eq!(wtwdg.try_weight_updated(0, 1), Err(GraphError::EdgeNotFound));
+    assert_eq!(wtwdg.try_delete_edge(0, 1), Err(GraphError::EdgeNotFound));
+    wtwdg.commit_edits();
+    assert_eq!(wtwdg.try_weight(1, 0), Ok(1.0));
+    assert_eq!(wtwdg.weight(1, 0), 1.0);
+    assert!(wtwdg.edge_exists(1, 0));
+    assert!(!wtwdg.edge_exists(0, 1));
+    assert_eq!(wtwdg.try_edge_exists(0, 1), Ok(false));
+}
+
+#[test]
+fn delete_edge_without_weight() {
+    // the edges 0 -> 1 and 1 -> 0, only the second one has a weight
+    let mut bits = vers_vecs::BitVec::new();
+    for bit in [true, false, true, false] {
+        bits.append(bit);
+    }
+    let weights = std::collections::HashMap::from([((1, 0), 1.0)]);
+    let mut wtwdg =
+        WeightedWTDigraph::from(vec![1, 0], vers_vecs::RsVec::from_bit_vec(bits), weights);
+    assert_eq!(wtwdg.try_weight(0, 1), Err(GraphError::WeightNotFound));
+    assert_eq!(wtwdg.try_delete_edge(0, 1), Ok(()));
+    assert_eq!(wtwdg.try_delete_edge(0, 1), Err(GraphError::EdgeNotFound));
+    wtwdg.delete_edge(1, 0);
+    wtwdg.commit_edits();
+    assert_eq!(wtwdg.e_count(), 0);
+    assert_eq!(wtwdg.try_weight(1, 0), Err(GraphError::EdgeNotFound));
+}
@@ -1,10 +1,26 @@
+use num::{Num, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use vers_vecs::RsVec;
+use crate::algo::biconnected;
+use crate::algo::centrality;
+use crate::algo::cores;
+use crate::algo::neighborhood::{self, Direction};
+use crate::algo::search::{self, SearchView};
+use crate::algo::spanning;
+use crate::algo::triangles;
 use crate::graph::weighted_undirected::WeightedUGraph;
-use crate::traits::{
-    Directed, Graph, Undirected, Unlabeled, WTDirected, WTUndirected, WTWeighted, Weighted, WT,
-};
+use crate::traits::{Graph, Undirected, Unlabeled, WTUndirected, WTWeighted, Weighted, WT};
+use crate::traits::{Centrality, CentralityMeasure};
+use crate::traits::{GraphSearch, ShortestPath, ShortestPathAlgorithm, ShortestPaths};
+use crate::traits::{TriangleCounts, Triangles};
+use crate::traits::Cores;
+use crate::traits::{BiconnectedComponents, Biconnectivity};
+use crate::traits::Neighborhood;
+use crate::traits::{CommitHook, CommitPolicy, WTCommitPolicy};
+use crate::traits::{SpanningForest, SpanningTree, SpanningTreeAlgorithm};
+use crate::traits::{TryGraph, TryUndirected, TryWTUndirected, TryWTWeighted, TryWeighted};
+use crate::GraphError;
 use super::weighted_directed::WeightedWTDigraph;
 
 #[cfg(test)]
@@ -12,7 +28,7 @@ mod test;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct WeightedWTUGraph<W> {
-    wdg: WeightedWTDigraph<W>,
+    pub(crate) wdg: WeightedWTDigraph<W>,
 }
 impl<W> WeightedWTUGraph<W> {
     pub fn from_weighted_ugraph(wug: WeightedUGraph<W>) -> Self {
@@ -29,6 +45,24 @@ impl<W> WeightedWTUGraph<W> {
             wdg: WeightedWTDigraph::from(sequence, starting_indices, weights),
         };
     }
+
+    // see WTUGraph::neighbors_iter
+    pub fn neighbors_iter(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.wdg.incoming_iter(vertex).chain(
+            self.wdg
+                .outgoing_iter(vertex)
+                .filter(move |&item| item != vertex),
+        )
+    }
+
+    // see WTUGraph::neighbors_iter_updated
+    pub fn neighbors_iter_updated(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.wdg.incoming_iter_updated(vertex).chain(
+            self.wdg
+                .outgoing_iter_updated(vertex)
+                .filter(move |&item| item != vertex),
+        )
+    }
 }
 impl<W> Graph<usize> for WeightedWTUGraph<W>
 where
@@ -76,29 +110,29 @@ where
 {
     fn edges(&self, vertex: usize) -> Vec<usize> {
         // returns all edges connected to vertex
-        let mut edges: Vec<usize>;
-        edges = self.wdg.incoming_edges(vertex); // all incoming edges of vertex
-        if self.edge_exists(vertex, vertex) {
-            for item in self.wdg.outgoing_edges(vertex) {
-                if item != vertex {
-                    edges.push(item);
-                }
-            }
-            return edges;
-        } else {
-            edges.append(&mut self.wdg.outgoing_edges(vertex)); // + outgoing edges of vertex
-            return edges;
-        }
+        return self.neighbors_iter(vertex).collect();
+    }
+
+    fn degree(&self, vertex: usize) -> usize {
+        self.wdg.dg.undirected_degree(vertex)
     }
 
     fn delete_edges_from(&mut self, vertex: usize) {
-        // deletes all edges connected to vertex
+        // deletes all edges connected to vertex, and commits at most once at the end
         for item in self.edges_updated(vertex) {
-            self.delete_edge(vertex, item);
+            if vertex <= item {
+                self.wdg.delete_edge_uncommitted(vertex, item);
+            } else {
+                self.wdg.delete_edge_uncommitted(item, vertex);
+            }
         }
+        self.wdg.commit_if_due();
     }
 }
-impl<W> Unlabeled<usize> for WeightedWTUGraph<W> {
+impl<W> Unlabeled<usize> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
     fn append_vertex(&mut self) -> usize {
         return self.wdg.append_vertex();
     }
@@ -127,7 +161,7 @@ where
         }
     }
 
-    fn weight(&mut self, from: usize, to: usize) -> W {
+    fn weight(&self, from: usize, to: usize) -> W {
         if from <= to {
             return self.wdg.weight(from, to);
         } else {
@@ -176,26 +210,18 @@ where
     W: Clone,
 {
     fn edges_updated(&self, vertex: usize) -> Vec<usize> {
-        let mut edges: Vec<usize>;
-        edges = self.wdg.incoming_edges_updated(vertex); // all incoming edges of vertex
-        if self.edge_exists_updated(vertex, vertex) {
-            for item in self.wdg.outgoing_edges_updated(vertex) {
-                if item != vertex {
-                    edges.push(item);
-                }
-            }
-            return edges;
-        } else {
-            edges.append(&mut self.wdg.outgoing_edges_updated(vertex)); // + outgoing edges of vertex
-            return edges;
-        }
+        return self.neighbors_iter_updated(vertex).collect();
+    }
+
+    fn degree_updated(&self, vertex: usize) -> usize {
+        self.wdg.dg.undirected_degree_updated(vertex)
     }
 }
 impl<W> WTWeighted<usize, W> for WeightedWTUGraph<W>
 where
     W: Clone,
 {
-    fn weight_updated(&mut self, from: usize, to: usize) -> W {
+    fn weight_updated(&self, from: usize, to: usize) -> W {
         if from <= to {
             return self.wdg.weight_updated(from, to);
         } else {
@@ -203,3 +229,266 @@ where
         }
     }
 }
+
+impl<W> TryGraph<usize> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn try_add_vertex(&mut self, vertex: usize) -> Result<usize, GraphError> {
+        self.wdg.try_add_vertex(vertex)
+    }
+
+    fn try_delete_edge(&mut self, from: usize, to: usize) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_delete_edge(from, to)
+        } else {
+            self.wdg.try_delete_edge(to, from)
+        }
+    }
+
+    fn try_delete_vertex(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.wdg.try_delete_vertex(vertex)
+    }
+
+    fn try_edge_exists(&self, from: usize, to: usize) -> Result<bool, GraphError> {
+        if from <= to {
+            self.wdg.try_edge_exists(from, to)
+        } else {
+            self.wdg.try_edge_exists(to, from)
+        }
+    }
+}
+
+impl<W> TryUndirected<usize> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn try_edges(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.wdg.dg.check_committed(vertex)?;
+        Ok(self.edges(vertex))
+    }
+
+    fn try_delete_edges_from(&mut self, vertex: usize) -> Result<(), GraphError> {
+        self.wdg.dg.check_updated(vertex)?;
+        self.delete_edges_from(vertex);
+        Ok(())
+    }
+}
+
+impl<W> TryWeighted<usize, W> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn try_add_edge(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_add_edge(from, to, weight)
+        } else {
+            self.wdg.try_add_edge(to, from, weight)
+        }
+    }
+
+    fn try_edit_weight(&mut self, from: usize, to: usize, weight: W) -> Result<(), GraphError> {
+        if from <= to {
+            self.wdg.try_edit_weight(from, to, weight)
+        } else {
+            self.wdg.try_edit_weight(to, from, weight)
+        }
+    }
+
+    fn try_weight(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        if from <= to {
+            self.wdg.try_weight(from, to)
+        } else {
+            self.wdg.try_weight(to, from)
+        }
+    }
+}
+
+impl<W> TryWTUndirected<usize> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn try_edges_updated(&self, vertex: usize) -> Result<Vec<usize>, GraphError> {
+        self.wdg.dg.check_updated(vertex)?;
+        Ok(self.edges_updated(vertex))
+    }
+}
+
+impl<W> TryWTWeighted<usize, W> for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn try_weight_updated(&self, from: usize, to: usize) -> Result<W, GraphError> {
+        if from <= to {
+            self.wdg.try_weight_updated(from, to)
+        } else {
+            self.wdg.try_weight_updated(to, from)
+        }
+    }
+}
+
+impl<W> GraphSearch<usize> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+
+    fn shortest_path(
+        &self,
+        from: usize,
+        to: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<Option<ShortestPath<usize, W>>, GraphError> {
+        search::shortest_path(self, from, to, mode)
+    }
+
+    fn shortest_paths_from(
+        &self,
+        from: usize,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<ShortestPaths<usize, W>, GraphError> {
+        search::shortest_paths_from(self, from, mode)
+    }
+
+    fn shortest_paths(
+        &self,
+        mode: ShortestPathAlgorithm<usize, W>,
+    ) -> Result<HashMap<usize, ShortestPaths<usize, W>>, GraphError> {
+        search::shortest_paths(self, mode)
+    }
+
+    fn connected_components(&self) -> Vec<Vec<usize>> {
+        search::connected_components(self)
+    }
+
+    fn connected(&self, from: usize, to: usize) -> bool {
+        search::connected(self, from, to)
+    }
+}
+
+impl<W> SearchView<usize> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    type Distance = W;
+    const DIRECTED: bool = false;
+    const WEIGHTED: bool = true;
+
+    fn search_vertices(&self) -> Vec<usize> {
+        self.wdg.search_vertices()
+    }
+
+    fn search_exists(&self, vertex: &usize) -> bool {
+        self.vertex_exists(*vertex)
+    }
+
+    fn successors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn predecessors(&self, vertex: &usize) -> Vec<usize> {
+        self.edges(*vertex)
+    }
+
+    fn distance(&self, from: &usize, to: &usize) -> W {
+        self.weight(*from, *to)
+    }
+}
+
+impl<W> SpanningTree<usize, W> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn minimum_spanning_forest(&self, mode: SpanningTreeAlgorithm) -> SpanningForest<usize, W> {
+        spanning::minimum_spanning_forest(self, mode)
+    }
+}
+
+impl<W> Triangles<usize> for WeightedWTUGraph<W>
+where
+    W: Num,
+{
+    fn triangle_counts(&self) -> TriangleCounts<usize> {
+        triangles::wt_triangle_counts(&self.wdg.dg, Some)
+    }
+}
+
+impl<W> Centrality<usize> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy + ToPrimitive,
+{
+    fn centrality(&self, measure: CentralityMeasure) -> Result<HashMap<usize, f64>, GraphError> {
+        centrality::centrality(self, measure)
+    }
+}
+
+impl<W> Cores<usize> for WeightedWTUGraph<W>
+where
+    W: Num,
+{
+    fn core_numbers(&self) -> HashMap<usize, usize> {
+        cores::wt_core_numbers(&self.wdg.dg, Some)
+    }
+
+    fn degeneracy_order(&self) -> Vec<usize> {
+        cores::wt_degeneracy_order(&self.wdg.dg, Some)
+    }
+}
+
+impl<W> Biconnectivity<usize> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn biconnected_components(&self) -> BiconnectedComponents<usize> {
+        biconnected::biconnected_components(self)
+    }
+}
+
+impl<W> Neighborhood<usize> for WeightedWTUGraph<W>
+where
+    W: Num + PartialOrd + Copy,
+{
+    fn common_out_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.wdg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |&v| Some(v),
+            Some,
+        )
+    }
+
+    fn common_in_neighbors(&self, u: usize, v: usize) -> Result<Vec<usize>, GraphError> {
+        neighborhood::wt_common_neighbors(
+            &self.wdg.dg,
+            &u,
+            &v,
+            Direction::Undirected,
+            |&v| Some(v),
+            Some,
+        )
+    }
+
+    fn jaccard(&self, u: usize, v: usize) -> Result<f64, GraphError> {
+        neighborhood::wt_jaccard(&self.wdg.dg, &u, &v, Direction::Undirected, |&v| Some(v))
+    }
+}
+
+// the policy belongs to the wrapped wt-graph, which commits everything of this graph
+impl<W> WTCommitPolicy for WeightedWTUGraph<W>
+where
+    W: Clone,
+{
+    fn commit_policy(&self) -> CommitPolicy {
+        self.wdg.commit_policy()
+    }
+
+    fn set_commit_policy(&mut self, policy: CommitPolicy) {
+        self.wdg.set_commit_policy(policy);
+    }
+
+    fn set_commit_hook(&mut self, hook: Option<CommitHook>) {
+        self.wdg.set_commit_hook(hook);
+    }
+}
@@ -1,5 +1,3 @@
-pub mod from_file;
-
 use super::*;
 use graph::{
     directed::Digraph, labeled_directed::LabeledDigraph, labeled_undirected::LabeledUGraph,
@@ -445,4 +445,49 @@ mod test {
         assert_eq!(digraph.v_count(), wtdigraph.v_count());
         assert_eq!(digraph.e_count(), wtdigraph.e_count());
     }
+    #[test]
+    fn test_io_read_files() {
+        use std::fs::File;
+        use std::io::BufReader;
+        use wt_graphs::io::*;
+        use wt_graphs::prelude::*;
+
+        let open =
+            |name: &str| BufReader::new(File::open(format!("tests/files/{name}.txt")).unwrap());
+
+        let dg = read_digraph(open("Digraph")).unwrap();
+        let wtd = read_wt_digraph(open("Digraph")).unwrap();
+        assert_eq!((dg.v_count(), dg.e_count()), (100, 500));
+        for i in 0..dg.v_count() {
+            assert_eq!(dg.outgoing_edges(i), wtd.outgoing_edges(i));
+        }
+
+        let ug = read_ugraph(open("UGraph")).unwrap();
+        let wtu = read_wt_ugraph(open("UGraph")).unwrap();
+        assert_eq!((wtu.v_count(), wtu.e_count()), (100, 500));
+        for i in 0..ug.v_count() {
+            assert_eq!(ug.edges(i).len(), wtu.edges(i).len());
+        }
+
+        let wdg: WeightedDigraph<f64> = read_weighted_digraph(open("WeightedDigraph")).unwrap();
+        let wug: WeightedWTUGraph<f64> = read_weighted_wt_ugraph(open("WeightedUGraph")).unwrap();
+        assert_eq!(wdg.e_count(), 500);
+        assert_eq!(wug.e_count(), 500);
+
+        let ldg: LabeledWTDigraph<String> =
+            read_labeled_wt_digraph(open("LabeledDigraph")).unwrap();
+        let lug: LabeledUGraph<String> = read_labeled_ugraph(open("LabeledUGraph")).unwrap();
+        assert_eq!((ldg.v_count(), ldg.e_count()), (100, 500));
+        assert_eq!((lug.v_count(), lug.e_count()), (100, 500));
+
+        let lwdg: graph::labeled_weighted_directed::LabeledWeightedDigraph<String, f64> =
+            read_labeled_weighted_digraph(open("LabeledWeightedDigraph")).unwrap();
+        let lwug: LabeledWeightedWTUGraph<String, f64> =
+            read_labeled_weighted_wt_ugraph(open("LabeledWeightedUGraph")).unwrap();
+        assert_eq!(
+            lwdg.weight("Eliza".to_string(), "Penelope".to_string()),
+            0.07189946078775622
+        );
+        assert_eq!(lwug.e_count(), 500);
+    }
 }